@@ -0,0 +1,48 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use tsconfig::{parse_to_value, TsConfig};
+
+/// A config with many path mappings, similar to the root config of a large monorepo.
+fn monorepo_config() -> String {
+    let paths: Vec<String> = (0..500)
+        .map(|i| {
+            format!(
+                r#"      "@org/package-{i}/*": ["packages/package-{i}/src/*", "packages/package-{i}/types/*"], // package {i}"#,
+                i = i
+            )
+        })
+        .collect();
+    format!(
+        r#"{{
+  "compilerOptions": {{
+    "target": "ES2022",
+    "module": "esnext",
+    "strict": true,
+    "baseUrl": ".",
+    "paths": {{
+{}
+    }},
+  }},
+  "include": ["packages/*/src/**/*"],
+}}"#,
+        paths.join("\n")
+    )
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let complete = include_str!("../test/tsconfig.complete.json");
+    let monorepo = monorepo_config();
+
+    c.bench_function("parse_str complete", |b| {
+        b.iter(|| TsConfig::parse_str(black_box(complete)).unwrap())
+    });
+    c.bench_function("parse_str monorepo", |b| {
+        b.iter(|| TsConfig::parse_str(black_box(&monorepo)).unwrap())
+    });
+    c.bench_function("parse_to_value monorepo", |b| {
+        b.iter(|| parse_to_value(black_box(&monorepo)).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -0,0 +1,167 @@
+#!/usr/bin/env node
+// Generates src/generated.rs, the tables of values that the `lib`, `target` and `module` options
+// accept, from the option definitions in the `typescript` package.
+//
+// Usage, from the root of the repository:
+//
+//     npm install --no-save typescript@latest
+//     node scripts/generate-option-tables.js
+//     cargo fmt
+//
+// Variant names are derived from the option values, so that an existing value always keeps the
+// same variant. Only add to RENAMED when a derived name would clash with an existing variant.
+
+"use strict";
+
+const fs = require("fs");
+const path = require("path");
+const ts = require("typescript");
+
+const OUTPUT = path.join(__dirname, "..", "src", "generated.rs");
+
+// Words that are written as more than one word in variant names.
+const WORDS = {
+  arraybuffer: "ArrayBuffer",
+  asyncgenerator: "AsyncGenerator",
+  asynciterable: "AsyncIterable",
+  bigint: "BigInt",
+  commonjs: "CommonJs",
+  esnext: "EsNext",
+  importscripts: "ImportScripts",
+  nodenext: "NodeNext",
+  regexp: "RegExp",
+  scripthost: "ScriptHost",
+  sharedmemory: "SharedMemory",
+  typedarrays: "TypedArrays",
+  weakref: "WeakRef",
+  webworker: "WebWorker",
+  wellknown: "WellKnown",
+};
+
+// Variants whose names predate the rules above.
+const RENAMED = {
+  "es2020.symbol.wellknown": "Es2020SymbolWellknown",
+};
+
+// Names that tsc doesn't accept for an option, but that this crate always has, with the name of
+// the value they stand for.
+const ALIASES = {
+  target: { es7: "es2016" },
+};
+
+// Option values that tsc documents in a casing other than the one the rules below give.
+const CASING = {
+  amd: "AMD",
+  commonjs: "CommonJS",
+  esnext: "ESNext",
+  umd: "UMD",
+};
+
+function variantName(value) {
+  if (RENAMED[value]) {
+    return RENAMED[value];
+  }
+  return value
+    .split(/[.-]/)
+    .map((word) => WORDS[word] || word[0].toUpperCase() + word.slice(1))
+    .join("");
+}
+
+// The casing tsc's documentation uses for a `target` or `module` value, such as `ES2015`.
+function documentedName(value) {
+  if (CASING[value]) {
+    return CASING[value];
+  }
+  if (/^es\d+$/.test(value)) {
+    return value.toUpperCase();
+  }
+  return variantName(value);
+}
+
+function option(name) {
+  const declaration = ts.optionDeclarations.find((option) => option.name === name);
+  if (!declaration) {
+    throw new Error(`typescript ${ts.version} has no ${name} option`);
+  }
+  return declaration;
+}
+
+// Groups the names of an option's values by what they stand for, giving the canonical name of
+// each value followed by its aliases. tsc lists legacy names, such as es6, before current ones.
+function group(entries, isCanonical) {
+  const groups = new Map();
+  for (const [name, value] of entries) {
+    if (!groups.has(value)) {
+      groups.set(value, []);
+    }
+    groups.get(value).push(name);
+  }
+  return [...groups.values()].map((names) => {
+    const canonical = names.find(isCanonical) || names[names.length - 1];
+    return [canonical, ...names.filter((name) => name !== canonical)];
+  });
+}
+
+function values(name) {
+  const entries = [...option(name).type.entries()];
+  const groups = group(entries, () => false);
+  for (const [alias, value] of Object.entries(ALIASES[name] || {})) {
+    const names = groups.find((names) => names[0] === value);
+    if (!names.includes(alias)) {
+      names.push(alias);
+    }
+  }
+  return groups;
+}
+
+// Each lib is also named by its file, such as lib.es2015.d.ts, which is the name that its own
+// references use.
+function libValues() {
+  const entries = [...option("lib").element.type.entries()];
+  // The .full libs, which bundle a lib with dom and the other host libs, are only published as
+  // files, but configs name them too.
+  const libDir = path.dirname(require.resolve("typescript"));
+  for (const file of fs.readdirSync(libDir).sort()) {
+    const match = /^lib\.(.+\.full)\.d\.ts$/.exec(file);
+    if (match && !entries.some(([name]) => name === match[1])) {
+      entries.push([match[1], file]);
+    }
+  }
+  const files = new Map(entries);
+  return group(entries, (name) => files.get(name) === `lib.${name}.d.ts`).map((names) => [
+    ...names,
+    files.get(names[0]),
+  ]);
+}
+
+function enumMacro(macro, name, groups, nameOf) {
+  const variants = groups
+    .map(([canonical, ...aliases]) => {
+      const names = [canonical, ...aliases].map((value) => `"${nameOf(value)}"`).join(" | ");
+      return `                ${variantName(canonical)} = ${names},`;
+    })
+    .join("\n");
+  return `/// Defines [${name}](crate::${name}) with the values the \`${macro.replace("_enum", "")}\` option accepts.
+macro_rules! ${macro} {
+    ($(#[$meta:meta])*) => {
+        option_enum! {
+            $(#[$meta])*
+            pub enum ${name} {
+${variants}
+                ..Other
+            }
+        }
+    };
+}
+`;
+}
+
+const output = `// @generated by scripts/generate-option-tables.js. Do not edit by hand; rerun the script to
+// pick up values added in new TypeScript releases.
+
+${enumMacro("lib_enum", "Lib", libValues(), (value) => value)}
+${enumMacro("target_enum", "Target", values("target"), documentedName)}
+${enumMacro("module_enum", "Module", values("module"), documentedName)}`;
+
+fs.writeFileSync(OUTPUT, output);
+console.log(`Wrote ${path.relative(process.cwd(), OUTPUT)} from typescript ${ts.version}`);
@@ -0,0 +1,204 @@
+//! Getters and setters for the fields of [TsConfig] and [CompilerOptions], for code that would
+//! rather not depend on their fields directly.
+
+use crate::{
+    CompilerOptions, Extends, FallbackPolling, ImportsNotUsedAsValues, Jsx, Lib, Module,
+    ModuleDetectionMode, ModuleResolutionMode, Paths, Plugin, References, Target, TsConfig,
+    TsNodeOptions, TypeAcquisition, WatchDirectory, WatchFile, WatchOptions,
+};
+
+accessors! {
+    impl TsConfig then sync_raw {
+        copy compile_on_save, set_compile_on_save: bool;
+        deref exclude, set_exclude: Vec<String> => [String];
+        deref files, set_files: Vec<String> => [String];
+        deref include, set_include: Vec<String> => [String];
+        ref references, set_references: References;
+        ref type_acquisition, set_type_acquisition: TypeAcquisition;
+        ref watch_options, set_watch_options: WatchOptions;
+        ref ts_node, set_ts_node: TsNodeOptions;
+        ref compiler_options, set_compiler_options: CompilerOptions;
+    }
+}
+
+impl TsConfig {
+    /// Returns the `extends` field.
+    pub fn extends(&self) -> Option<&Extends> {
+        self.extends.as_ref()
+    }
+
+    /// Sets the `extends` field, or unsets it with `None`. The files it resolved to, if the config
+    /// was loaded from disk, are forgotten unless it's unchanged.
+    pub fn set_extends(&mut self, value: impl Into<Option<Extends>>) {
+        let extends = value.into();
+        if extends != self.extends {
+            self.extends_paths = None;
+        }
+        self.extends = extends;
+        self.sync_raw();
+    }
+}
+
+accessors! {
+    impl CompilerOptions {
+        copy allow_arbitrary_extensions, set_allow_arbitrary_extensions: bool;
+        copy allow_importing_ts_extensions, set_allow_importing_ts_extensions: bool;
+        copy allow_js, set_allow_js: bool;
+        copy check_js, set_check_js: bool;
+        copy composite, set_composite: bool;
+        copy declaration, set_declaration: bool;
+        copy declaration_map, set_declaration_map: bool;
+        copy downlevel_iteration, set_downlevel_iteration: bool;
+        copy import_helpers, set_import_helpers: bool;
+        copy incremental, set_incremental: bool;
+        copy isolated_modules, set_isolated_modules: bool;
+        copy isolated_declarations, set_isolated_declarations: bool;
+        ref jsx, set_jsx: Jsx;
+        deref lib, set_lib: Vec<Lib> => [Lib];
+        ref module, set_module: Module;
+        ref module_detection, set_module_detection: ModuleDetectionMode;
+        copy no_emit, set_no_emit: bool;
+        copy no_check, set_no_check: bool;
+        deref out_dir, set_out_dir: String => str;
+        deref out_file, set_out_file: String => str;
+        copy remove_comments, set_remove_comments: bool;
+        deref root_dir, set_root_dir: String => str;
+        copy source_map, set_source_map: bool;
+        ref target, set_target: Target;
+        deref ts_build_info_file, set_ts_build_info_file: String => str;
+        copy always_strict, set_always_strict: bool;
+        copy exact_optional_property_types, set_exact_optional_property_types: bool;
+        copy no_implicit_any, set_no_implicit_any: bool;
+        copy no_implicit_override, set_no_implicit_override: bool;
+        copy no_implicit_this, set_no_implicit_this: bool;
+        copy strict, set_strict: bool;
+        copy strict_bind_call_apply, set_strict_bind_call_apply: bool;
+        copy strict_builtin_iterator_return, set_strict_builtin_iterator_return: bool;
+        copy strict_function_types, set_strict_function_types: bool;
+        copy strict_null_checks, set_strict_null_checks: bool;
+        copy strict_property_initialization, set_strict_property_initialization: bool;
+        copy use_unknown_in_catch_variables, set_use_unknown_in_catch_variables: bool;
+        copy allow_synthetic_default_imports, set_allow_synthetic_default_imports: bool;
+        copy allow_umd_global_access, set_allow_umd_global_access: bool;
+        deref base_url, set_base_url: String => str;
+        deref custom_conditions, set_custom_conditions: Vec<String> => [String];
+        copy es_module_interop, set_es_module_interop: bool;
+        ref module_resolution, set_module_resolution: ModuleResolutionMode;
+        deref module_suffixes, set_module_suffixes: Vec<String> => [String];
+        ref paths, set_paths: Paths;
+        deref plugins, set_plugins: Vec<Plugin> => [Plugin];
+        copy preserve_symlinks, set_preserve_symlinks: bool;
+        deref root_dirs, set_root_dirs: Vec<String> => [String];
+        deref type_roots, set_type_roots: Vec<String> => [String];
+        deref types, set_types: Vec<String> => [String];
+        copy inline_source_map, set_inline_source_map: bool;
+        copy inline_sources, set_inline_sources: bool;
+        deref map_root, set_map_root: String => str;
+        deref source_root, set_source_root: String => str;
+        copy no_fallthrough_cases_in_switch, set_no_fallthrough_cases_in_switch: bool;
+        copy no_implicit_returns, set_no_implicit_returns: bool;
+        copy no_property_access_from_index_signature, set_no_property_access_from_index_signature: bool;
+        copy no_unchecked_indexed_access, set_no_unchecked_indexed_access: bool;
+        copy no_unchecked_side_effect_imports, set_no_unchecked_side_effect_imports: bool;
+        copy no_unused_locals, set_no_unused_locals: bool;
+        copy no_unused_parameters, set_no_unused_parameters: bool;
+        copy emit_decorator_metadata, set_emit_decorator_metadata: bool;
+        copy experimental_decorators, set_experimental_decorators: bool;
+        copy allow_unreachable_code, set_allow_unreachable_code: bool;
+        copy allow_unused_labels, set_allow_unused_labels: bool;
+        copy assume_changes_only_affect_direct_dependencies, set_assume_changes_only_affect_direct_dependencies: bool;
+        #[deprecated]
+        deref charset, set_charset: String => str;
+        deref declaration_dir, set_declaration_dir: String => str;
+        #[deprecated]
+        copy diagnostics, set_diagnostics: bool;
+        copy disable_referenced_project_load, set_disable_referenced_project_load: bool;
+        copy disable_size_limit, set_disable_size_limit: bool;
+        copy disable_solution_searching, set_disable_solution_searching: bool;
+        copy disable_source_of_project_reference_redirect, set_disable_source_of_project_reference_redirect: bool;
+        copy emit_bom, set_emit_bom: bool;
+        copy emit_declaration_only, set_emit_declaration_only: bool;
+        copy erasable_syntax_only, set_erasable_syntax_only: bool;
+        copy explain_files, set_explain_files: bool;
+        copy extended_diagnostics, set_extended_diagnostics: bool;
+        copy force_consistent_casing_in_file_names, set_force_consistent_casing_in_file_names: bool;
+        deref generate_cpu_profile, set_generate_cpu_profile: String => str;
+        ref imports_not_used_as_values, set_imports_not_used_as_values: ImportsNotUsedAsValues;
+        deref jsx_factory, set_jsx_factory: String => str;
+        deref jsx_fragment_factory, set_jsx_fragment_factory: String => str;
+        deref jsx_import_source, set_jsx_import_source: String => str;
+        copy keyof_strings_only, set_keyof_strings_only: bool;
+        copy lib_replacement, set_lib_replacement: bool;
+        copy list_emitted_files, set_list_emitted_files: bool;
+        deref locale, set_locale: String => str;
+        copy list_files, set_list_files: bool;
+        copy max_node_module_js_depth, set_max_node_module_js_depth: u32;
+        copy no_emit_helpers, set_no_emit_helpers: bool;
+        copy no_emit_on_error, set_no_emit_on_error: bool;
+        copy no_error_truncation, set_no_error_truncation: bool;
+        copy no_implicit_use_strict, set_no_implicit_use_strict: bool;
+        copy no_lib, set_no_lib: bool;
+        copy no_resolve, set_no_resolve: bool;
+        copy no_strict_generic_checks, set_no_strict_generic_checks: bool;
+        #[deprecated]
+        deref out, set_out: String => str;
+        copy preserve_const_enums, set_preserve_const_enums: bool;
+        #[deprecated]
+        copy preserve_value_imports, set_preserve_value_imports: bool;
+        deref react_namespace, set_react_namespace: String => str;
+        copy resolve_json_module, set_resolve_json_module: bool;
+        copy resolve_package_json_exports, set_resolve_package_json_exports: bool;
+        copy resolve_package_json_imports, set_resolve_package_json_imports: bool;
+        copy rewrite_relative_import_extensions, set_rewrite_relative_import_extensions: bool;
+        copy skip_default_lib_check, set_skip_default_lib_check: bool;
+        copy skip_lib_check, set_skip_lib_check: bool;
+        copy strip_internal, set_strip_internal: bool;
+        copy suppress_excess_property_errors, set_suppress_excess_property_errors: bool;
+        copy suppress_implicit_any_index_errors, set_suppress_implicit_any_index_errors: bool;
+        copy trace_resolution, set_trace_resolution: bool;
+        copy use_define_for_class_fields, set_use_define_for_class_fields: bool;
+        copy verbatim_module_syntax, set_verbatim_module_syntax: bool;
+        copy preserve_watch_output, set_preserve_watch_output: bool;
+        copy pretty, set_pretty: bool;
+        ref fallback_polling, set_fallback_polling: FallbackPolling;
+        ref watch_directory, set_watch_directory: WatchDirectory;
+        ref watch_file, set_watch_file: WatchFile;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use std::borrow::Cow;
+
+    #[test]
+    fn setters_write_into_the_json() {
+        let mut config =
+            TsConfig::parse_str(r#"{"include": ["src"], "compilerOptions": {"strict": false}}"#)
+                .unwrap();
+        assert_eq!(config.include(), Some(&["src".to_string()][..]));
+
+        let mut options = config.compiler_options().cloned().unwrap();
+        assert_eq!(options.strict(), Some(false));
+        options.set_strict(true);
+        options.set_out_dir("dist".to_string());
+        options.set_target(Target::Es2022);
+        config.set_compiler_options(options);
+        config.set_include(None);
+
+        assert!(matches!(config.raw(), Cow::Borrowed(_)));
+        assert_eq!(
+            *config.raw(),
+            json!({"compilerOptions": {"strict": true, "outDir": "dist", "target": "ES2022"}})
+        );
+        assert_eq!(config.include(), None);
+
+        config.set_extends(Extends::from("./base.json"));
+        assert_eq!(
+            config.extends().and_then(Extends::single),
+            Some("./base.json")
+        );
+        assert_eq!(config.raw()["extends"], "./base.json");
+    }
+}
@@ -0,0 +1,274 @@
+//! Zero-copy views of a TSConfig that borrow their strings from the source text.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::Deserialize;
+
+use crate::{jsonc, Result};
+
+/// A string borrowed from the source text where possible.
+///
+/// Strings containing escape sequences (such as `"..\\base"`) have to be unescaped, and are
+/// allocated; all others point directly into the parsed buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CowStr<'a>(pub Cow<'a, str>);
+
+impl<'a> CowStr<'a> {
+    /// Returns the string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the string points into the source text rather than owning a copy.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+
+    /// Converts into an owned [String], allocating only if the string was borrowed.
+    pub fn into_owned(self) -> String {
+        self.0.into_owned()
+    }
+}
+
+impl<'a> Deref for CowStr<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a> fmt::Display for CowStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for CowStr<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CowStrVisitor;
+
+        impl<'de> Visitor<'de> for CowStrVisitor {
+            type Value = CowStr<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(v.to_string())))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(v)))
+            }
+        }
+
+        deserializer.deserialize_str(CowStrVisitor)
+    }
+}
+
+/// A borrowed view of the `extends` field, which is a single specifier or a list of them, as in
+/// [Extends](crate::Extends).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExtendsRef<'a> {
+    /// A single config, written as a string.
+    Single(CowStr<'a>),
+    /// Configs written as a list, which are applied in order.
+    Multiple(Vec<CowStr<'a>>),
+}
+
+impl<'a> ExtendsRef<'a> {
+    /// Returns the specifiers as written, in the order they're applied.
+    pub fn specifiers(&self) -> &[CowStr<'a>] {
+        match self {
+            ExtendsRef::Single(specifier) => std::slice::from_ref(specifier),
+            ExtendsRef::Multiple(specifiers) => specifiers,
+        }
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for ExtendsRef<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ExtendsRefVisitor;
+
+        impl<'de> Visitor<'de> for ExtendsRefVisitor {
+            type Value = ExtendsRef<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a config specifier, or a list of them")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(ExtendsRef::Single(CowStr(Cow::Borrowed(v))))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(ExtendsRef::Single(CowStr(Cow::Owned(v.to_string()))))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(ExtendsRef::Single(CowStr(Cow::Owned(v))))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut specifiers = Vec::new();
+                while let Some(specifier) = seq.next_element()? {
+                    specifiers.push(specifier);
+                }
+                Ok(ExtendsRef::Multiple(specifiers))
+            }
+        }
+
+        deserializer.deserialize_any(ExtendsRefVisitor)
+    }
+}
+
+/// A borrowed view of the path- and string-valued parts of a TSConfig.
+///
+/// This is intended for tooling that scans large numbers of configs and only needs to know which
+/// files they cover and where they emit to. Unlike [TsConfig](crate::TsConfig), it does not
+/// deserialize the full set of compiler options, and the `extends` field is not followed.
+///
+/// ## Example
+/// ```
+/// use tsconfig::TsConfigRef;
+///
+/// let mut json = r#"{
+///     // Only the project layout is needed here
+///     "include": ["src/**/*",],
+///     "compilerOptions": {"outDir": "dist"}
+/// }"#.to_string();
+///
+/// let config = TsConfigRef::parse(&mut json).unwrap();
+/// let out_dir = config.compiler_options.unwrap().out_dir.unwrap();
+/// assert_eq!(out_dir.as_str(), "dist");
+/// assert!(out_dir.is_borrowed());
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TsConfigRef<'a> {
+    #[serde(borrow)]
+    pub exclude: Option<Vec<CowStr<'a>>>,
+    #[serde(borrow)]
+    pub extends: Option<ExtendsRef<'a>>,
+    #[serde(borrow)]
+    pub files: Option<Vec<CowStr<'a>>>,
+    #[serde(borrow)]
+    pub include: Option<Vec<CowStr<'a>>>,
+    #[serde(borrow)]
+    pub references: Option<Vec<ReferenceRef<'a>>>,
+    #[serde(borrow)]
+    pub compiler_options: Option<CompilerOptionsRef<'a>>,
+}
+
+impl<'a> TsConfigRef<'a> {
+    /// Parses a JSON string into a [TsConfigRef] without copying its strings.
+    ///
+    /// Comments and trailing commas are allowed. To avoid copying the input, they are overwritten with
+    /// whitespace directly in `json`, which is otherwise left unchanged.
+    pub fn parse(json: &'a mut String) -> Result<TsConfigRef<'a>> {
+        jsonc::strip_in_place(json);
+        let config = serde_json::from_str(json)?;
+        Ok(config)
+    }
+}
+
+/// A borrowed view of a project reference.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReferenceRef<'a> {
+    #[serde(borrow)]
+    pub path: CowStr<'a>,
+    pub prepend: Option<bool>,
+}
+
+/// A borrowed view of the path- and string-valued compiler options.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerOptionsRef<'a> {
+    #[serde(borrow)]
+    pub base_url: Option<CowStr<'a>>,
+    #[serde(borrow)]
+    pub declaration_dir: Option<CowStr<'a>>,
+    #[serde(borrow)]
+    pub out_dir: Option<CowStr<'a>>,
+    #[serde(borrow)]
+    pub out_file: Option<CowStr<'a>>,
+    #[serde(borrow)]
+    pub paths: Option<HashMap<CowStr<'a>, Vec<CowStr<'a>>>>,
+    #[serde(borrow)]
+    pub root_dir: Option<CowStr<'a>>,
+    #[serde(borrow)]
+    pub root_dirs: Option<Vec<CowStr<'a>>>,
+    #[serde(borrow)]
+    pub ts_build_info_file: Option<CowStr<'a>>,
+    #[serde(borrow)]
+    pub type_roots: Option<Vec<CowStr<'a>>>,
+    #[serde(borrow)]
+    pub types: Option<Vec<CowStr<'a>>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn borrows_unescaped_strings() {
+        let mut json = r#"{
+            "extends": "..\\base.json",
+            "files": ["a.ts", "b.ts"],
+            "references": [{"path": "../core"}],
+            "compilerOptions": {
+                "paths": {"@app/*": ["src/*"]},
+                "rootDir": "src", // comment
+            },
+        }"#
+        .to_string();
+        let config = TsConfigRef::parse(&mut json).unwrap();
+
+        let extends = config.extends.unwrap();
+        let extends = &extends.specifiers()[0];
+        assert_eq!(extends.as_str(), "..\\base.json");
+        assert!(!extends.is_borrowed());
+
+        assert!(config.files.unwrap().iter().all(CowStr::is_borrowed));
+        assert!(config.references.unwrap()[0].path.is_borrowed());
+
+        let opts = config.compiler_options.unwrap();
+        assert!(opts.root_dir.unwrap().is_borrowed());
+        let paths = opts.paths.unwrap();
+        let (key, targets) = paths.iter().next().unwrap();
+        assert!(key.is_borrowed());
+        assert_eq!(targets[0].as_str(), "src/*");
+        assert!(targets[0].is_borrowed());
+    }
+
+    #[test]
+    fn borrows_a_list_of_extends() {
+        let mut json = r#"{"extends": ["./a.json", "./b.json"]}"#.to_string();
+        let config = TsConfigRef::parse(&mut json).unwrap();
+
+        let extends = config.extends.unwrap();
+        assert!(matches!(extends, ExtendsRef::Multiple(_)));
+        let specifiers: Vec<_> = extends.specifiers().iter().map(CowStr::as_str).collect();
+        assert_eq!(specifiers, ["./a.json", "./b.json"]);
+        assert!(extends.specifiers().iter().all(CowStr::is_borrowed));
+    }
+}
@@ -0,0 +1,103 @@
+//! Caching parsed config files between loads.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use crate::order::Ordered;
+
+/// A cache of parsed config files, shared between [ConfigLoader](crate::ConfigLoader)s.
+///
+/// Monorepos often have hundreds of configs extending the same few bases. With a cache, each base is
+/// read and parsed once, rather than once for every config that extends it. Entries are keyed by the
+/// canonical path of the file, and are only reused while its modification time is unchanged, so a
+/// cache can be kept for the lifetime of a long-running process. Files whose modification time isn't
+/// available from the [FileSystem](crate::FileSystem) are never cached.
+///
+/// Cloning a `ConfigCache` is cheap, and the clones share their entries.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::{ConfigCache, ConfigLoader};
+///
+/// let root = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test");
+/// let cache = ConfigCache::new();
+/// let loader = ConfigLoader::new().with_cache(cache.clone());
+///
+/// loader.load(&root.join("tsconfig.inherits.json")).unwrap();
+/// loader.load(&root.join("a/tsconfig.inherits_again.json")).unwrap();
+///
+/// // The shared base was only read once.
+/// assert_eq!(cache.len(), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigCache {
+    entries: Arc<Mutex<HashMap<PathBuf, (SystemTime, Ordered)>>>,
+}
+
+impl ConfigCache {
+    /// Creates an empty cache.
+    pub fn new() -> ConfigCache {
+        ConfigCache::default()
+    }
+
+    /// Returns the number of files in the cache.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Removes the file with the canonical path `path` from the cache.
+    pub fn invalidate(&self, path: &Path) {
+        self.entries().remove(path);
+    }
+
+    /// Removes every file from the cache.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    pub(crate) fn get(&self, path: &Path, modified: SystemTime) -> Option<Ordered> {
+        match self.entries().get(path) {
+            Some((cached, json)) if *cached == modified => Some(json.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, path: PathBuf, modified: SystemTime, json: Ordered) {
+        self.entries().insert(path, (modified, json));
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, (SystemTime, Ordered)>> {
+        // The map is never left half-updated, so it's still usable if another thread panicked.
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::Value;
+    use std::time::Duration;
+
+    #[test]
+    fn entries_expire_when_modified() {
+        let cache = ConfigCache::new();
+        let path = Path::new("/repo/tsconfig.json");
+        let then = SystemTime::UNIX_EPOCH;
+        cache.insert(path.to_path_buf(), then, Ordered::new(Value::Bool(true)));
+
+        assert_eq!(cache.get(path, then).unwrap().value, Value::Bool(true));
+        assert!(cache.get(path, then + Duration::from_secs(1)).is_none());
+
+        let clone = cache.clone();
+        clone.invalidate(path);
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+//! Warnings about deprecated options that a config sets.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::{compiler_option, Deprecation, TsVersion};
+
+/// `prepend` on a project reference, which isn't a compiler option, so isn't in the registry.
+pub(crate) const PREPEND: Deprecation = Deprecation {
+    since: TsVersion::new(5, 0),
+    removed: Some(TsVersion::new(5, 5)),
+    replacement: None,
+};
+
+/// A deprecated option that a config sets, found while parsing it.
+///
+/// See [TsConfig::deprecations](crate::TsConfig::deprecations).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprecationWarning {
+    /// The name of the option, such as `out`.
+    pub option: String,
+    /// Dotted path of the option, such as `compilerOptions.out` or `references.0.prepend`.
+    pub field: String,
+    /// The release that deprecated the option.
+    pub since: TsVersion,
+    /// The release that stopped accepting the option, if it has been removed.
+    pub removed: Option<TsVersion>,
+    /// The option to use instead, if there is one.
+    pub replacement: Option<&'static str>,
+}
+
+impl DeprecationWarning {
+    fn new(option: &str, field: String, deprecation: Deprecation) -> DeprecationWarning {
+        DeprecationWarning {
+            option: option.to_string(),
+            field,
+            since: deprecation.since,
+            removed: deprecation.removed,
+            replacement: deprecation.replacement,
+        }
+    }
+}
+
+impl fmt::Display for DeprecationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Option '{}' is deprecated since TypeScript {}",
+            self.option, self.since
+        )?;
+        if let Some(removed) = self.removed {
+            write!(f, ", and was removed in TypeScript {}", removed)?;
+        }
+        f.write_str(".")?;
+        if let Some(replacement) = self.replacement {
+            write!(f, " Use '{}' instead.", replacement)?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds every deprecated option set in `raw`, in the order they appear.
+pub(crate) fn collect(raw: &Value) -> Vec<DeprecationWarning> {
+    let mut warnings = Vec::new();
+    if let Some(Value::Object(options)) = raw.get("compilerOptions") {
+        for (name, value) in options {
+            let deprecation = compiler_option(name)
+                .and_then(|option| option.deprecation)
+                .filter(|_| !value.is_null());
+            if let Some(deprecation) = deprecation {
+                let field = format!("compilerOptions.{}", name);
+                warnings.push(DeprecationWarning::new(name, field, deprecation));
+            }
+        }
+    }
+    if let Some(Value::Array(references)) = raw.get("references") {
+        for (i, reference) in references.iter().enumerate() {
+            if reference
+                .get("prepend")
+                .is_some_and(|value| !value.is_null())
+            {
+                let field = format!("references.{}.prepend", i);
+                warnings.push(DeprecationWarning::new("prepend", field, PREPEND));
+            }
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn collects_deprecated_options_and_prepend() {
+        let raw = json!({
+            "compilerOptions": {"charset": "utf8", "strict": true, "out": "out.js", "keyofStringsOnly": null},
+            "references": [{"path": "../a"}, {"path": "../b", "prepend": true}]
+        });
+        let warnings = collect(&raw);
+
+        let fields: Vec<_> = warnings.iter().map(|w| w.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "compilerOptions.charset",
+                "compilerOptions.out",
+                "references.1.prepend"
+            ]
+        );
+        assert_eq!(warnings[1].option, "out");
+        assert_eq!(warnings[1].replacement, Some("outFile"));
+        assert_eq!(warnings[1].removed, Some(TsVersion::new(5, 5)));
+        assert_eq!(
+            warnings[1].to_string(),
+            "Option 'out' is deprecated since TypeScript 1.6, and was removed in TypeScript 5.5. Use 'outFile' instead."
+        );
+        assert_eq!(
+            warnings[2].to_string(),
+            "Option 'prepend' is deprecated since TypeScript 5.0, and was removed in TypeScript 5.5."
+        );
+    }
+}
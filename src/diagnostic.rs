@@ -0,0 +1,41 @@
+//! Diagnostics reported about individual fields of a TSConfig.
+
+use std::fmt;
+
+use crate::Span;
+
+/// A problem found with a single field of a TSConfig.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Dotted path of the offending field, such as `compilerOptions.target`.
+    ///
+    /// For syntax errors, this is the path of the enclosing object or array.
+    pub field: String,
+    /// A human-readable description of the problem.
+    pub message: String,
+    /// Location of the problem in the source text, if it is known.
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(field: impl Into<String>, message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            field: field.into(),
+            message: message.into(),
+            span: None,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.span {
+            Some(span) => write!(
+                f,
+                "{}:{}: {}: {}",
+                span.line, span.column, self.field, self.message
+            ),
+            None => write!(f, "{}: {}", self.field, self.message),
+        }
+    }
+}
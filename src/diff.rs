@@ -0,0 +1,127 @@
+//! Comparing the options set by two configs.
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::fingerprint::normalize;
+
+/// The differences between two configs, found by [TsConfig::diff](crate::TsConfig::diff).
+///
+/// Fields are named by their dotted path, such as `compilerOptions.strict`. Objects are compared
+/// field by field, however deeply nested, while arrays such as `include` are compared as a whole.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigDiff {
+    /// Fields only set in the other config, with their values.
+    pub added: Vec<(String, Value)>,
+    /// Fields only set in this config, with their values.
+    pub removed: Vec<(String, Value)>,
+    /// Fields set to different values, with the value in this config and then in the other.
+    pub changed: Vec<(String, Value, Value)>,
+}
+
+impl ConfigDiff {
+    /// Whether the two configs set the same options.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (field, value) in &self.added {
+            writeln!(f, "+ {}: {}", field, value)?;
+        }
+        for (field, value) in &self.removed {
+            writeln!(f, "- {}: {}", field, value)?;
+        }
+        for (field, old, new) in &self.changed {
+            writeln!(f, "~ {}: {} -> {}", field, old, new)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two raw configs.
+pub(crate) fn diff(old: &Value, new: &Value) -> ConfigDiff {
+    // Configs that weren't parsed from JSON have no raw value, and so no options.
+    let empty = Map::new();
+    let mut diff = ConfigDiff::default();
+    let (old, new) = (old.as_object(), new.as_object());
+    diff_objects(&mut diff, old.unwrap_or(&empty), new.unwrap_or(&empty), "");
+    diff
+}
+
+fn diff_objects(
+    diff: &mut ConfigDiff,
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    parent: &str,
+) {
+    let field = |key: &str| match parent {
+        "" => key.to_string(),
+        _ => format!("{}.{}", parent, key),
+    };
+    for (key, old_value) in old {
+        let field = field(key);
+        match (old_value, new.get(key)) {
+            (_, None) => diff.removed.push((field, old_value.clone())),
+            (Value::Object(old), Some(Value::Object(new))) => diff_objects(diff, old, new, &field),
+            // Values that only differ in case may still be the same to tsc.
+            (_, Some(new_value))
+                if normalize(old_value, &field) != normalize(new_value, &field) =>
+            {
+                diff.changed
+                    .push((field, old_value.clone(), new_value.clone()))
+            }
+            _ => {}
+        }
+    }
+    for (key, new_value) in new {
+        if !old.contains_key(key) {
+            diff.added.push((field(key), new_value.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diffs_nested_objects() {
+        let old = json!({
+            "compilerOptions": {"strict": true, "target": "ES2020", "paths": {"@/*": ["src/*"]}},
+            "include": ["src"],
+        });
+        let new = json!({
+            "compilerOptions": {"target": "es2020", "paths": {"@/*": ["lib/*"]}, "outDir": "dist"},
+            "include": ["src"],
+        });
+
+        let diff = diff(&old, &new);
+        assert_eq!(
+            diff.added,
+            [("compilerOptions.outDir".to_string(), json!("dist"))]
+        );
+        assert_eq!(
+            diff.removed,
+            [("compilerOptions.strict".to_string(), json!(true))]
+        );
+        assert_eq!(
+            diff.changed,
+            [(
+                "compilerOptions.paths.@/*".to_string(),
+                json!(["src/*"]),
+                json!(["lib/*"])
+            )]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "+ compilerOptions.outDir: \"dist\"\n\
+             - compilerOptions.strict: true\n\
+             ~ compilerOptions.paths.@/*: [\"src/*\"] -> [\"lib/*\"]\n"
+        );
+    }
+}
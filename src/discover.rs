@@ -0,0 +1,188 @@
+//! Finding the config files that govern source files.
+
+use std::path::{Path, PathBuf};
+
+use crate::{jsconfig_defaults, merge, ConfigLoader, Result, TsConfig};
+
+/// Finds the config that governs a source file, by looking in its directory and then in each
+/// directory above it, as tsc and editors do.
+///
+/// In each directory, tsconfig.json is looked for first and, if [ConfigFinder::with_jsconfig] is
+/// set, jsconfig.json after it. Relative paths are taken to be relative to the current directory.
+/// Files are looked for on the [FileSystem](crate::FileSystem) of the finder's [ConfigLoader].
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::{ConfigFinder, ConfigLoader, MemoryFileSystem};
+///
+/// let mut fs = MemoryFileSystem::new();
+/// fs.insert("/repo/tsconfig.json", r#"{"compilerOptions": {"strict": true}}"#);
+/// fs.insert("/repo/scripts/jsconfig.json", "{}");
+///
+/// let finder = ConfigFinder::new().with_loader(ConfigLoader::new().with_file_system(fs));
+/// assert_eq!(
+///     finder.find(&"/repo/scripts/build.js"),
+///     Some(Path::new("/repo/tsconfig.json").to_path_buf())
+/// );
+///
+/// let finder = finder.with_jsconfig(true);
+/// let (path, config) = finder.load(&"/repo/scripts/build.js").unwrap().unwrap();
+/// assert_eq!(path, Path::new("/repo/scripts/jsconfig.json"));
+/// assert_eq!(config.compiler_options.unwrap().allow_js, Some(true));
+/// ```
+#[derive(Clone, Default)]
+pub struct ConfigFinder {
+    loader: ConfigLoader,
+    jsconfig: bool,
+}
+
+impl ConfigFinder {
+    /// Creates a finder that looks for tsconfig.json on the real filesystem.
+    pub fn new() -> ConfigFinder {
+        ConfigFinder::default()
+    }
+
+    /// Sets the loader used to read the configs that are found, and whose filesystem is searched.
+    pub fn with_loader(self, loader: ConfigLoader) -> ConfigFinder {
+        ConfigFinder { loader, ..self }
+    }
+
+    /// Whether to also accept a jsconfig.json, if a directory has no tsconfig.json.
+    pub fn with_jsconfig(self, jsconfig: bool) -> ConfigFinder {
+        ConfigFinder { jsconfig, ..self }
+    }
+
+    /// Returns the path of the nearest config to `path`, if there is one.
+    pub fn find<P: AsRef<Path>>(&self, path: &P) -> Option<PathBuf> {
+        let path = path.as_ref();
+        let path = match std::env::current_dir() {
+            Ok(cwd) if path.is_relative() => cwd.join(path),
+            _ => path.to_path_buf(),
+        };
+        let names: &[&str] = if self.jsconfig {
+            &["tsconfig.json", "jsconfig.json"]
+        } else {
+            &["tsconfig.json"]
+        };
+        let fs = self.loader.file_system();
+        path.ancestors()
+            .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+            .find(|candidate| fs.exists(candidate))
+    }
+
+    /// Finds the nearest config to `path` and loads it, returning its path along with the config.
+    ///
+    /// A jsconfig.json gets the defaults described in [TsConfig::parse_jsconfig_file].
+    pub fn load<P: AsRef<Path>>(&self, path: &P) -> Result<Option<(PathBuf, TsConfig)>> {
+        let path = match self.find(path) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let config = load_config(&self.loader, &path)?;
+        Ok(Some((path, config)))
+    }
+}
+
+/// Loads the config at `path`, applying the jsconfig defaults if it is a jsconfig.
+pub(crate) fn load_config(loader: &ConfigLoader, path: &Path) -> Result<TsConfig> {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+    if name.starts_with("jsconfig") {
+        load_jsconfig(loader, path)
+    } else {
+        loader.load(&path)
+    }
+}
+
+/// Loads the jsconfig at `path`, filling in the defaults a jsconfig implies once everything it
+/// extends has been merged.
+pub(crate) fn load_jsconfig(loader: &ConfigLoader, path: &Path) -> Result<TsConfig> {
+    let mut loaded = loader.load_merged(path)?;
+    merge(&mut loaded.json.value, jsconfig_defaults());
+    loaded.into_config()
+}
+
+/// Returns the path of the tsconfig.json in the directory of `path`, or in the nearest directory
+/// above it that has one.
+///
+/// Use a [ConfigFinder] to also accept jsconfig.json, or to search a different filesystem.
+pub fn find_config_for<P: AsRef<Path>>(path: &P) -> Option<PathBuf> {
+    ConfigFinder::new().find(path)
+}
+
+/// Finds the nearest tsconfig.json to `path`, as [find_config_for] does, and parses it.
+pub fn load_config_for<P: AsRef<Path>>(path: &P) -> Result<Option<(PathBuf, TsConfig)>> {
+    ConfigFinder::new().load(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryFileSystem;
+
+    fn finder() -> ConfigFinder {
+        let mut fs = MemoryFileSystem::new();
+        fs.insert("/repo/tsconfig.json", "{}");
+        fs.insert("/repo/packages/app/tsconfig.json", "{}");
+        fs.insert("/repo/packages/app/src/jsconfig.json", "{}");
+        ConfigFinder::new().with_loader(ConfigLoader::new().with_file_system(fs))
+    }
+
+    #[test]
+    fn finds_nearest_tsconfig() {
+        let finder = finder();
+        assert_eq!(
+            finder.find(&"/repo/packages/app/src/index.ts"),
+            Some(PathBuf::from("/repo/packages/app/tsconfig.json"))
+        );
+        assert_eq!(
+            finder.find(&"/repo/packages/lib/index.ts"),
+            Some(PathBuf::from("/repo/tsconfig.json"))
+        );
+        assert_eq!(
+            finder.find(&"/repo/packages/app"),
+            Some(PathBuf::from("/repo/packages/app/tsconfig.json"))
+        );
+        assert_eq!(finder.find(&"/elsewhere/index.ts"), None);
+    }
+
+    #[test]
+    fn accepts_jsconfig_on_request() {
+        let finder = finder().with_jsconfig(true);
+        let (path, config) = finder
+            .load(&"/repo/packages/app/src/index.js")
+            .unwrap()
+            .unwrap();
+        assert_eq!(path, Path::new("/repo/packages/app/src/jsconfig.json"));
+        assert_eq!(config.compiler_options.unwrap().allow_js, Some(true));
+
+        let (path, config) = finder.load(&"/repo/index.js").unwrap().unwrap();
+        assert_eq!(path, Path::new("/repo/tsconfig.json"));
+        assert!(config.compiler_options.is_none());
+    }
+
+    #[test]
+    fn records_what_found_configs_extend() {
+        let mut fs = MemoryFileSystem::new();
+        fs.insert(
+            "/repo/base.json",
+            r#"{"compilerOptions": {"checkJs": true}}"#,
+        );
+        fs.insert("/repo/jsconfig.json", r#"{"extends": "./base.json"}"#);
+        let finder = ConfigFinder::new()
+            .with_loader(ConfigLoader::new().with_file_system(fs))
+            .with_jsconfig(true);
+
+        let (_, config) = finder.load(&"/repo/index.js").unwrap().unwrap();
+        assert_eq!(
+            config.extends_paths(),
+            Some(&[PathBuf::from("/repo/base.json")][..])
+        );
+        let options = config.compiler_options.unwrap();
+        assert_eq!(options.check_js, Some(true));
+        assert_eq!(options.allow_js, Some(true));
+    }
+}
@@ -0,0 +1,606 @@
+//! Comment- and formatting-preserving edits to TSConfig files.
+//!
+//! A [Document] keeps the original text of a config file, alongside a concrete syntax tree that
+//! records where every object member and array item lives in that text. Edits splice new text into
+//! place, so everything outside the edited value — comments, indentation, trailing commas — is
+//! preserved byte-for-byte.
+//!
+//! ## Example
+//! ```
+//! use tsconfig::edit::Document;
+//!
+//! let mut doc = Document::parse(r#"{
+//!   "compilerOptions": {
+//!     // Keep this comment
+//!     "strict": true, /* and this one */
+//!   }
+//! }"#).unwrap();
+//!
+//! doc.set(&["compilerOptions", "strict"], false).unwrap();
+//! doc.set(&["compilerOptions", "noEmit"], true).unwrap();
+//!
+//! assert_eq!(doc.as_str(), r#"{
+//!   "compilerOptions": {
+//!     // Keep this comment
+//!     "strict": false, /* and this one */
+//!     "noEmit": true,
+//!   }
+//! }"#);
+//! ```
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::jsonc::{self, Token, TokenKind};
+use crate::{parse_to_value, ConfigError, Result, TsConfig};
+
+/// A config file that can be edited without disturbing its formatting.
+#[derive(Debug, Clone)]
+pub struct Document {
+    text: String,
+    root: Node,
+}
+
+impl Document {
+    /// Parses JSONC text into a [Document].
+    pub fn parse(text: impl Into<String>) -> Result<Document> {
+        let text = text.into();
+        // Validate first, so that syntax errors are reported with their position.
+        parse_to_value(&text)?;
+        let root = Node::parse(&text)
+            .ok_or_else(|| ConfigError::InvalidEdit("could not parse document".to_string()))?;
+        Ok(Document { text, root })
+    }
+
+    /// Returns the current text of the document.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Consumes the document, returning its text.
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    /// Parses the current text of the document into a [TsConfig].
+    ///
+    /// A document can hold values this crate can't type, such as those added by other tools, so
+    /// it's parsed as [TsConfig::parse_str_lenient] parses it: those values are left unset, but
+    /// kept in [TsConfig::raw]. Only malformed JSON is an error.
+    pub fn to_config(&self) -> Result<TsConfig> {
+        TsConfig::parse_str_lenient(&self.text).map(|(config, _)| config)
+    }
+
+    /// Returns the value at `path`, where each element of the path is an object key.
+    pub fn get(&self, path: &[&str]) -> Option<Value> {
+        let node = self.root.find(path)?;
+        parse_to_value(&self.text[node.start()..node.end()]).ok()
+    }
+
+    /// Sets the value at `path`, where each element of the path is an object key.
+    ///
+    /// An existing value is replaced in place. Otherwise a new member is appended to the innermost
+    /// existing object, creating any missing intermediate objects, and following the indentation of
+    /// its siblings.
+    pub fn set(&mut self, path: &[&str], value: impl Into<Value>) -> Result<()> {
+        let value = value.into();
+        let (last, parents) = path
+            .split_last()
+            .ok_or_else(|| ConfigError::InvalidEdit("cannot replace the whole document".into()))?;
+
+        // Find the deepest existing object along the path.
+        let mut object = &self.root;
+        let mut depth = 0;
+        while depth < parents.len() {
+            match object.member(parents[depth]) {
+                Some(member) => object = &member.value,
+                None => break,
+            }
+            depth += 1;
+        }
+        let object = match object {
+            Node::Object(object) => object,
+            _ => {
+                return Err(ConfigError::InvalidEdit(format!(
+                    "`{}` is not an object",
+                    path[..depth].join(".")
+                )))
+            }
+        };
+
+        let unit = indent_unit(&self.text);
+        let edit = if depth == parents.len() {
+            if let Some(member) = object.members.iter().find(|m| m.key == *last) {
+                let indent = line_indent(&self.text, member.key_start);
+                let formatted = format_value(&value, indent, &unit, newline(&self.text));
+                (member.value.start(), member.value.end(), formatted)
+            } else {
+                insert_member(&self.text, object, last, &value, &unit)
+            }
+        } else {
+            // Wrap the value in objects for each missing key after the first.
+            let value = parents[depth + 1..]
+                .iter()
+                .chain(std::iter::once(last))
+                .rev()
+                .fold(value, |value, key| {
+                    let mut map = serde_json::Map::new();
+                    map.insert(key.to_string(), value);
+                    Value::Object(map)
+                });
+            insert_member(&self.text, object, parents[depth], &value, &unit)
+        };
+
+        self.splice(vec![edit])
+    }
+
+    /// Removes the member at `path`, returning whether it existed.
+    ///
+    /// If the member is the only thing on its line, the whole line is removed, including any comment
+    /// at its end.
+    pub fn remove(&mut self, path: &[&str]) -> Result<bool> {
+        let (last, parents) = match path.split_last() {
+            Some(split) => split,
+            None => {
+                return Err(ConfigError::InvalidEdit(
+                    "cannot remove the whole document".into(),
+                ))
+            }
+        };
+        let object = match self.root.find(parents) {
+            Some(Node::Object(object)) => object,
+            _ => return Ok(false),
+        };
+        let index = match object.members.iter().position(|m| m.key == *last) {
+            Some(index) => index,
+            None => return Ok(false),
+        };
+
+        let text = &self.text;
+        let member = &object.members[index];
+        let own_end = member.comma.map_or(member.value.end(), |comma| comma + 1);
+        // Removing the last member would leave its predecessor's comma trailing.
+        let prev_comma = match member.comma {
+            None if index > 0 => object.members[index - 1].comma,
+            _ => None,
+        };
+
+        let line_start = text[..member.key_start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[own_end..]
+            .find('\n')
+            .map_or(text.len(), |i| own_end + i);
+        let after = text[own_end..line_end].trim();
+        let alone = text[line_start..member.key_start].trim().is_empty()
+            && (after.is_empty() || after.starts_with("//") || is_block_comment(after));
+
+        let mut edits = Vec::new();
+        if alone {
+            edits.push((line_start, (line_end + 1).min(text.len()), String::new()));
+            if let Some(comma) = prev_comma {
+                edits.push((comma, comma + 1, String::new()));
+            }
+        } else if let Some(comma) = prev_comma {
+            edits.push((comma, member.value.end(), String::new()));
+        } else {
+            let spaces = text[own_end..].len() - text[own_end..].trim_start_matches(' ').len();
+            edits.push((member.key_start, own_end + spaces, String::new()));
+        }
+
+        self.splice(edits)?;
+        Ok(true)
+    }
+
+    /// Applies non-overlapping text edits and re-parses the document.
+    fn splice(&mut self, mut edits: Vec<(usize, usize, String)>) -> Result<()> {
+        let mut text = self.text.clone();
+        edits.sort_by_key(|&(start, _, _)| std::cmp::Reverse(start));
+        for (start, end, replacement) in edits {
+            text.replace_range(start..end, &replacement);
+        }
+        *self = Document::parse(text)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Computes the text edit that appends a member to an object.
+fn insert_member(
+    text: &str,
+    object: &ObjectNode,
+    key: &str,
+    value: &Value,
+    unit: &str,
+) -> (usize, usize, String) {
+    let key = Value::String(key.to_string()).to_string();
+    let nl = newline(text);
+    let close = object.end - 1;
+
+    let (first, last) = match (object.members.first(), object.members.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => {
+            let indent = line_indent(text, object.start);
+            let inner = format!("{}{}", indent, unit);
+            let value = format_value(value, &inner, unit, nl);
+            let replacement = format!("{nl}{}{}: {}{nl}{}", inner, key, value, indent, nl = nl);
+            return (object.start + 1, close, replacement);
+        }
+    };
+
+    if !text[object.start..first.key_start].contains('\n') {
+        // A single-line object stays on a single line.
+        let member = format!("{}: {}", key, format_value(value, "", unit, nl));
+        return match last.comma {
+            Some(comma) => (comma + 1, comma + 1, format!(" {},", member)),
+            None => {
+                let end = last.value.end();
+                (end, end, format!(", {}", member))
+            }
+        };
+    }
+
+    let indent = line_indent(text, first.key_start);
+    let member = format!("{}: {}", key, format_value(value, indent, unit, nl));
+    let anchor = last.comma.map_or(last.value.end(), |comma| comma + 1);
+    // Keep anything that follows the last member on its line, such as a comment, on that line.
+    let eol = match text[anchor..close].find('\n') {
+        Some(i) if text[..anchor + i].ends_with('\r') => anchor + i - 1,
+        Some(i) => anchor + i,
+        None => anchor,
+    };
+    let replacement = match last.comma {
+        Some(_) => format!("{}{}{}{},", &text[anchor..eol], nl, indent, member),
+        None => format!(",{}{}{}{}", &text[anchor..eol], nl, indent, member),
+    };
+    (anchor, eol, replacement)
+}
+
+/// Whether `text` consists of exactly one block comment.
+fn is_block_comment(text: &str) -> bool {
+    text.len() >= 4 && text.starts_with("/*") && text.find("*/") == Some(text.len() - 2)
+}
+
+/// Returns the line ending used by a document.
+fn newline(text: &str) -> &'static str {
+    if text.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+/// Returns the leading whitespace of the line containing `offset`.
+fn line_indent(text: &str, offset: usize) -> &str {
+    let start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = &text[start..];
+    let len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    &line[..len]
+}
+
+/// Guesses the indentation unit of a document from its first indented line.
+fn indent_unit(text: &str) -> String {
+    text.lines()
+        .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+        .find(|indent| !indent.is_empty())
+        .unwrap_or("  ")
+        .to_string()
+}
+
+/// Formats a value as it would appear at the given indentation.
+fn format_value(value: &Value, indent: &str, unit: &str, nl: &str) -> String {
+    let inner = format!("{}{}", indent, unit);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            let members: Vec<String> = map
+                .iter()
+                .map(|(k, v)| {
+                    format!(
+                        "{}{}: {}",
+                        inner,
+                        Value::String(k.clone()),
+                        format_value(v, &inner, unit, nl)
+                    )
+                })
+                .collect();
+            format!(
+                "{{{nl}{}{nl}{}}}",
+                members.join(&format!(",{}", nl)),
+                indent,
+                nl = nl
+            )
+        }
+        Value::Array(items) if items.iter().any(|v| v.is_object() || v.is_array()) => {
+            let items: Vec<String> = items
+                .iter()
+                .map(|v| format!("{}{}", inner, format_value(v, &inner, unit, nl)))
+                .collect();
+            format!(
+                "[{nl}{}{nl}{}]",
+                items.join(&format!(",{}", nl)),
+                indent,
+                nl = nl
+            )
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(Value::to_string).collect();
+            format!("[{}]", items.join(", "))
+        }
+        other => other.to_string(),
+    }
+}
+
+/// A node of the concrete syntax tree. All offsets are byte offsets into the document text.
+#[derive(Debug, Clone)]
+enum Node {
+    Object(ObjectNode),
+    Array { start: usize, end: usize },
+    Scalar { start: usize, end: usize },
+}
+
+#[derive(Debug, Clone)]
+struct ObjectNode {
+    /// Offset of the opening brace.
+    start: usize,
+    /// Offset just past the closing brace.
+    end: usize,
+    members: Vec<MemberNode>,
+}
+
+#[derive(Debug, Clone)]
+struct MemberNode {
+    key: String,
+    key_start: usize,
+    value: Node,
+    /// Offset of the comma following the member, if there is one.
+    comma: Option<usize>,
+}
+
+impl Node {
+    fn parse(text: &str) -> Option<Node> {
+        let tokens: Vec<Token> = jsonc::tokenize(text)
+            .into_iter()
+            .filter(|t| !t.kind.is_trivia())
+            .collect();
+        let mut pos = 0;
+        let node = parse_node(text, &tokens, &mut pos)?;
+        if pos == tokens.len() {
+            Some(node)
+        } else {
+            None
+        }
+    }
+
+    fn start(&self) -> usize {
+        match self {
+            Node::Object(object) => object.start,
+            Node::Array { start, .. } | Node::Scalar { start, .. } => *start,
+        }
+    }
+
+    fn end(&self) -> usize {
+        match self {
+            Node::Object(object) => object.end,
+            Node::Array { end, .. } | Node::Scalar { end, .. } => *end,
+        }
+    }
+
+    fn member(&self, key: &str) -> Option<&MemberNode> {
+        match self {
+            Node::Object(object) => object.members.iter().find(|m| m.key == key),
+            _ => None,
+        }
+    }
+
+    fn find(&self, path: &[&str]) -> Option<&Node> {
+        path.iter()
+            .try_fold(self, |node, key| node.member(key).map(|m| &m.value))
+    }
+}
+
+fn parse_node(text: &str, tokens: &[Token], pos: &mut usize) -> Option<Node> {
+    let token = *tokens.get(*pos)?;
+    *pos += 1;
+    match token.kind {
+        TokenKind::OpenBrace => {
+            let mut members = Vec::new();
+            loop {
+                let key = *tokens.get(*pos)?;
+                *pos += 1;
+                match key.kind {
+                    TokenKind::CloseBrace => break,
+                    TokenKind::String => {}
+                    _ => return None,
+                }
+                if tokens.get(*pos)?.kind != TokenKind::Colon {
+                    return None;
+                }
+                *pos += 1;
+                let value = parse_node(text, tokens, pos)?;
+                let comma = match tokens.get(*pos)?.kind {
+                    TokenKind::Comma => {
+                        *pos += 1;
+                        Some(tokens[*pos - 1].start)
+                    }
+                    _ => None,
+                };
+                members.push(MemberNode {
+                    key: serde_json::from_str(&text[key.start..key.end]).ok()?,
+                    key_start: key.start,
+                    value,
+                    comma,
+                });
+            }
+            Some(Node::Object(ObjectNode {
+                start: token.start,
+                end: tokens[*pos - 1].end,
+                members,
+            }))
+        }
+        TokenKind::OpenBracket => loop {
+            match tokens.get(*pos)?.kind {
+                TokenKind::CloseBracket => {
+                    *pos += 1;
+                    return Some(Node::Array {
+                        start: token.start,
+                        end: tokens[*pos - 1].end,
+                    });
+                }
+                TokenKind::Comma => *pos += 1,
+                _ => {
+                    parse_node(text, tokens, pos)?;
+                }
+            }
+        },
+        TokenKind::String | TokenKind::Number | TokenKind::Word => Some(Node::Scalar {
+            start: token.start,
+            end: token.end,
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WatchFile;
+    use serde_json::json;
+
+    const SOURCE: &str = r#"{
+    // Project settings
+    "compilerOptions": {
+        "target": "es5", /* old */
+        "strict": true,
+        "paths": {}
+    },
+    "include": ["src"]
+}
+"#;
+
+    #[test]
+    fn replaces_values_in_place() {
+        let mut doc = Document::parse(SOURCE).unwrap();
+        doc.set(&["compilerOptions", "target"], "es2022").unwrap();
+        doc.set(&["include"], json!(["src", "test"])).unwrap();
+        assert_eq!(
+            doc.as_str(),
+            SOURCE
+                .replace("\"es5\"", "\"es2022\"")
+                .replace("[\"src\"]", "[\"src\", \"test\"]")
+        );
+        assert_eq!(
+            doc.get(&["compilerOptions", "target"]),
+            Some(json!("es2022"))
+        );
+    }
+
+    #[test]
+    fn inserts_members_with_matching_indentation() {
+        let mut doc = Document::parse(SOURCE).unwrap();
+        doc.set(&["compilerOptions", "noEmit"], true).unwrap();
+        doc.set(&["compilerOptions", "paths", "@app/*"], json!(["src/*"]))
+            .unwrap();
+        doc.set(&["watchOptions", "watchFile", "mode"], "x")
+            .unwrap();
+        assert_eq!(
+            doc.as_str(),
+            r#"{
+    // Project settings
+    "compilerOptions": {
+        "target": "es5", /* old */
+        "strict": true,
+        "paths": {
+            "@app/*": ["src/*"]
+        },
+        "noEmit": true
+    },
+    "include": ["src"],
+    "watchOptions": {
+        "watchFile": {
+            "mode": "x"
+        }
+    }
+}
+"#
+        );
+        doc.to_config().unwrap();
+    }
+
+    #[test]
+    fn inserts_into_inline_objects() {
+        let mut doc = Document::parse(r#"{"compilerOptions": {"strict": true}}"#).unwrap();
+        doc.set(&["compilerOptions", "noEmit"], false).unwrap();
+        assert_eq!(
+            doc.as_str(),
+            r#"{"compilerOptions": {"strict": true, "noEmit": false}}"#
+        );
+    }
+
+    #[test]
+    fn reads_typed_watch_options() {
+        let mut doc = Document::parse(SOURCE).unwrap();
+        doc.set(&["watchOptions", "watchFile"], "useFsEvents")
+            .unwrap();
+        doc.set(&["watchOptions", "excludeDirectories"], json!(["dist"]))
+            .unwrap();
+        let watch = doc.to_config().unwrap().watch_options.unwrap();
+        assert_eq!(watch.watch_file, Some(WatchFile::UseFsEvents));
+        assert_eq!(watch.exclude_directories, Some(vec!["dist".to_string()]));
+
+        doc.set(&["watchOptions", "watchFile"], json!({"mode": "x"}))
+            .unwrap();
+        let config = doc.to_config().unwrap();
+        assert_eq!(config.watch_options, None);
+        assert_eq!(
+            config.raw()["watchOptions"]["watchFile"],
+            json!({"mode": "x"})
+        );
+    }
+
+    #[test]
+    fn removes_members() {
+        let mut doc = Document::parse(SOURCE).unwrap();
+        assert!(doc.remove(&["compilerOptions", "target"]).unwrap());
+        assert!(doc.remove(&["include"]).unwrap());
+        assert!(!doc.remove(&["compilerOptions", "missing"]).unwrap());
+        assert_eq!(
+            doc.as_str(),
+            r#"{
+    // Project settings
+    "compilerOptions": {
+        "strict": true,
+        "paths": {}
+    }
+}
+"#
+        );
+
+        let mut doc = Document::parse(r#"{"a": 1, "b": 2, "c": 3}"#).unwrap();
+        doc.remove(&["b"]).unwrap();
+        assert_eq!(doc.as_str(), r#"{"a": 1, "c": 3}"#);
+        doc.remove(&["c"]).unwrap();
+        assert_eq!(doc.as_str(), r#"{"a": 1}"#);
+    }
+
+    #[test]
+    fn keeps_windows_line_endings() {
+        let mut doc = Document::parse("{\r\n  \"a\": 1 // one\r\n}\r\n").unwrap();
+        doc.set(&["b"], 2).unwrap();
+        assert_eq!(
+            doc.as_str(),
+            "{\r\n  \"a\": 1, // one\r\n  \"b\": 2\r\n}\r\n"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_edits() {
+        let mut doc = Document::parse(SOURCE).unwrap();
+        assert!(doc.set(&["include", "x"], 1).is_err());
+        assert!(doc.set(&[], 1).is_err());
+        assert!(Document::parse("{").is_err());
+    }
+}
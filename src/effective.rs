@@ -0,0 +1,312 @@
+//! Working out the options that tsc computes from others when a config doesn't set them.
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    compiler_options, CompilerOptions, Lib, Module, ModuleResolutionMode, Target, TsConfig,
+};
+
+/// The options that default to the value of `strict`.
+const STRICT_FAMILY: &[&str] = &[
+    "alwaysStrict",
+    "noImplicitAny",
+    "noImplicitThis",
+    "strictBindCallApply",
+    "strictBuiltinIteratorReturn",
+    "strictFunctionTypes",
+    "strictNullChecks",
+    "strictPropertyInitialization",
+    "useUnknownInCatchVariables",
+];
+
+/// Returns the value tsc gives each computed option in `config`, as a config to merge under it.
+pub(crate) fn implied_defaults(config: &TsConfig) -> Value {
+    let unset = CompilerOptions::default();
+    let options = config.compiler_options.as_ref().unwrap_or(&unset);
+    let mut implied = Map::new();
+
+    let target = options.effective_target();
+    let module = options.effective_module();
+    let module_resolution = options.effective_module_resolution();
+    let node_module = matches!(module, Module::Node16 | Module::NodeNext);
+    let es_module_interop = options
+        .es_module_interop
+        .unwrap_or(node_module || module == Module::Preserve);
+    let resolves_exports = matches!(
+        module_resolution,
+        ModuleResolutionMode::Node16
+            | ModuleResolutionMode::NodeNext
+            | ModuleResolutionMode::Bundler
+    );
+    let composite = options.composite.unwrap_or(false);
+    let isolated_modules = options
+        .isolated_modules
+        .unwrap_or(options.verbatim_module_syntax.unwrap_or(false));
+
+    implied.insert("target".to_string(), json!(target));
+    implied.insert("module".to_string(), json!(module));
+    implied.insert("moduleResolution".to_string(), json!(module_resolution));
+    implied.insert(
+        "moduleDetection".to_string(),
+        json!(if node_module { "force" } else { "auto" }),
+    );
+    implied.insert("esModuleInterop".to_string(), json!(es_module_interop));
+    implied.insert(
+        "allowSyntheticDefaultImports".to_string(),
+        json!(
+            es_module_interop
+                || module == Module::System
+                || module_resolution == ModuleResolutionMode::Bundler
+        ),
+    );
+    implied.insert(
+        "resolvePackageJsonExports".to_string(),
+        json!(resolves_exports),
+    );
+    implied.insert(
+        "resolvePackageJsonImports".to_string(),
+        json!(resolves_exports),
+    );
+    implied.insert(
+        "resolveJsonModule".to_string(),
+        json!(module_resolution == ModuleResolutionMode::Bundler),
+    );
+    implied.insert(
+        "declaration".to_string(),
+        json!(options.declaration_enabled()),
+    );
+    implied.insert("incremental".to_string(), json!(composite));
+    implied.insert("isolatedModules".to_string(), json!(isolated_modules));
+    implied.insert("preserveConstEnums".to_string(), json!(isolated_modules));
+    implied.insert(
+        "useDefineForClassFields".to_string(),
+        json!(rank(&target) >= rank(&Target::Es2022)),
+    );
+    let strict = options.strict.unwrap_or(false);
+    for option in STRICT_FAMILY {
+        implied.insert(option.to_string(), json!(strict));
+    }
+    let lib = default_lib(&target).filter(|_| !options.no_lib.unwrap_or(false));
+    if let Some(lib) = lib {
+        implied.insert("lib".to_string(), json!(lib));
+    }
+
+    let mut defaults = Map::new();
+    defaults.insert("compilerOptions".to_string(), Value::Object(implied));
+    let include = config.effective_include();
+    if !include.is_explicit() && !include.patterns().is_empty() {
+        defaults.insert("include".to_string(), json!(include.patterns()));
+    }
+    let exclude = config.effective_exclude();
+    if !exclude.is_explicit() {
+        defaults.insert("exclude".to_string(), json!(exclude.patterns()));
+    }
+    Value::Object(defaults)
+}
+
+/// Sets the options in `value`, the JSON of `config` with its implied defaults, that tsc computes
+/// whatever the config sets them to: `composite` always emits declarations.
+pub(crate) fn force_options(config: &TsConfig, value: &mut Value) {
+    let composite = config
+        .compiler_options
+        .as_ref()
+        .and_then(|options| options.composite)
+        .unwrap_or(false);
+    if let Some(Value::Object(options)) = value.get_mut("compilerOptions") {
+        if composite {
+            options.insert("declaration".to_string(), json!(true));
+        }
+    }
+}
+
+/// Returns the JSON of `config` without the options that are set to the value tsc would give them
+/// anyway, keeping the order of those that remain.
+///
+/// Options are removed one at a time, in the order they're written, and only if the config without
+/// them resolves to the same options, so an option that others default from is kept whenever
+/// removing it would change them. `include` and `exclude` are removed the same way. Options set to
+/// `null` are kept, since they undo an option that the config extends.
+pub(crate) fn minimize(config: &TsConfig) -> Value {
+    let resolved = resolve(config);
+    let mut raw = config.raw().into_owned();
+    let options = match raw.get("compilerOptions") {
+        Some(Value::Object(options)) => options.clone(),
+        _ => Map::new(),
+    };
+    let removable = options
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(name, _)| (Some("compilerOptions"), name.as_str()))
+        .chain(vec![(None, "include"), (None, "exclude")]);
+
+    for (section, name) in removable {
+        let mut candidate = raw.clone();
+        let fields = match section {
+            Some(section) => candidate.get_mut(section),
+            None => Some(&mut candidate),
+        };
+        if let Some(Value::Object(fields)) = fields {
+            if fields.get(name).is_none_or(Value::is_null) {
+                continue;
+            }
+            fields.retain(|key, _| key != name);
+        }
+        let unchanged = TsConfig::from_value(candidate.clone())
+            .is_ok_and(|config| resolve(&config) == resolved);
+        if unchanged {
+            raw = candidate;
+        }
+    }
+    raw
+}
+
+/// The options and files that `config` resolves to, with every option tsc gives a fixed default
+/// filled in as well as those it computes.
+fn resolve(
+    config: &TsConfig,
+) -> (
+    Option<CompilerOptions>,
+    Option<Vec<String>>,
+    Option<Vec<String>>,
+) {
+    let mut value = config.effective().raw;
+    if let Some(Value::Object(options)) = value.get_mut("compilerOptions") {
+        for option in compiler_options() {
+            // Defaults such as `false` and `0`; the rest are descriptions, or computed above.
+            let default = option
+                .default
+                .and_then(|default| serde_json::from_str::<Value>(default).ok());
+            if let Some(default) = default {
+                options.entry(option.name).or_insert(default);
+            }
+        }
+    }
+    let config = TsConfig::from_value(value).expect("tsc's defaults are valid options");
+    (config.compiler_options, config.include, config.exclude)
+}
+
+/// Where `target` comes in the order of releases. Targets this crate doesn't know are taken to be
+/// newer than all those it does.
+pub(crate) fn rank(target: &Target) -> usize {
+    Target::NAMES
+        .iter()
+        .position(|name| *name == target.as_str())
+        .unwrap_or(Target::NAMES.len())
+}
+
+/// The libs that tsc includes for `target` when a config doesn't set `lib`.
+fn default_lib(target: &Target) -> Option<Vec<Lib>> {
+    match target {
+        Target::Es3 | Target::Es5 => Some(vec![
+            Lib::Es5,
+            Lib::Dom,
+            Lib::WebWorkerImportScripts,
+            Lib::ScriptHost,
+        ]),
+        Target::Es2015 => Some(vec![
+            Lib::Es2015,
+            Lib::Dom,
+            Lib::DomIterable,
+            Lib::WebWorkerImportScripts,
+            Lib::ScriptHost,
+        ]),
+        Target::Other(_) => None,
+        target => format!("{}.full", target).parse().ok().map(|lib| vec![lib]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn effective(json: &str) -> TsConfig {
+        TsConfig::parse_str(json).unwrap().effective()
+    }
+
+    #[test]
+    fn derives_module_settings_from_target() {
+        let config = effective(r#"{"compilerOptions": {"target": "es2022"}}"#);
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.module, Some(Module::Es2015));
+        assert_eq!(opts.module_resolution, Some(ModuleResolutionMode::Classic));
+        assert_eq!(opts.lib, Some(vec![Lib::Es2022Full]));
+        assert_eq!(opts.use_define_for_class_fields, Some(true));
+        assert_eq!(opts.es_module_interop, Some(false));
+
+        let opts = effective("{}").compiler_options.unwrap();
+        assert_eq!(opts.target, Some(Target::Es5));
+        assert_eq!(opts.module, Some(Module::CommonJs));
+        assert_eq!(opts.module_resolution, Some(ModuleResolutionMode::Node));
+        assert_eq!(opts.lib.unwrap()[2], Lib::WebWorkerImportScripts);
+    }
+
+    #[test]
+    fn derives_settings_from_node_modules() {
+        let config = effective(r#"{"compilerOptions": {"module": "nodenext", "noLib": true}}"#);
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.target, Some(Target::EsNext));
+        assert_eq!(opts.module_resolution, Some(ModuleResolutionMode::NodeNext));
+        assert_eq!(opts.es_module_interop, Some(true));
+        assert_eq!(opts.allow_synthetic_default_imports, Some(true));
+        assert_eq!(opts.resolve_package_json_exports, Some(true));
+        assert_eq!(opts.lib, None);
+    }
+
+    #[test]
+    fn keeps_options_that_are_set() {
+        let config = effective(
+            r#"{
+                "compilerOptions": {"strict": true, "strictNullChecks": false, "outDir": "dist", "composite": true},
+                "files": ["index.ts"]
+            }"#,
+        );
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.no_implicit_any, Some(true));
+        assert_eq!(opts.strict_null_checks, Some(false));
+        assert_eq!(opts.declaration, Some(true));
+        assert_eq!(config.include, None);
+        assert_eq!(
+            config.exclude.unwrap(),
+            ["node_modules", "bower_components", "jspm_packages", "dist"]
+        );
+
+        let config = effective(r#"{"compilerOptions": {"composite": true, "declaration": false}}"#);
+        assert_eq!(config.compiler_options.unwrap().declaration, Some(true));
+    }
+
+    #[test]
+    fn minimizes_to_options_that_differ_from_defaults() {
+        let config = TsConfig::parse_str(
+            r#"{
+                "compilerOptions": {
+                    "target": "es2022",
+                    "module": "es2015",
+                    "moduleResolution": "classic",
+                    "lib": ["es2022.full"],
+                    "composite": true,
+                    "declaration": true,
+                    "removeComments": false,
+                    "noEmit": null,
+                    "strict": false,
+                    "alwaysStrict": true,
+                    "customOption": false
+                },
+                "exclude": ["node_modules", "bower_components", "jspm_packages"]
+            }"#,
+        )
+        .unwrap();
+        let minimal = config.minimize();
+        assert_eq!(
+            *minimal.raw(),
+            json!({
+                "compilerOptions": {
+                    "target": "es2022",
+                    "composite": true,
+                    "noEmit": null,
+                    "alwaysStrict": true,
+                    "customOption": false
+                }
+            })
+        );
+    }
+}
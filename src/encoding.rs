@@ -0,0 +1,53 @@
+//! Detection of the text encoding of TSConfig files.
+//!
+//! Like tsc, we accept UTF-8 with or without a byte order mark, and UTF-16 in either byte order
+//! as long as it starts with a byte order mark.
+
+use std::io;
+
+/// Decodes the contents of a config file into a string, removing any byte order mark.
+pub(crate) fn decode(bytes: Vec<u8>) -> io::Result<String> {
+    match bytes.as_slice() {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => utf8(rest.to_vec()),
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+        _ => utf8(bytes),
+    }
+}
+
+fn utf8(bytes: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+fn utf16(bytes: &[u8], from_bytes: fn([u8; 2]) -> u16) -> io::Result<String> {
+    let chunks = bytes.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "UTF-16 data has an odd number of bytes",
+        ));
+    }
+    let units = chunks.map(|c| from_bytes([c[0], c[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_each_encoding() {
+        assert_eq!(decode(b"{}".to_vec()).unwrap(), "{}");
+        assert_eq!(decode(b"\xEF\xBB\xBF{}".to_vec()).unwrap(), "{}");
+        assert_eq!(decode(b"\xFF\xFE{\0}\0".to_vec()).unwrap(), "{}");
+        assert_eq!(decode(b"\xFE\xFF\0{\0}".to_vec()).unwrap(), "{}");
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        assert!(decode(b"\xFF\xFE{".to_vec()).is_err());
+        assert!(decode(b"\xC3\x28".to_vec()).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! The `extends` field, which names the configs that a config inherits from.
+
+use std::fmt;
+
+use serde::de::{SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The `extends` field: one config to inherit from, or, since TypeScript 5.0, a list of them.
+///
+/// Each specifier is a path relative to the config, or the name of a package that provides one,
+/// as written in the file. A config's [extends_paths](crate::TsConfig::extends_paths) are the files
+/// they resolved to, if it was loaded from disk.
+///
+/// ```json
+/// { "extends": "./tsconfig.base.json" }
+/// { "extends": ["@tsconfig/strictest", "./tsconfig.base.json"] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Extends {
+    /// A single config, written as a string.
+    Single(String),
+    /// Configs written as a list, which are applied in order, so that each overrides those before
+    /// it.
+    Multiple(Vec<String>),
+}
+
+impl Extends {
+    /// Returns the specifiers as written, in the order they're applied.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let config = TsConfig::parse_str(r#"{"extends": "./base.json"}"#).unwrap();
+    /// assert_eq!(config.extends.unwrap().specifiers(), ["./base.json"]);
+    ///
+    /// let config = TsConfig::parse_str(r#"{"extends": ["./a.json", "./b.json"]}"#).unwrap();
+    /// assert_eq!(config.extends.unwrap().specifiers(), ["./a.json", "./b.json"]);
+    /// ```
+    pub fn specifiers(&self) -> &[String] {
+        match self {
+            Extends::Single(specifier) => std::slice::from_ref(specifier),
+            Extends::Multiple(specifiers) => specifiers,
+        }
+    }
+
+    /// Returns the specifier if there is exactly one, however it was written.
+    pub fn single(&self) -> Option<&str> {
+        match self.specifiers() {
+            [specifier] => Some(specifier),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Extends {
+    fn from(specifier: &str) -> Extends {
+        Extends::Single(specifier.to_string())
+    }
+}
+
+impl From<String> for Extends {
+    fn from(specifier: String) -> Extends {
+        Extends::Single(specifier)
+    }
+}
+
+impl From<Vec<String>> for Extends {
+    fn from(specifiers: Vec<String>) -> Extends {
+        Extends::Multiple(specifiers)
+    }
+}
+
+impl<'de> Deserialize<'de> for Extends {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ExtendsVisitor;
+
+        impl<'de> Visitor<'de> for ExtendsVisitor {
+            type Value = Extends;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a config specifier, or a list of them")
+            }
+
+            fn visit_str<E>(self, specifier: &str) -> Result<Extends, E> {
+                Ok(Extends::Single(specifier.to_string()))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Extends, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut specifiers = Vec::new();
+                while let Some(specifier) = seq.next_element::<String>()? {
+                    specifiers.push(specifier);
+                }
+                Ok(Extends::Multiple(specifiers))
+            }
+        }
+
+        deserializer.deserialize_any(ExtendsVisitor)
+    }
+}
+
+impl Serialize for Extends {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Extends::Single(specifier) => serializer.serialize_str(specifier),
+            Extends::Multiple(specifiers) => specifiers.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Extends {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Extends".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "anyOf": [
+                { "type": "string" },
+                { "type": "array", "items": { "type": "string" } },
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accepts_a_specifier_or_a_list() {
+        let single: Extends = serde_json::from_str(r#""./base.json""#).unwrap();
+        assert_eq!(single, Extends::from("./base.json"));
+        assert_eq!(single.single(), Some("./base.json"));
+
+        let multiple: Extends = serde_json::from_str(r#"["./a.json", "./b.json"]"#).unwrap();
+        assert_eq!(multiple.specifiers(), ["./a.json", "./b.json"]);
+        assert_eq!(multiple.single(), None);
+
+        assert!(serde_json::from_str::<Extends>("true").is_err());
+        assert!(serde_json::from_str::<Extends>("[1]").is_err());
+    }
+}
@@ -0,0 +1,107 @@
+//! The `files`, `include` and `exclude` fields, which choose the files in a project.
+
+use crate::TsConfig;
+
+/// The directories tsc excludes when a config doesn't set `exclude`, besides its output directories.
+pub(crate) const DEFAULT_EXCLUDE: &[&str] = &["node_modules", "bower_components", "jspm_packages"];
+
+/// The list that tsc uses for `files`, `include` or `exclude`: the one the config sets, or the
+/// default that tsc uses when it doesn't.
+///
+/// See [TsConfig::effective_files], [TsConfig::effective_include] and
+/// [TsConfig::effective_exclude].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FilePatterns {
+    patterns: Vec<String>,
+    explicit: bool,
+}
+
+impl FilePatterns {
+    fn new(set: Option<&Vec<String>>, default: impl FnOnce() -> Vec<String>) -> FilePatterns {
+        match set {
+            Some(patterns) => FilePatterns {
+                patterns: patterns.clone(),
+                explicit: true,
+            },
+            None => FilePatterns {
+                patterns: default(),
+                explicit: false,
+            },
+        }
+    }
+
+    /// The patterns tsc uses, whether they were set or are the default.
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Whether the config, or one it extends, set the field, rather than leaving it to the default.
+    pub fn is_explicit(&self) -> bool {
+        self.explicit
+    }
+
+    pub fn into_patterns(self) -> Vec<String> {
+        self.patterns
+    }
+}
+
+/// Returns the files listed in `config`, which are none unless it sets `files`.
+pub(crate) fn files(config: &TsConfig) -> FilePatterns {
+    FilePatterns::new(config.files.as_ref(), Vec::new)
+}
+
+/// Returns the patterns `config` includes, which are everything, `**/*`, unless it sets `include`
+/// or `files`.
+pub(crate) fn include(config: &TsConfig) -> FilePatterns {
+    FilePatterns::new(config.include.as_ref(), || match config.files {
+        Some(_) => Vec::new(),
+        None => vec!["**/*".to_string()],
+    })
+}
+
+/// Returns the patterns `config` excludes, which are the package directories and its output
+/// directories unless it sets `exclude`.
+pub(crate) fn exclude(config: &TsConfig) -> FilePatterns {
+    FilePatterns::new(config.exclude.as_ref(), || {
+        let options = config.compiler_options.as_ref();
+        let out_dirs = options
+            .into_iter()
+            .flat_map(|options| [&options.out_dir, &options.declaration_dir])
+            .filter_map(|dir| dir.clone());
+        DEFAULT_EXCLUDE
+            .iter()
+            .map(|dir| dir.to_string())
+            .chain(out_dirs)
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tells_set_lists_from_defaults() {
+        let config = TsConfig::parse_str(
+            r#"{"files": [], "exclude": [], "compilerOptions": {"declarationDir": "types"}}"#,
+        )
+        .unwrap();
+        let files = config.effective_files();
+        assert!(files.is_explicit() && files.patterns().is_empty());
+        let include = config.effective_include();
+        assert!(!include.is_explicit() && include.patterns().is_empty());
+        let exclude = config.effective_exclude();
+        assert!(exclude.is_explicit() && exclude.patterns().is_empty());
+
+        let config = TsConfig::parse_str(
+            r#"{"include": null, "compilerOptions": {"declarationDir": "types"}}"#,
+        )
+        .unwrap();
+        assert!(!config.effective_files().is_explicit());
+        assert_eq!(config.effective_include().into_patterns(), ["**/*"]);
+        assert_eq!(
+            config.effective_exclude().into_patterns(),
+            ["node_modules", "bower_components", "jspm_packages", "types"]
+        );
+    }
+}
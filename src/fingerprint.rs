@@ -0,0 +1,100 @@
+//! Hashing the effective options of a config.
+
+use std::hash::{Hash, Hasher};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Compiler options whose values tsc reads case-insensitively.
+const CASE_INSENSITIVE_OPTIONS: &[&str] = &[
+    "importsNotUsedAsValues",
+    "jsx",
+    "lib",
+    "module",
+    "moduleDetection",
+    "moduleResolution",
+    "newLine",
+    "target",
+];
+
+/// Hashes `raw`, the JSON of a config, so that configs with the same effective options hash the
+/// same.
+pub(crate) fn fingerprint(raw: &Value) -> u64 {
+    let normalized = normalize(raw, "");
+    let bytes = serde_json::to_vec(&normalized).expect("JSON values always serialize");
+    fnv1a(&bytes)
+}
+
+/// Hashes `value`, one of the config types, as it's serialized at `field` in a config, so that
+/// values that are equal hash the same however the keys of their maps are ordered.
+pub(crate) fn hash<T: Serialize, H: Hasher>(value: &T, field: &str, state: &mut H) {
+    let value = serde_json::to_value(value).expect("config types always serialize");
+    let normalized = normalize(&value, field);
+    serde_json::to_vec(&normalized)
+        .expect("JSON values always serialize")
+        .hash(state);
+}
+
+/// Sorts object keys, lowercases case-insensitive option values, and drops `extends`, whose
+/// contents are already merged in.
+pub(crate) fn normalize(value: &Value, field: &str) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map
+                .iter()
+                .filter(|(key, _)| !(field.is_empty() && *key == "extends"))
+                .collect();
+            entries.sort_by_key(|(key, _)| *key);
+            let map: Map<_, _> = entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let path = match field {
+                        "" => key.clone(),
+                        _ => format!("{}.{}", field, key),
+                    };
+                    (key.clone(), normalize(value, &path))
+                })
+                .collect();
+            Value::Object(map)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(|v| normalize(v, field)).collect()),
+        Value::String(s) if is_case_insensitive(field) => Value::String(s.to_lowercase()),
+        other => other.clone(),
+    }
+}
+
+fn is_case_insensitive(field: &str) -> bool {
+    field
+        .strip_prefix("compilerOptions.")
+        .is_some_and(|option| CASE_INSENSITIVE_OPTIONS.contains(&option))
+}
+
+/// The 64-bit FNV-1a hash, which unlike [std::hash::DefaultHasher] is the same in every release and
+/// on every platform.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fnv1a_matches_reference_values() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn ignores_extends_and_case() {
+        let a = serde_json::json!({"extends": "./base.json", "compilerOptions": {"lib": ["DOM"]}});
+        let b = serde_json::json!({"compilerOptions": {"lib": ["dom"]}});
+        assert_eq!(fingerprint(&a), fingerprint(&b));
+
+        let c = serde_json::json!({"compilerOptions": {"outDir": "Dist"}});
+        let d = serde_json::json!({"compilerOptions": {"outDir": "dist"}});
+        assert_ne!(fingerprint(&c), fingerprint(&d));
+    }
+}
@@ -0,0 +1,171 @@
+//! The filesystem that config files are loaded from.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+/// The file access needed to load configs and resolve `extends`.
+///
+/// [ConfigLoader](crate::ConfigLoader) and [NodeResolver](crate::NodeResolver) go through this trait
+/// for every file they touch. [OsFileSystem] is the real filesystem, and [MemoryFileSystem] holds
+/// files in memory, for tests and for build systems with their own virtual filesystem.
+pub trait FileSystem: Send + Sync {
+    /// Reads the contents of the file at `path`.
+    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// Whether a file, rather than a directory, exists at `path`.
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Returns the canonical form of `path`, which is the same for every path to a given file.
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf>;
+
+    /// Returns the time the file at `path` was last modified, which a
+    /// [ConfigCache](crate::ConfigCache) uses to tell whether the file has changed.
+    ///
+    /// The default implementation returns an error, so files are never cached.
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("modification time of {} is unknown", path.display()),
+        ))
+    }
+}
+
+/// The real filesystem, accessed through [std::fs].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        path.canonicalize()
+    }
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        std::fs::metadata(path)?.modified()
+    }
+}
+
+/// A filesystem held entirely in memory.
+///
+/// Paths are canonicalized by removing `.` components and resolving `..` components, so
+/// `/repo/a/../tsconfig.json` and `/repo/tsconfig.json` are the same file.
+///
+/// ## Example
+/// ```
+/// use tsconfig::{ConfigLoader, MemoryFileSystem};
+///
+/// let mut fs = MemoryFileSystem::new();
+/// fs.insert("/repo/tsconfig.base.json", r#"{"compilerOptions": {"strict": true}}"#);
+/// fs.insert("/repo/app/tsconfig.json", r#"{"extends": "../tsconfig.base.json"}"#);
+///
+/// let loader = ConfigLoader::new().with_file_system(fs);
+/// let config = loader.load(&"/repo/app/tsconfig.json").unwrap();
+/// assert_eq!(config.compiler_options.unwrap().strict, Some(true));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileSystem {
+    files: HashMap<PathBuf, Vec<u8>>,
+}
+
+impl MemoryFileSystem {
+    /// Creates an empty filesystem.
+    pub fn new() -> MemoryFileSystem {
+        MemoryFileSystem::default()
+    }
+
+    /// Adds a file, replacing any file already at `path`.
+    pub fn insert(&mut self, path: impl AsRef<Path>, contents: impl Into<Vec<u8>>) {
+        self.files.insert(normalize(path.as_ref()), contents.into());
+    }
+
+    /// Removes the file at `path`, returning its contents if it existed.
+    pub fn remove(&mut self, path: impl AsRef<Path>) -> Option<Vec<u8>> {
+        self.files.remove(&normalize(path.as_ref()))
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.files
+            .get(&normalize(path))
+            .cloned()
+            .ok_or_else(|| not_found(path))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.files.contains_key(&normalize(path))
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = normalize(path);
+        if self.files.contains_key(&path) {
+            Ok(path)
+        } else {
+            Err(not_found(&path))
+        }
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+    )
+}
+
+/// Removes `.` components from a path, and resolves `..` components where possible, without
+/// touching the filesystem.
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => normalized.push(".."),
+            },
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn memory_paths_are_normalized() {
+        let mut fs = MemoryFileSystem::new();
+        fs.insert("/repo/./app/../tsconfig.json", "{}");
+
+        assert!(fs.exists(Path::new("/repo/tsconfig.json")));
+        assert!(!fs.exists(Path::new("/repo")));
+        assert_eq!(
+            fs.canonicalize(Path::new("/repo/app/../tsconfig.json"))
+                .unwrap(),
+            Path::new("/repo/tsconfig.json")
+        );
+        assert_eq!(
+            fs.read_file(Path::new("/repo/missing.json"))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::NotFound
+        );
+
+        assert_eq!(fs.remove("/repo/tsconfig.json"), Some(b"{}".to_vec()));
+        assert!(!fs.exists(Path::new("/repo/tsconfig.json")));
+    }
+}
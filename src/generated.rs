@@ -0,0 +1,142 @@
+// @generated by scripts/generate-option-tables.js. Do not edit by hand; rerun the script to
+// pick up values added in new TypeScript releases.
+
+/// Defines [Lib](crate::Lib) with the values the `lib` option accepts.
+macro_rules! lib_enum {
+    ($(#[$meta:meta])*) => {
+        option_enum! {
+            $(#[$meta])*
+            pub enum Lib {
+                Es5 = "es5" | "lib.es5.d.ts",
+                Es2015 = "es2015" | "es6" | "lib.es2015.d.ts",
+                Es2016 = "es2016" | "es7" | "lib.es2016.d.ts",
+                Es2017 = "es2017" | "lib.es2017.d.ts",
+                Es2018 = "es2018" | "lib.es2018.d.ts",
+                Es2019 = "es2019" | "lib.es2019.d.ts",
+                Es2020 = "es2020" | "lib.es2020.d.ts",
+                Es2021 = "es2021" | "lib.es2021.d.ts",
+                Es2022 = "es2022" | "lib.es2022.d.ts",
+                Es2023 = "es2023" | "lib.es2023.d.ts",
+                Es2024 = "es2024" | "lib.es2024.d.ts",
+                EsNext = "esnext" | "lib.esnext.d.ts",
+                Dom = "dom" | "lib.dom.d.ts",
+                DomIterable = "dom.iterable" | "lib.dom.iterable.d.ts",
+                DomAsyncIterable = "dom.asynciterable" | "lib.dom.asynciterable.d.ts",
+                WebWorker = "webworker" | "lib.webworker.d.ts",
+                WebWorkerImportScripts = "webworker.importscripts" | "lib.webworker.importscripts.d.ts",
+                ScriptHost = "scripthost" | "lib.scripthost.d.ts",
+                Es2015Core = "es2015.core" | "lib.es2015.core.d.ts",
+                Es2015Generator = "es2015.generator" | "lib.es2015.generator.d.ts",
+                Es2015Iterable = "es2015.iterable" | "lib.es2015.iterable.d.ts",
+                Es2015Promise = "es2015.promise" | "lib.es2015.promise.d.ts",
+                Es2015Proxy = "es2015.proxy" | "lib.es2015.proxy.d.ts",
+                Es2015Reflect = "es2015.reflect" | "lib.es2015.reflect.d.ts",
+                Es2015Symbol = "es2015.symbol" | "lib.es2015.symbol.d.ts",
+                Es2015SymbolWellKnown = "es2015.symbol.wellknown" | "lib.es2015.symbol.wellknown.d.ts",
+                Es2016ArrayInclude = "es2016.array.include" | "lib.es2016.array.include.d.ts",
+                Es2017Object = "es2017.object" | "lib.es2017.object.d.ts",
+                Es2017SharedMemory = "es2017.sharedmemory" | "lib.es2017.sharedmemory.d.ts",
+                Es2017String = "es2017.string" | "lib.es2017.string.d.ts",
+                Es2017Intl = "es2017.intl" | "lib.es2017.intl.d.ts",
+                Es2017TypedArrays = "es2017.typedarrays" | "lib.es2017.typedarrays.d.ts",
+                Es2018Intl = "es2018.intl" | "lib.es2018.intl.d.ts",
+                Es2018Promise = "es2018.promise" | "lib.es2018.promise.d.ts",
+                Es2018RegExp = "es2018.regexp" | "lib.es2018.regexp.d.ts",
+                Es2019Array = "es2019.array" | "lib.es2019.array.d.ts",
+                Es2019Object = "es2019.object" | "lib.es2019.object.d.ts",
+                Es2019String = "es2019.string" | "lib.es2019.string.d.ts",
+                Es2019Symbol = "es2019.symbol" | "lib.es2019.symbol.d.ts",
+                Es2020String = "es2020.string" | "lib.es2020.string.d.ts",
+                Es2020SymbolWellknown = "es2020.symbol.wellknown" | "lib.es2020.symbol.wellknown.d.ts",
+                Es2021Promise = "es2021.promise" | "lib.es2021.promise.d.ts",
+                Es2021String = "es2021.string" | "lib.es2021.string.d.ts",
+                Es2021WeakRef = "es2021.weakref" | "lib.es2021.weakref.d.ts",
+                Es2021Intl = "es2021.intl" | "lib.es2021.intl.d.ts",
+                Es2022Array = "es2022.array" | "lib.es2022.array.d.ts",
+                Es2022Error = "es2022.error" | "lib.es2022.error.d.ts",
+                Es2022Intl = "es2022.intl" | "lib.es2022.intl.d.ts",
+                Es2022Object = "es2022.object" | "lib.es2022.object.d.ts",
+                Es2022SharedMemory = "es2022.sharedmemory" | "lib.es2022.sharedmemory.d.ts",
+                Es2022String = "es2022.string" | "lib.es2022.string.d.ts",
+                Es2022RegExp = "es2022.regexp" | "lib.es2022.regexp.d.ts",
+                Es2023Array = "es2023.array" | "lib.es2023.array.d.ts",
+                Es2023Collection = "es2023.collection" | "lib.es2023.collection.d.ts",
+                Es2023Intl = "es2023.intl" | "lib.es2023.intl.d.ts",
+                Es2024ArrayBuffer = "es2024.arraybuffer" | "lib.es2024.arraybuffer.d.ts",
+                Es2024Collection = "es2024.collection" | "lib.es2024.collection.d.ts",
+                Es2024Object = "es2024.object" | "lib.es2024.object.d.ts",
+                Es2024Promise = "es2024.promise" | "lib.es2024.promise.d.ts",
+                Es2024RegExp = "es2024.regexp" | "lib.es2024.regexp.d.ts",
+                Es2024SharedMemory = "es2024.sharedmemory" | "lib.es2024.sharedmemory.d.ts",
+                Es2024String = "es2024.string" | "lib.es2024.string.d.ts",
+                EsNextArray = "esnext.array" | "lib.esnext.array.d.ts",
+                EsNextSymbol = "esnext.symbol" | "lib.esnext.symbol.d.ts",
+                EsNextAsyncIterable = "esnext.asynciterable" | "lib.esnext.asynciterable.d.ts",
+                EsNextIntl = "esnext.intl" | "lib.esnext.intl.d.ts",
+                EsNextDisposable = "esnext.disposable" | "lib.esnext.disposable.d.ts",
+                Decorators = "decorators" | "lib.decorators.d.ts",
+                DecoratorsLegacy = "decorators.legacy" | "lib.decorators.legacy.d.ts",
+                Es2016Full = "es2016.full" | "lib.es2016.full.d.ts",
+                Es2017Full = "es2017.full" | "lib.es2017.full.d.ts",
+                Es2018Full = "es2018.full" | "lib.es2018.full.d.ts",
+                Es2019Full = "es2019.full" | "lib.es2019.full.d.ts",
+                Es2020Full = "es2020.full" | "lib.es2020.full.d.ts",
+                Es2021Full = "es2021.full" | "lib.es2021.full.d.ts",
+                Es2022Full = "es2022.full" | "lib.es2022.full.d.ts",
+                Es2023Full = "es2023.full" | "lib.es2023.full.d.ts",
+                Es2024Full = "es2024.full" | "lib.es2024.full.d.ts",
+                EsNextFull = "esnext.full" | "lib.esnext.full.d.ts",
+                ..Other
+            }
+        }
+    };
+}
+
+/// Defines [Target](crate::Target) with the values the `target` option accepts.
+macro_rules! target_enum {
+    ($(#[$meta:meta])*) => {
+        option_enum! {
+            $(#[$meta])*
+            pub enum Target {
+                Es3 = "ES3",
+                Es5 = "ES5",
+                Es2015 = "ES2015" | "ES6",
+                Es2016 = "ES2016" | "ES7",
+                Es2017 = "ES2017",
+                Es2018 = "ES2018",
+                Es2019 = "ES2019",
+                Es2020 = "ES2020",
+                Es2021 = "ES2021",
+                Es2022 = "ES2022",
+                Es2023 = "ES2023",
+                Es2024 = "ES2024",
+                EsNext = "ESNext",
+                ..Other
+            }
+        }
+    };
+}
+
+/// Defines [Module](crate::Module) with the values the `module` option accepts.
+macro_rules! module_enum {
+    ($(#[$meta:meta])*) => {
+        option_enum! {
+            $(#[$meta])*
+            pub enum Module {
+                None = "None",
+                CommonJs = "CommonJS",
+                Amd = "AMD",
+                System = "System",
+                Umd = "UMD",
+                Es2015 = "ES2015" | "ES6",
+                Es2020 = "ES2020",
+                Es2022 = "ES2022",
+                EsNext = "ESNext",
+                Node16 = "Node16",
+                NodeNext = "NodeNext",
+                Preserve = "Preserve",
+                ..Other
+            }
+        }
+    };
+}
@@ -0,0 +1,540 @@
+//! The graph of projects connected by project references.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::fs::normalize;
+use crate::loader::relative_dir;
+use crate::solution::reference_paths;
+use crate::{CompilerOptions, ConfigError, ConfigLoader, Project, Result};
+
+/// Every project reachable from a root config by following `references`, as `tsc -b` builds them.
+///
+/// Projects are identified by index into [ProjectGraph::projects]. The root config is always the
+/// first project, and the rest follow in the order they were first referenced, breadth first.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::ProjectGraph;
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/solution/tsconfig.json");
+/// let graph = ProjectGraph::load(&path).unwrap();
+///
+/// // The app references the lib, so the lib is built first.
+/// let order: Vec<_> = graph.build_order().unwrap().iter().map(|p| &p.path).collect();
+/// assert!(order[0].ends_with("lib/tsconfig.lib.json"));
+/// assert!(order[1].ends_with("app/tsconfig.json"));
+/// assert!(order[2].ends_with("solution/tsconfig.json"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProjectGraph {
+    projects: Vec<Project>,
+    /// The indexes of the projects that each project references, in the order they are listed.
+    edges: Vec<Vec<usize>>,
+}
+
+impl ProjectGraph {
+    /// Loads the config at `path`, and every project reachable from it.
+    pub fn load<P: AsRef<Path>>(path: &P) -> Result<ProjectGraph> {
+        ProjectGraph::load_with(&ConfigLoader::new(), path)
+    }
+
+    /// Loads the config at `path`, and every project reachable from it, with `loader`.
+    ///
+    /// Each project is loaded once, however many projects reference it. Circular references are
+    /// allowed here, and reported by [ProjectGraph::build_order].
+    pub fn load_with<P: AsRef<Path>>(loader: &ConfigLoader, path: &P) -> Result<ProjectGraph> {
+        let mut builder = GraphBuilder::new(path.as_ref());
+        while let Some(path) = builder.next_unloaded() {
+            let config = loader.load(&path)?;
+            builder.add(Project { path, config });
+        }
+        Ok(builder.finish())
+    }
+
+    /// Loads the config at `path`, and every project reachable from it, loading projects in parallel.
+    ///
+    /// See [ProjectGraph::load_parallel_with]. It is only available with the `parallel` feature.
+    #[cfg(feature = "parallel")]
+    pub fn load_parallel<P: AsRef<Path>>(path: &P) -> Result<ProjectGraph> {
+        ProjectGraph::load_parallel_with(&ConfigLoader::new(), path)
+    }
+
+    /// Loads the config at `path`, and every project reachable from it, with `loader`, loading
+    /// projects in parallel on Rayon's thread pool.
+    ///
+    /// The graph is the same as [ProjectGraph::load_with] would return, with the projects in the same
+    /// order. Projects are loaded a level at a time: first everything the root references, then
+    /// everything those projects reference, and so on. Sharing a [ConfigCache](crate::ConfigCache)
+    /// through the loader avoids parsing common bases once per project. It is only available with the
+    /// `parallel` feature.
+    #[cfg(feature = "parallel")]
+    pub fn load_parallel_with<P: AsRef<Path>>(
+        loader: &ConfigLoader,
+        path: &P,
+    ) -> Result<ProjectGraph> {
+        use rayon::prelude::*;
+
+        let mut builder = GraphBuilder::new(path.as_ref());
+        loop {
+            let level = builder.take_unloaded();
+            if level.is_empty() {
+                return Ok(builder.finish());
+            }
+            let projects = level
+                .into_par_iter()
+                .map(|path| {
+                    let config = loader.load(&path)?;
+                    Ok(Project { path, config })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            for project in projects {
+                builder.add(project);
+            }
+        }
+    }
+
+    /// Returns every project in the graph, starting with the root.
+    pub fn projects(&self) -> &[Project] {
+        &self.projects
+    }
+
+    /// Returns the root config that the graph was loaded from.
+    pub fn root(&self) -> &Project {
+        &self.projects[0]
+    }
+
+    /// Returns the projects that the project at `index` references.
+    ///
+    /// ## Panics
+    /// Panics if `index` is out of bounds.
+    pub fn references(&self, index: usize) -> impl Iterator<Item = &Project> {
+        self.edges[index].iter().map(move |&i| &self.projects[i])
+    }
+
+    /// Returns every project in an order it can be built in, with each project after the projects it
+    /// references, and the root last.
+    ///
+    /// The order is deterministic: references are visited depth first, in the order they are listed.
+    /// Returns [ConfigError::ReferenceCycle] if projects reference each other in a loop, since then
+    /// there is no such order.
+    pub fn build_order(&self) -> Result<Vec<&Project>> {
+        let mut marks = vec![Mark::Unvisited; self.projects.len()];
+        let mut stack = Vec::new();
+        let mut order = Vec::with_capacity(self.projects.len());
+        self.visit(0, &mut marks, &mut stack, &mut order)?;
+        Ok(order.into_iter().map(|i| &self.projects[i]).collect())
+    }
+
+    fn visit(
+        &self,
+        index: usize,
+        marks: &mut [Mark],
+        stack: &mut Vec<usize>,
+        order: &mut Vec<usize>,
+    ) -> Result<()> {
+        match marks[index] {
+            Mark::Done => return Ok(()),
+            Mark::InProgress => {
+                let start = stack.iter().position(|&i| i == index).unwrap_or(0);
+                let chain = stack[start..]
+                    .iter()
+                    .chain(Some(&index))
+                    .map(|&i| self.projects[i].path.clone())
+                    .collect();
+                return Err(ConfigError::ReferenceCycle { chain });
+            }
+            Mark::Unvisited => {}
+        }
+        marks[index] = Mark::InProgress;
+        stack.push(index);
+        for &reference in &self.edges[index] {
+            self.visit(reference, marks, stack, order)?;
+        }
+        stack.pop();
+        marks[index] = Mark::Done;
+        order.push(index);
+        Ok(())
+    }
+
+    /// Checks that every project reference is to a project that can be referenced, as tsc does
+    /// when it builds the graph.
+    ///
+    /// The problems reported are:
+    ///
+    /// - 6306: a referenced project doesn't set `composite`.
+    /// - 6310: a referenced project sets `noEmit`.
+    /// - 6304: a composite project disables `declaration`.
+    /// - 5069: a project sets `isolatedDeclarations` without `declaration` or `composite`.
+    /// - 6377: a project would write its .tsbuildinfo over that of a project it references, because
+    ///   their `outDir`, `rootDir`, `outFile` or `tsBuildInfoFile` options lead to the same file.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::path::Path;
+    /// use tsconfig::ProjectGraph;
+    ///
+    /// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+    ///     .join("test/solution/tsconfig.json");
+    /// let graph = ProjectGraph::load(&path).unwrap();
+    /// assert!(graph.validate_references().is_empty());
+    /// ```
+    pub fn validate_references(&self) -> Vec<ReferenceDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for (index, project) in self.projects.iter().enumerate() {
+            let options = project.config.compiler_options.as_ref();
+            if options.is_some_and(|o| o.composite == Some(true) && o.declaration == Some(false)) {
+                diagnostics.push(ReferenceDiagnostic {
+                    code: 6304,
+                    path: project.path.clone(),
+                    field: "compilerOptions.declaration".to_string(),
+                    message: "Composite projects may not disable declaration emit.".to_string(),
+                });
+            }
+            let emits_declarations =
+                |o: &CompilerOptions| o.declaration == Some(true) || o.composite == Some(true);
+            if options
+                .is_some_and(|o| o.isolated_declarations == Some(true) && !emits_declarations(o))
+            {
+                diagnostics.push(ReferenceDiagnostic {
+                    code: 5069,
+                    path: project.path.clone(),
+                    field: "compilerOptions.isolatedDeclarations".to_string(),
+                    message: "Option 'isolatedDeclarations' cannot be specified without specifying option 'declaration' or option 'composite'.".to_string(),
+                });
+            }
+
+            let build_info = build_info_path(project);
+            for (position, &reference) in self.edges[index].iter().enumerate() {
+                let referenced = &self.projects[reference];
+                let options = referenced.config.compiler_options.as_ref();
+                let mut report = |code, message| {
+                    diagnostics.push(ReferenceDiagnostic {
+                        code,
+                        path: project.path.clone(),
+                        field: format!("references.{}", position),
+                        message,
+                    })
+                };
+                if options.and_then(|o| o.composite) != Some(true) {
+                    report(
+                        6306,
+                        format!(
+                            "Referenced project '{}' must have setting \"composite\": true.",
+                            referenced.path.display()
+                        ),
+                    );
+                }
+                if options.and_then(|o| o.no_emit) == Some(true) {
+                    report(
+                        6310,
+                        format!(
+                            "Referenced project '{}' may not disable emit.",
+                            referenced.path.display()
+                        ),
+                    );
+                }
+                match &build_info {
+                    Some(path) if build_info_path(referenced).as_ref() == Some(path) => report(
+                        6377,
+                        format!(
+                            "Cannot write file '{}' because it will overwrite '.tsbuildinfo' file generated by referenced project '{}'",
+                            path.display(),
+                            referenced.path.display()
+                        ),
+                    ),
+                    _ => {}
+                }
+            }
+        }
+        diagnostics
+    }
+}
+
+/// A problem with the project references in a [ProjectGraph], found by
+/// [ProjectGraph::validate_references].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceDiagnostic {
+    /// The number of the error tsc reports for the problem, such as 6306.
+    pub code: u32,
+    /// The config that the problem is in.
+    pub path: PathBuf,
+    /// Dotted path of the offending field, such as `references.0`.
+    pub field: String,
+    /// The message tsc gives for the problem.
+    pub message: String,
+}
+
+impl fmt::Display for ReferenceDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: error TS{}: {}",
+            self.path.display(),
+            self.field,
+            self.code,
+            self.message
+        )
+    }
+}
+
+/// Returns where tsc writes the .tsbuildinfo file for a project, if it writes one.
+fn build_info_path(project: &Project) -> Option<PathBuf> {
+    let options = project.config.compiler_options.as_ref()?;
+    if options.composite != Some(true) && options.incremental != Some(true) {
+        return None;
+    }
+    let dir = project.path.parent().unwrap_or_else(|| Path::new(""));
+    if let Some(file) = &options.ts_build_info_file {
+        return Some(normalize(&dir.join(file)));
+    }
+    let without_extension = match &options.out_file {
+        Some(out_file) => dir.join(out_file).with_extension(""),
+        None => {
+            let config = project.path.with_extension("");
+            match (&options.out_dir, &options.root_dir) {
+                (Some(out_dir), Some(root_dir)) => dir
+                    .join(out_dir)
+                    .join(relative_dir(&dir.join(root_dir), &config)),
+                (Some(out_dir), None) => dir.join(out_dir).join(config.file_name()?),
+                (None, _) => config,
+            }
+        }
+    };
+    let mut path = normalize(&without_extension).into_os_string();
+    path.push(".tsbuildinfo");
+    Some(path.into())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mark {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Builds a [ProjectGraph] one project at a time, without doing any IO.
+///
+/// The caller loads each path returned by [GraphBuilder::next_unloaded] and passes the project to
+/// [GraphBuilder::add], until there is nothing left to load.
+struct GraphBuilder {
+    projects: Vec<Project>,
+    edges: Vec<Vec<usize>>,
+    /// The index of each path that has been discovered, loaded or not.
+    indexes: HashMap<PathBuf, usize>,
+    /// Paths that have been discovered but not loaded, in the order they were discovered.
+    unloaded: VecDeque<PathBuf>,
+}
+
+impl GraphBuilder {
+    fn new(root: &Path) -> GraphBuilder {
+        let root = normalize(root);
+        GraphBuilder {
+            projects: Vec::new(),
+            edges: Vec::new(),
+            indexes: vec![(root.clone(), 0)].into_iter().collect(),
+            unloaded: vec![root].into_iter().collect(),
+        }
+    }
+
+    fn next_unloaded(&mut self) -> Option<PathBuf> {
+        self.unloaded.pop_front()
+    }
+
+    #[cfg(feature = "parallel")]
+    fn take_unloaded(&mut self) -> Vec<PathBuf> {
+        self.unloaded.drain(..).collect()
+    }
+
+    fn add(&mut self, project: Project) {
+        let mut edges = Vec::new();
+        for path in reference_paths(&project.path, &project.config) {
+            let index = match self.indexes.get(&path) {
+                Some(&index) => index,
+                None => {
+                    let index = self.indexes.len();
+                    self.indexes.insert(path.clone(), index);
+                    self.unloaded.push_back(path);
+                    index
+                }
+            };
+            edges.push(index);
+        }
+        self.projects.push(project);
+        self.edges.push(edges);
+    }
+
+    fn finish(self) -> ProjectGraph {
+        ProjectGraph {
+            projects: self.projects,
+            edges: self.edges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryFileSystem;
+
+    fn graph(files: &[(&str, &str)]) -> ProjectGraph {
+        let mut fs = MemoryFileSystem::new();
+        for (path, contents) in files {
+            fs.insert(path, *contents);
+        }
+        let loader = ConfigLoader::new().with_file_system(fs);
+        ProjectGraph::load_with(&loader, &"/repo/tsconfig.json").unwrap()
+    }
+
+    fn paths<'a>(projects: impl IntoIterator<Item = &'a Project>) -> Vec<&'a Path> {
+        projects.into_iter().map(|p| p.path.as_path()).collect()
+    }
+
+    #[test]
+    fn orders_shared_references_once() {
+        let graph = graph(&[
+            (
+                "/repo/tsconfig.json",
+                r#"{"files": [], "references": [{"path": "app"}, {"path": "cli"}]}"#,
+            ),
+            (
+                "/repo/app/tsconfig.json",
+                r#"{"references": [{"path": "../core"}]}"#,
+            ),
+            (
+                "/repo/cli/tsconfig.json",
+                r#"{"references": [{"path": "../core"}, {"path": "../app"}]}"#,
+            ),
+            ("/repo/core/tsconfig.json", "{}"),
+        ]);
+
+        assert_eq!(
+            paths(graph.projects()),
+            [
+                Path::new("/repo/tsconfig.json"),
+                Path::new("/repo/app/tsconfig.json"),
+                Path::new("/repo/cli/tsconfig.json"),
+                Path::new("/repo/core/tsconfig.json"),
+            ]
+        );
+        assert_eq!(
+            paths(graph.references(2)),
+            [
+                Path::new("/repo/core/tsconfig.json"),
+                Path::new("/repo/app/tsconfig.json"),
+            ]
+        );
+        assert_eq!(
+            paths(graph.build_order().unwrap()),
+            [
+                Path::new("/repo/core/tsconfig.json"),
+                Path::new("/repo/app/tsconfig.json"),
+                Path::new("/repo/cli/tsconfig.json"),
+                Path::new("/repo/tsconfig.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_reference_cycles() {
+        let graph = graph(&[
+            ("/repo/tsconfig.json", r#"{"references": [{"path": "a"}]}"#),
+            (
+                "/repo/a/tsconfig.json",
+                r#"{"references": [{"path": "../b"}]}"#,
+            ),
+            (
+                "/repo/b/tsconfig.json",
+                r#"{"references": [{"path": "../a"}]}"#,
+            ),
+        ]);
+
+        assert_eq!(graph.projects().len(), 3);
+        match graph.build_order().unwrap_err() {
+            ConfigError::ReferenceCycle { chain } => assert_eq!(
+                chain,
+                [
+                    Path::new("/repo/a/tsconfig.json"),
+                    Path::new("/repo/b/tsconfig.json"),
+                    Path::new("/repo/a/tsconfig.json"),
+                ]
+            ),
+            other => panic!("expected a cycle, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn validates_references() {
+        let graph = graph(&[
+            (
+                "/repo/tsconfig.json",
+                r#"{"files": [], "references": [{"path": "app"}, {"path": "lib"}, {"path": "legacy"}]}"#,
+            ),
+            (
+                "/repo/app/tsconfig.json",
+                r#"{
+                    "compilerOptions": {"composite": true, "outDir": "../dist", "rootDir": "."},
+                    "references": [{"path": "../lib"}]
+                }"#,
+            ),
+            (
+                "/repo/lib/tsconfig.json",
+                r#"{"compilerOptions": {"composite": true, "declaration": false, "tsBuildInfoFile": "../dist/tsconfig.tsbuildinfo"}}"#,
+            ),
+            (
+                "/repo/legacy/tsconfig.json",
+                r#"{"compilerOptions": {"noEmit": true, "isolatedDeclarations": true}}"#,
+            ),
+        ]);
+
+        let found: Vec<_> = graph
+            .validate_references()
+            .into_iter()
+            .map(|d| (d.code, format!("{} {}", d.path.display(), d.field)))
+            .collect();
+        let expected = [
+            (6306, "/repo/tsconfig.json references.2"),
+            (6310, "/repo/tsconfig.json references.2"),
+            (6377, "/repo/app/tsconfig.json references.0"),
+            (6304, "/repo/lib/tsconfig.json compilerOptions.declaration"),
+            (
+                5069,
+                "/repo/legacy/tsconfig.json compilerOptions.isolatedDeclarations",
+            ),
+        ];
+        let expected: Vec<_> = expected.iter().map(|(c, s)| (*c, s.to_string())).collect();
+        assert_eq!(found, expected);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn loads_in_parallel() {
+        let mut fs = MemoryFileSystem::new();
+        let mut root = Vec::new();
+        for i in 0..20 {
+            root.push(format!(r#"{{"path": "p{}"}}"#, i));
+            let next = (i + 1) % 20;
+            fs.insert(
+                format!("/repo/p{}/tsconfig.json", i),
+                format!(
+                    r#"{{"references": [{{"path": "../p{}"}}, {{"path": "../shared"}}]}}"#,
+                    next
+                ),
+            );
+        }
+        fs.insert(
+            "/repo/tsconfig.json",
+            format!(r#"{{"references": [{}]}}"#, root.join(", ")),
+        );
+        fs.insert("/repo/shared/tsconfig.json", "{}");
+
+        let loader = ConfigLoader::new().with_file_system(fs);
+        let serial = ProjectGraph::load_with(&loader, &"/repo/tsconfig.json").unwrap();
+        let parallel = ProjectGraph::load_parallel_with(&loader, &"/repo/tsconfig.json").unwrap();
+        assert_eq!(paths(parallel.projects()), paths(serial.projects()));
+        assert_eq!(parallel.edges, serial.edges);
+    }
+}
@@ -0,0 +1,774 @@
+//! A small tokenizer for JSON with comments (JSONC), the dialect accepted by tsc.
+//!
+//! The tokenizer is lossless: every byte of the input belongs to exactly one token, including
+//! whitespace and comments. This lets us strip comments and trailing commas without ever touching
+//! the contents of string literals.
+
+use std::io::{self, Read};
+
+use serde_json::Value;
+
+use crate::order::Ordered;
+
+/// The kind of a [Token].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    OpenBrace,
+    CloseBrace,
+    OpenBracket,
+    CloseBracket,
+    Colon,
+    Comma,
+    String,
+    Number,
+    /// An unquoted identifier, such as `true`, `false` or `null`.
+    Word,
+    Whitespace,
+    LineComment,
+    BlockComment,
+    /// Any character that cannot start a JSON token.
+    Unknown,
+}
+
+impl TokenKind {
+    /// Whether this token carries no meaning for the JSON value.
+    pub(crate) fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment
+        )
+    }
+}
+
+/// A token, represented as a byte range into the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Splits JSONC source text into tokens.
+///
+/// Unterminated strings and block comments extend to the end of the input, leaving it to the
+/// JSON parser to report the error.
+pub(crate) fn tokenize(src: &str) -> Vec<Token> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let start = pos;
+        let kind = match bytes[pos] {
+            b'{' => {
+                pos += 1;
+                TokenKind::OpenBrace
+            }
+            b'}' => {
+                pos += 1;
+                TokenKind::CloseBrace
+            }
+            b'[' => {
+                pos += 1;
+                TokenKind::OpenBracket
+            }
+            b']' => {
+                pos += 1;
+                TokenKind::CloseBracket
+            }
+            b':' => {
+                pos += 1;
+                TokenKind::Colon
+            }
+            b',' => {
+                pos += 1;
+                TokenKind::Comma
+            }
+            b'"' => {
+                pos += 1;
+                while pos < bytes.len() {
+                    match bytes[pos] {
+                        b'\\' => pos += 2,
+                        b'"' => {
+                            pos += 1;
+                            break;
+                        }
+                        _ => pos += 1,
+                    }
+                }
+                pos = pos.min(bytes.len());
+                TokenKind::String
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                while pos < bytes.len() && bytes[pos] != b'\n' && bytes[pos] != b'\r' {
+                    pos += 1;
+                }
+                TokenKind::LineComment
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos += 2;
+                while pos < bytes.len() && !bytes[pos..].starts_with(b"*/") {
+                    pos += 1;
+                }
+                pos = (pos + 2).min(bytes.len());
+                TokenKind::BlockComment
+            }
+            b' ' | b'\t' | b'\n' | b'\r' => {
+                while pos < bytes.len() && matches!(bytes[pos], b' ' | b'\t' | b'\n' | b'\r') {
+                    pos += 1;
+                }
+                TokenKind::Whitespace
+            }
+            b'-' | b'+' | b'.' | b'0'..=b'9' => {
+                while pos < bytes.len()
+                    && matches!(bytes[pos], b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+                {
+                    pos += 1;
+                }
+                TokenKind::Number
+            }
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'$' => {
+                while pos < bytes.len()
+                    && matches!(bytes[pos], b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'$')
+                {
+                    pos += 1;
+                }
+                TokenKind::Word
+            }
+            _ => {
+                // Consume a whole character so that token boundaries stay on UTF-8 boundaries.
+                pos += src[pos..].chars().next().map_or(1, char::len_utf8);
+                TokenKind::Unknown
+            }
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: pos,
+        });
+    }
+
+    tokens
+}
+
+/// Returns the index of the first non-trivia token after `index`.
+pub(crate) fn next_significant(tokens: &[Token], index: usize) -> Option<usize> {
+    (index + 1..tokens.len()).find(|&i| !tokens[i].kind.is_trivia())
+}
+
+/// Whether the comma at `index` is followed by the end of its object or array.
+pub(crate) fn is_trailing_comma(tokens: &[Token], index: usize) -> bool {
+    tokens[index].kind == TokenKind::Comma
+        && match next_significant(tokens, index) {
+            Some(next) => matches!(
+                tokens[next].kind,
+                TokenKind::CloseBrace | TokenKind::CloseBracket
+            ),
+            None => true,
+        }
+}
+
+/// Removes comments and trailing commas from JSONC text, producing plain JSON.
+///
+/// Removed text is replaced with spaces (line breaks are kept), so every byte offset, line and
+/// column in the output refers to the same place in the original text. This keeps the positions
+/// reported by serde_json errors meaningful to the user.
+pub(crate) fn strip(src: &str) -> String {
+    let mut out = src.to_string();
+    strip_in_place(&mut out);
+    out
+}
+
+/// Like [strip], but overwrites comments and trailing commas in an existing buffer.
+pub(crate) fn strip_in_place(text: &mut String) {
+    let tokens = tokenize(text);
+    let removed: Vec<&Token> = tokens
+        .iter()
+        .enumerate()
+        .filter(|&(i, token)| match token.kind {
+            TokenKind::LineComment | TokenKind::BlockComment => true,
+            TokenKind::Comma => is_trailing_comma(&tokens, i),
+            _ => false,
+        })
+        .map(|(_, token)| token)
+        .collect();
+    if removed.is_empty() {
+        return;
+    }
+
+    let mut bytes = std::mem::take(text).into_bytes();
+    for token in removed {
+        for b in &mut bytes[token.start..token.end] {
+            if !matches!(*b, b'\n' | b'\r') {
+                *b = b' ';
+            }
+        }
+    }
+    // Tokens always cover whole characters, so blanking them cannot split a UTF-8 sequence.
+    *text = String::from_utf8(bytes).expect("stripped JSONC is valid UTF-8");
+}
+
+/// State of the [StripReader] between bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StripState {
+    Normal,
+    String,
+    StringEscape,
+    /// A `/` has been seen, which may start a comment.
+    Slash,
+    LineComment,
+    BlockComment,
+    /// A `*` has been seen inside a block comment, which may end it.
+    BlockCommentStar,
+}
+
+/// A reader that strips comments and trailing commas from JSONC as it streams through.
+///
+/// This produces exactly the same output as [strip], but only buffers the text between a comma and
+/// the next significant character, which is needed to tell whether the comma is trailing.
+pub(crate) struct StripReader<R> {
+    inner: R,
+    state: StripState,
+    out: Vec<u8>,
+    out_pos: usize,
+    /// Index in `out` of a comma that may turn out to be trailing. Nothing from here on may be
+    /// handed out until the next significant character decides its fate.
+    held_comma: Option<usize>,
+    eof: bool,
+}
+
+impl<R: Read> StripReader<R> {
+    pub(crate) fn new(inner: R) -> StripReader<R> {
+        StripReader {
+            inner,
+            state: StripState::Normal,
+            out: Vec::new(),
+            out_pos: 0,
+            held_comma: None,
+            eof: false,
+        }
+    }
+
+    /// Decides whether a held comma is trailing, given the next significant byte.
+    fn resolve_comma(&mut self, next: u8) {
+        if let Some(i) = self.held_comma.take() {
+            if next == b'}' || next == b']' {
+                self.out[i] = b' ';
+            }
+        }
+    }
+
+    fn blank(&mut self, b: u8) {
+        self.out.push(match b {
+            b'\n' | b'\r' => b,
+            _ => b' ',
+        });
+    }
+
+    fn push(&mut self, b: u8) {
+        match self.state {
+            StripState::Normal => match b {
+                b'/' => self.state = StripState::Slash,
+                b' ' | b'\t' | b'\n' | b'\r' => self.out.push(b),
+                _ => {
+                    self.resolve_comma(b);
+                    if b == b',' {
+                        self.held_comma = Some(self.out.len());
+                    } else if b == b'"' {
+                        self.state = StripState::String;
+                    }
+                    self.out.push(b);
+                }
+            },
+            StripState::String => {
+                match b {
+                    b'\\' => self.state = StripState::StringEscape,
+                    b'"' => self.state = StripState::Normal,
+                    _ => {}
+                }
+                self.out.push(b);
+            }
+            StripState::StringEscape => {
+                self.state = StripState::String;
+                self.out.push(b);
+            }
+            StripState::Slash => match b {
+                b'/' => {
+                    self.out.extend_from_slice(b"  ");
+                    self.state = StripState::LineComment;
+                }
+                b'*' => {
+                    self.out.extend_from_slice(b"  ");
+                    self.state = StripState::BlockComment;
+                }
+                _ => {
+                    // A lone slash is not a comment; let the JSON parser reject it.
+                    self.resolve_comma(b'/');
+                    self.out.push(b'/');
+                    self.state = StripState::Normal;
+                    self.push(b);
+                }
+            },
+            StripState::LineComment => {
+                if b == b'\n' || b == b'\r' {
+                    self.state = StripState::Normal;
+                }
+                self.blank(b);
+            }
+            StripState::BlockComment => {
+                if b == b'*' {
+                    self.state = StripState::BlockCommentStar;
+                }
+                self.blank(b);
+            }
+            StripState::BlockCommentStar => {
+                match b {
+                    b'/' => self.state = StripState::Normal,
+                    b'*' => {}
+                    _ => self.state = StripState::BlockComment,
+                }
+                self.blank(b);
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        if self.state == StripState::Slash {
+            self.resolve_comma(b'/');
+            self.out.push(b'/');
+            self.state = StripState::Normal;
+        }
+        // Nothing follows a comma that's still held, so it's trailing.
+        if let Some(i) = self.held_comma.take() {
+            self.out[i] = b' ';
+        }
+    }
+}
+
+impl<R: Read> Read for StripReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut chunk = [0; 8192];
+        loop {
+            let ready = self.held_comma.unwrap_or(self.out.len());
+            if self.out_pos < ready {
+                let n = (ready - self.out_pos).min(buf.len());
+                buf[..n].copy_from_slice(&self.out[self.out_pos..self.out_pos + n]);
+                self.out_pos += n;
+                return Ok(n);
+            }
+            if self.eof {
+                return Ok(0);
+            }
+
+            // Everything before `out_pos` has been handed out, so it can be discarded.
+            self.out.drain(..self.out_pos);
+            if let Some(i) = self.held_comma.as_mut() {
+                *i -= self.out_pos;
+            }
+            self.out_pos = 0;
+
+            let n = match self.inner.read(&mut chunk) {
+                Ok(n) => n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if n == 0 {
+                self.eof = true;
+                self.finish();
+            }
+            for &b in &chunk[..n] {
+                self.push(b);
+            }
+        }
+    }
+}
+
+/// Nesting depth beyond which the recovering parser gives up, to avoid exhausting the stack.
+pub(crate) const MAX_DEPTH: usize = 128;
+
+/// A syntax error found by [parse_recover].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SyntaxError {
+    /// Dotted path of the object or array in which the error occurred.
+    pub path: String,
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Parses JSONC text into a [Value], recovering from syntax errors, along with the order its keys
+/// were written in.
+///
+/// This never fails: missing closing brackets, missing or doubled commas, missing colons and
+/// stray tokens are reported as [SyntaxError]s while parsing carries on, keeping every member that
+/// could be read. If no value can be read at all, [Value::Null] is returned.
+pub(crate) fn parse_recover(src: &str) -> (Ordered, Vec<SyntaxError>) {
+    let tokens = tokenize(src)
+        .into_iter()
+        .filter(|t| !t.kind.is_trivia())
+        .collect();
+    let mut parser = RecoveringParser {
+        src,
+        tokens,
+        pos: 0,
+        errors: Vec::new(),
+    };
+
+    let value = parser.value("", 0).unwrap_or_default();
+    if let Some(token) = parser.peek() {
+        parser.error(
+            "",
+            token,
+            "unexpected content after the end of the document",
+        );
+    }
+    (value, parser.errors)
+}
+
+struct RecoveringParser<'a> {
+    src: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    errors: Vec<SyntaxError>,
+}
+
+impl<'a> RecoveringParser<'a> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<Token> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    fn error(&mut self, path: &str, token: Token, message: &str) {
+        self.errors.push(SyntaxError {
+            path: path.to_string(),
+            message: message.to_string(),
+            start: token.start,
+            end: token.end,
+        });
+    }
+
+    fn eof_error(&mut self, path: &str, message: &str) {
+        let end = self.src.len();
+        self.errors.push(SyntaxError {
+            path: path.to_string(),
+            message: message.to_string(),
+            start: end,
+            end,
+        });
+    }
+
+    fn text(&self, token: Token) -> &'a str {
+        &self.src[token.start..token.end]
+    }
+
+    /// Parses a single value. Closing brackets and commas are left for the enclosing container.
+    fn value(&mut self, path: &str, depth: usize) -> Option<Ordered> {
+        let token = match self.peek() {
+            Some(token) => token,
+            None => {
+                self.eof_error(path, "unexpected end of input, expected a value");
+                return None;
+            }
+        };
+
+        match token.kind {
+            TokenKind::OpenBrace | TokenKind::OpenBracket if depth >= MAX_DEPTH => {
+                self.error(path, token, "too deeply nested");
+                self.pos = self.tokens.len();
+                None
+            }
+            TokenKind::OpenBrace => {
+                self.bump();
+                Some(self.object(path, depth + 1))
+            }
+            TokenKind::OpenBracket => {
+                self.bump();
+                Some(self.array(path, depth + 1))
+            }
+            TokenKind::String | TokenKind::Number => {
+                self.bump();
+                match serde_json::from_str(self.text(token)) {
+                    Ok(value) => Some(Ordered::new(value)),
+                    Err(_) => {
+                        self.error(path, token, "invalid literal");
+                        None
+                    }
+                }
+            }
+            TokenKind::Word => {
+                self.bump();
+                match self.text(token) {
+                    "true" => Some(Ordered::new(Value::Bool(true))),
+                    "false" => Some(Ordered::new(Value::Bool(false))),
+                    "null" => Some(Ordered::new(Value::Null)),
+                    _ => {
+                        self.error(path, token, "unexpected identifier, expected a value");
+                        None
+                    }
+                }
+            }
+            TokenKind::CloseBrace | TokenKind::CloseBracket | TokenKind::Comma => {
+                self.error(path, token, "expected a value");
+                None
+            }
+            _ => {
+                self.bump();
+                self.error(path, token, "expected a value");
+                None
+            }
+        }
+    }
+
+    fn object(&mut self, path: &str, depth: usize) -> Ordered {
+        let mut members = Vec::new();
+        loop {
+            let token = match self.peek() {
+                Some(token) => token,
+                None => {
+                    self.eof_error(path, "unexpected end of input, expected `}`");
+                    break;
+                }
+            };
+
+            match token.kind {
+                TokenKind::CloseBrace => {
+                    self.bump();
+                    break;
+                }
+                TokenKind::CloseBracket => {
+                    self.bump();
+                    self.error(path, token, "expected `}`, found `]`");
+                    break;
+                }
+                TokenKind::Comma => {
+                    self.bump();
+                    self.error(path, token, "unexpected `,`");
+                    continue;
+                }
+                TokenKind::String => {}
+                _ => {
+                    self.error(path, token, "expected a property name");
+                    // Skip the whole offending value, so that its contents aren't mistaken for members.
+                    if self.value(path, depth).is_none() && self.pos < self.tokens.len() {
+                        self.bump();
+                    }
+                    continue;
+                }
+            }
+
+            self.bump();
+            let key: String = match serde_json::from_str(self.text(token)) {
+                Ok(key) => key,
+                Err(_) => {
+                    self.error(path, token, "invalid property name");
+                    continue;
+                }
+            };
+            let member_path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+
+            match self.peek() {
+                Some(t) if t.kind == TokenKind::Colon => {
+                    self.bump();
+                }
+                Some(t) => self.error(&member_path, t, "expected `:`"),
+                None => {
+                    self.eof_error(&member_path, "unexpected end of input, expected `:`");
+                    break;
+                }
+            }
+
+            if let Some(value) = self.value(&member_path, depth) {
+                members.push((key, value));
+            }
+
+            match self.peek() {
+                Some(t) if t.kind == TokenKind::Comma => {
+                    self.bump();
+                }
+                Some(t) if t.kind == TokenKind::CloseBrace => {}
+                Some(t) => self.error(path, t, "expected `,` or `}`"),
+                None => {}
+            }
+        }
+        Ordered::object(members)
+    }
+
+    fn array(&mut self, path: &str, depth: usize) -> Ordered {
+        let mut items = Vec::new();
+        loop {
+            let token = match self.peek() {
+                Some(token) => token,
+                None => {
+                    self.eof_error(path, "unexpected end of input, expected `]`");
+                    break;
+                }
+            };
+
+            match token.kind {
+                TokenKind::CloseBracket => {
+                    self.bump();
+                    break;
+                }
+                TokenKind::CloseBrace => {
+                    self.bump();
+                    self.error(path, token, "expected `]`, found `}`");
+                    break;
+                }
+                TokenKind::Comma => {
+                    self.bump();
+                    self.error(path, token, "unexpected `,`");
+                    continue;
+                }
+                _ => {}
+            }
+
+            let item_path = format!("{}.{}", path, items.len());
+            match self.value(&item_path, depth) {
+                Some(value) => items.push(value),
+                None if self.pos == self.tokens.len() => break,
+                None => {}
+            }
+
+            match self.peek() {
+                Some(t) if t.kind == TokenKind::Comma => {
+                    self.bump();
+                }
+                Some(t) if t.kind == TokenKind::CloseBracket => {}
+                Some(t) => self.error(path, t, "expected `,` or `]`"),
+                None => {}
+            }
+        }
+        Ordered::array(items)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tokenize_is_lossless() {
+        let src = r#"{ "a": [1, -2.5e3, true], /* c */ "b": null // d
+}"#;
+        let tokens = tokenize(src);
+        let joined: String = tokens.iter().map(|t| &src[t.start..t.end]).collect();
+        assert_eq!(joined, src);
+    }
+
+    #[test]
+    fn strips_comments() {
+        let src = "{\n// line\n\"a\": /* block */ 1}";
+        assert_eq!(strip(src), "{\n       \n\"a\":             1}");
+    }
+
+    #[test]
+    fn strips_trailing_commas_in_arrays_and_objects() {
+        let src = r#"{"a": [1, 2, ], "b": {"c": 3, /* x */ }, }"#;
+        assert_eq!(strip(src), r#"{"a": [1, 2  ], "b": {"c": 3          }  }"#);
+    }
+
+    #[test]
+    fn preserves_offsets() {
+        let src = "{\n  /* multi\n     line \u{e9} */\n  \"a\": 1, // x\n}";
+        let stripped = strip(src);
+        assert_eq!(stripped.len(), src.len());
+        assert_eq!(stripped.find("\"a\""), src.find("\"a\""));
+        assert_eq!(stripped.lines().count(), src.lines().count());
+    }
+
+    #[test]
+    fn leaves_strings_untouched() {
+        let src =
+            r#"{"a": "http://example.com", "b": "/* not a comment */", "c": ",}", "d": "\",]"}"#;
+        assert_eq!(strip(src), src);
+    }
+
+    #[test]
+    fn recovers_from_missing_braces() {
+        let (json, errors) =
+            parse_recover(r#"{"compilerOptions": {"strict": true, "outDir": "dist""#);
+        let value = json.value;
+        assert_eq!(value["compilerOptions"]["strict"], Value::Bool(true));
+        assert_eq!(value["compilerOptions"]["outDir"], "dist");
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path, "compilerOptions");
+    }
+
+    #[test]
+    fn recovers_from_bad_separators() {
+        let src = r#"{"a": 1 "b" 2, , "c": [1 2,, 3,], "d": , "e": tru, "f": true}"#;
+        let (json, errors) = parse_recover(src);
+        let value = json.value;
+        assert_eq!(value["a"], 1);
+        assert_eq!(value["b"], 2);
+        assert_eq!(value["c"], serde_json::json!([1, 2, 3]));
+        assert!(value.get("d").is_none());
+        assert!(value.get("e").is_none());
+        assert_eq!(value["f"], true);
+        assert_eq!(errors.len(), 7);
+    }
+
+    #[test]
+    fn recovery_never_panics() {
+        let inputs = [
+            "",
+            "{",
+            "}",
+            "[",
+            "]]]]",
+            ":",
+            "\"",
+            "{\"a",
+            "{\"a\":",
+            "{\"a\" \"b\"}",
+            "{[1]: 2}",
+            "{\"\\u12\": 1}",
+            "/* unterminated",
+            "{\"é\": ü}",
+            "1 2 3",
+        ];
+        for input in inputs.iter() {
+            let _ = parse_recover(input);
+        }
+
+        let deep = "[".repeat(10_000);
+        let (_, errors) = parse_recover(&deep);
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn strip_reader_matches_strip() {
+        let inputs = [
+            "{\n// line\n\"a\": /* block */ 1}",
+            r#"{"a": [1, 2, ], "b": {"c": 3, /* x */ }, }"#,
+            r#"{"a": "http://example.com", "b": "/* not a comment */", "c": ",}", "d": "\",]"}"#,
+            "{\"a\": 1, // trailing\r\n}",
+            "{\"a\": [1,/**/,2] , /***/ }",
+            "{\"é\": 1 / 2}",
+            "[1, /",
+            "{\"compilerOptions\": {}},",
+            "[1], // trailing",
+            "[1], /* unterminated",
+        ];
+        for input in inputs.iter() {
+            let mut out = String::new();
+            // Read one byte at a time to exercise the buffering.
+            let mut reader = StripReader::new(input.as_bytes());
+            let mut byte = [0; 1];
+            while reader.read(&mut byte).unwrap() == 1 {
+                out.push(byte[0] as char);
+            }
+            let expected: String = strip(input).bytes().map(|b| b as char).collect();
+            assert_eq!(out, expected, "input: {}", input);
+        }
+    }
+}
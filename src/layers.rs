@@ -0,0 +1,229 @@
+//! The two stages of a config: the JSON in its file, and the config tsc compiles with.
+//!
+//! These mirror tsc's own `TsConfigSourceFile`, which is what was parsed from the file, and
+//! `ParsedCommandLine`, which is the result of following `extends` and filling in defaults.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::fs::normalize;
+use crate::loader::absolutize_paths;
+use crate::order::Ordered;
+use crate::{CompilerOptions, ConfigLoader, Result, TsConfig};
+
+/// A config file exactly as it's written: `extends` isn't followed, paths are as written, and
+/// nothing tsc computes is filled in.
+///
+/// Read one with [TsConfigJson::read] or [ConfigLoader::read_json], and resolve it into the config
+/// that tsc compiles with using [TsConfigJson::resolve].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsConfigJson {
+    path: PathBuf,
+    canonical: PathBuf,
+    config: TsConfig,
+}
+
+impl TsConfigJson {
+    pub(crate) fn new(path: PathBuf, canonical: PathBuf, config: TsConfig) -> TsConfigJson {
+        TsConfigJson {
+            path,
+            canonical,
+            config,
+        }
+    }
+
+    /// Reads the config file at `path` from disk, without following `extends`.
+    pub fn read<P: AsRef<Path>>(path: &P) -> Result<TsConfigJson> {
+        ConfigLoader::new().read_json(path)
+    }
+
+    /// The path the file was read from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(crate) fn canonical(&self) -> &Path {
+        &self.canonical
+    }
+
+    /// The file's fields, as written.
+    pub fn config(&self) -> &TsConfig {
+        &self.config
+    }
+
+    /// The file's JSON, as written.
+    pub fn raw(&self) -> Cow<'_, Value> {
+        self.config.raw()
+    }
+
+    pub fn into_config(self) -> TsConfig {
+        self.config
+    }
+
+    /// Resolves the file into the config tsc compiles with, following `extends` with
+    /// [NodeResolver](crate::NodeResolver). See [TsConfigJson::resolve_with].
+    pub fn resolve(&self) -> Result<ResolvedTsConfig> {
+        self.resolve_with(&ConfigLoader::new())
+    }
+
+    /// Resolves the file into the config tsc compiles with, following `extends` with `loader`.
+    ///
+    /// Everything the file extends is merged in, the options that tsc computes from others are
+    /// filled in as in [TsConfig::effective], and every relative path is made absolute, including
+    /// those that start with `${configDir}`. Entries in `paths` are the exception when `baseUrl`
+    /// is set, since they're relative to it. Inherited paths are always rebased onto the file's
+    /// directory, whatever `loader` is set to do.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{ConfigLoader, MemoryFileSystem};
+    ///
+    /// let mut fs = MemoryFileSystem::new();
+    /// fs.insert("/repo/tsconfig.base.json", r#"{"compilerOptions": {"outDir": "${configDir}/dist", "strict": true}}"#);
+    /// fs.insert("/repo/app/tsconfig.json", r#"{"extends": "../tsconfig.base.json", "include": ["src"]}"#);
+    /// let loader = ConfigLoader::new().with_file_system(fs);
+    ///
+    /// let json = loader.read_json(&"/repo/app/tsconfig.json").unwrap();
+    /// assert_eq!(json.config().compiler_options, None);
+    ///
+    /// let resolved = json.resolve_with(&loader).unwrap();
+    /// let options = resolved.compiler_options();
+    /// assert_eq!(options.out_dir.as_deref(), Some("/repo/app/dist"));
+    /// assert_eq!(options.no_implicit_any, Some(true));
+    /// assert_eq!(resolved.config().include, Some(vec!["/repo/app/src".to_string()]));
+    /// ```
+    pub fn resolve_with(&self, loader: &ConfigLoader) -> Result<ResolvedTsConfig> {
+        let loaded = loader.resolve_json(self)?;
+        let path = match std::env::current_dir() {
+            Ok(cwd) => normalize(&cwd.join(&self.path)),
+            Err(_) => normalize(&self.path),
+        };
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let effective = TsConfig::from_ordered(loaded.json)?.effective();
+        let mut value = effective.raw().into_owned();
+        absolutize_paths(&mut value, dir);
+        let order = effective.order().clone();
+        let mut config = TsConfig::from_ordered(Ordered { value, order })?;
+        config.set_extends_paths(loaded.bases);
+        Ok(ResolvedTsConfig {
+            path,
+            chain: loaded.files,
+            config,
+        })
+    }
+}
+
+/// A config as tsc compiles with it: everything it extends merged in, every path absolute, and
+/// the options that tsc computes from others filled in.
+///
+/// Make one with [TsConfigJson::resolve], [ResolvedTsConfig::load] or [ConfigLoader::resolve].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedTsConfig {
+    path: PathBuf,
+    chain: Vec<PathBuf>,
+    config: TsConfig,
+}
+
+impl ResolvedTsConfig {
+    /// Reads the config file at `path` from disk and resolves it, as [TsConfigJson::resolve_with]
+    /// describes.
+    pub fn load<P: AsRef<Path>>(path: &P) -> Result<ResolvedTsConfig> {
+        ConfigLoader::new().resolve(path)
+    }
+
+    /// The absolute path of the config file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The directory that the config's paths were resolved against.
+    pub fn dir(&self) -> &Path {
+        self.path.parent().unwrap_or_else(|| Path::new(""))
+    }
+
+    /// The canonical paths of the config file and everything it extends, directly or indirectly,
+    /// starting with its own.
+    pub fn chain(&self) -> &[PathBuf] {
+        &self.chain
+    }
+
+    /// The resolved config.
+    pub fn config(&self) -> &TsConfig {
+        &self.config
+    }
+
+    /// The resolved compiler options, which are always present.
+    pub fn compiler_options(&self) -> &CompilerOptions {
+        self.config
+            .compiler_options
+            .as_ref()
+            .expect("effective configs always have compiler options")
+    }
+
+    pub fn into_config(self) -> TsConfig {
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryFileSystem;
+
+    fn loader() -> ConfigLoader {
+        let mut fs = MemoryFileSystem::new();
+        fs.insert(
+            "/repo/tsconfig.base.json",
+            r#"{"compilerOptions": {"rootDirs": ["src", "${configDir}/generated"], "paths": {"@/*": ["src/*"]}}}"#,
+        );
+        fs.insert(
+            "/repo/app/tsconfig.json",
+            r#"{"extends": "../tsconfig.base.json", "compilerOptions": {"outDir": "dist"}}"#,
+        );
+        fs.insert(
+            "/repo/lib/tsconfig.json",
+            r#"{"extends": "../tsconfig.base.json", "compilerOptions": {"baseUrl": "."}}"#,
+        );
+        ConfigLoader::new()
+            .with_file_system(fs)
+            .with_raw_paths(true)
+    }
+
+    #[test]
+    fn resolves_every_path_against_the_config_dir() {
+        let resolved = loader().resolve(&"/repo/app/tsconfig.json").unwrap();
+        assert_eq!(resolved.dir(), Path::new("/repo/app"));
+        assert_eq!(
+            resolved.chain(),
+            [
+                PathBuf::from("/repo/app/tsconfig.json"),
+                PathBuf::from("/repo/tsconfig.base.json")
+            ]
+        );
+        let options = resolved.compiler_options();
+        assert_eq!(
+            options.root_dirs,
+            Some(vec![
+                "/repo/src".to_string(),
+                "/repo/app/generated".to_string()
+            ])
+        );
+        assert_eq!(options.paths.as_ref().unwrap()["@/*"], ["/repo/src/*"]);
+        assert_eq!(
+            resolved.config().include,
+            Some(vec!["/repo/app/**/*".to_string()])
+        );
+        assert_eq!(
+            resolved.config().exclude.as_ref().unwrap().last().unwrap(),
+            "/repo/app/dist"
+        );
+
+        let resolved = loader().resolve(&"/repo/lib/tsconfig.json").unwrap();
+        let options = resolved.compiler_options();
+        assert_eq!(options.base_url.as_deref(), Some("/repo/lib"));
+        assert_eq!(options.paths.as_ref().unwrap()["@/*"], ["src/*"]);
+    }
+}
@@ -17,16 +17,103 @@
 //!
 //! ```
 
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
 use std::path::{Path, PathBuf};
-use std::{collections::HashMap, io::Read};
+use std::str::FromStr;
+use std::sync::OnceLock;
 
-use json_comments::StripComments;
-use regex::Regex;
-use serde::{Deserialize, Deserializer};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
+use crate::order::{Order, Ordered};
+
 use thiserror::Error;
 
+#[macro_use]
+mod macros;
+// The values of `lib`, `target` and `module`, from scripts/generate-option-tables.js.
+#[macro_use]
+mod generated;
+
+mod accessors;
+mod borrowed;
+mod cache;
+mod deprecation;
+mod descriptions;
+mod diagnostic;
+mod diff;
+mod discover;
+mod effective;
+mod encoding;
+mod extends;
+mod files;
+mod fingerprint;
+mod fs;
+mod graph;
+mod jsonc;
+mod layers;
+mod loader;
+mod options;
+mod order;
+mod paths;
+#[cfg(feature = "pnp")]
+mod pnp;
+mod pointer;
+mod policy;
+#[cfg(feature = "bundled-presets")]
+mod presets;
+mod show_config;
+mod solution;
+mod span;
+mod suggest;
+mod upgrade;
+mod validate;
+#[cfg(feature = "watch")]
+mod watch;
+#[cfg(feature = "workspace")]
+mod workspace;
+mod write;
+
+pub mod edit;
+
+pub use borrowed::{CompilerOptionsRef, CowStr, ExtendsRef, ReferenceRef, TsConfigRef};
+pub use cache::ConfigCache;
+pub use deprecation::DeprecationWarning;
+pub use diagnostic::Diagnostic;
+pub use diff::ConfigDiff;
+pub use discover::{find_config_for, load_config_for, ConfigFinder};
+pub use extends::Extends;
+pub use files::FilePatterns;
+pub use fs::{FileSystem, MemoryFileSystem, OsFileSystem};
+pub use graph::{ProjectGraph, ReferenceDiagnostic};
+pub use layers::{ResolvedTsConfig, TsConfigJson};
+pub use loader::{ConfigLoader, ExtendsResolver, NodeResolver};
+pub use options::{
+    compiler_option, compiler_options, Deprecation, OptionCategory, OptionInfo, OptionKind,
+    TsVersion,
+};
+pub use paths::Paths;
+#[cfg(feature = "pnp")]
+pub use pnp::{PnpFileSystem, PnpResolver};
+pub use policy::{ConfigPolicy, PolicyViolation};
+#[cfg(feature = "bundled-presets")]
+pub use presets::Preset;
+pub use solution::{Project, Solution};
+pub use span::Span;
+pub use upgrade::Migration;
+pub use validate::Validator;
+#[cfg(feature = "watch")]
+pub use watch::ConfigWatcher;
+#[cfg(feature = "workspace")]
+pub use workspace::{discover_workspace, WorkspaceConfig};
+pub use write::{Indent, KeyOrder, WriteOptions};
+
 pub type Result<T, E = ConfigError> = std::result::Result<T, E>;
 
 /// Errors when parsing TsConfig files.
@@ -34,7 +121,7 @@ pub type Result<T, E = ConfigError> = std::result::Result<T, E>;
 #[non_exhaustive]
 #[derive(Debug, Error)]
 pub enum ConfigError {
-    #[error("Could not parse configuration file")]
+    #[error("Could not parse configuration file: {0}")]
     ParseError(#[from] serde_json::Error),
     #[error("Could not read file {0}")]
     CouldNotReadFile(PathBuf, std::io::Error),
@@ -42,26 +129,238 @@ pub enum ConfigError {
     InvalidJsonData(std::io::Error),
     #[error("Could not convert path into UTF-8: {0}")]
     InvalidPath(String),
+    #[error("Unknown field `{0}`")]
+    UnknownField(String),
+    #[error(
+        "Unknown value `{value}` for {field}{}",
+        display_suggestion(suggestion)
+    )]
+    UnknownValue {
+        /// Dotted path of the field, such as `compilerOptions.target`.
+        field: String,
+        value: String,
+        /// The known value closest to `value`, if any is close enough to be a likely misspelling.
+        suggestion: Option<String>,
+    },
+    #[error("Invalid edit: {0}")]
+    InvalidEdit(String),
+    #[error("Invalid TypeScript version `{0}`")]
+    InvalidVersion(String),
+    #[error("Could not resolve `{specifier}`, extended by {}{}", from.display(), display_searched(searched))]
+    UnresolvedExtends {
+        specifier: String,
+        from: PathBuf,
+        /// The paths that were looked at, in order.
+        searched: Vec<PathBuf>,
+        /// The configs that extend the one that couldn't be resolved, directly or indirectly,
+        /// starting with the one being loaded.
+        chain: Vec<PathBuf>,
+    },
+    #[error("Circular extends: {}", display_chain(chain))]
+    ExtendsCycle { chain: Vec<PathBuf> },
+    #[error("Extends chain is deeper than {max_depth}: {}", display_chain(chain))]
+    ExtendsTooDeep {
+        chain: Vec<PathBuf>,
+        max_depth: usize,
+    },
+    #[error("Cannot flatten a config whose `extends` has not been merged in")]
+    ExtendsNotMerged,
+    #[error("Circular project references: {}", display_chain(chain))]
+    ReferenceCycle { chain: Vec<PathBuf> },
+    #[error("Invalid configuration: {}", display_diagnostics(.0))]
+    Validation(Vec<Diagnostic>),
+    #[cfg(feature = "json5")]
+    #[error("Could not parse JSON5 configuration file: {0}")]
+    Json5Error(#[from] json5::Error),
+    #[cfg(feature = "pnp")]
+    #[error("Yarn Plug'n'Play resolution failed: {0}")]
+    PnpError(#[from] ::pnp::Error),
+    #[cfg(feature = "watch")]
+    #[error("Could not watch files: {0}")]
+    WatchError(#[from] notify::Error),
+    #[cfg(feature = "workspace")]
+    #[error("Could not scan workspace: {0}")]
+    WalkError(#[from] ignore::Error),
+}
+
+fn display_chain(chain: &[PathBuf]) -> String {
+    chain
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+fn display_diagnostics(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(Diagnostic::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl ConfigError {
+    /// Returns the line and column, both starting at 1, of a syntax or type error in the JSON of a
+    /// config.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let err = TsConfig::parse_str("{\n  \"compilerOptions\": {\"strict\": 1}\n}").unwrap_err();
+    /// assert_eq!(err.position(), Some((2, 33)));
+    /// ```
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match self {
+            ConfigError::ParseError(err) if err.line() > 0 => Some((err.line(), err.column())),
+            _ => None,
+        }
+    }
+}
+
+fn display_suggestion(suggestion: &Option<String>) -> String {
+    match suggestion {
+        Some(suggestion) => format!("; did you mean `{}`?", suggestion),
+        None => String::new(),
+    }
+}
+
+fn display_searched(searched: &[PathBuf]) -> String {
+    if searched.is_empty() {
+        return String::new();
+    }
+    let paths = searched
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>();
+    format!("; searched {}", paths.join(", "))
+}
+
+/// The error returned when parsing a value for an option that only accepts certain values, such as
+/// `jsx`, and the value isn't one of them.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "unknown value `{value}`, expected one of {}",
+    display_expected(expected)
+)]
+pub struct ParseOptionError {
+    value: String,
+    expected: &'static [&'static str],
+}
+
+impl ParseOptionError {
+    fn new(value: &str, expected: &'static [&'static str]) -> ParseOptionError {
+        ParseOptionError {
+            value: value.to_string(),
+            expected,
+        }
+    }
+
+    /// The value that couldn't be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The values that the option accepts.
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+}
+
+fn display_expected(expected: &[&str]) -> String {
+    expected
+        .iter()
+        .map(|value| format!("`{}`", value))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// The main struct representing a parsed .tsconfig file.
-#[derive(Deserialize, Debug, Clone)]
+///
+/// New fields are added as TypeScript gains them, so this can't be built field by field outside
+/// this crate; start from [TsConfig::default] or one of the parsing functions instead. Each field
+/// also has a getter of the same name and a setter such as [TsConfig::set_include], which writes
+/// the change into the config's [raw](TsConfig::raw) JSON straight away.
+#[non_exhaustive]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compile_on_save: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub exclude: Option<Vec<String>>,
-    pub extends: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<Extends>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub include: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub references: Option<References>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub type_acquisition: Option<TypeAcquisition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_options: Option<WatchOptions>,
+    #[serde(rename = "ts-node")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_node: Option<TsNodeOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub compiler_options: Option<CompilerOptions>,
+    #[serde(skip)]
+    spans: HashMap<String, Span>,
+    #[serde(skip)]
+    raw: Value,
+    #[serde(skip)]
+    order: Order,
+    #[serde(skip)]
+    baseline: Baseline,
+    #[serde(skip)]
+    extends_paths: Option<Vec<PathBuf>>,
+    #[serde(skip)]
+    deprecations: Vec<DeprecationWarning>,
+    #[serde(flatten)]
+    unknown_fields: HashMap<String, Value>,
+}
+
+/// Configs are equal if they have the same fields and the same [JSON](TsConfig::raw). Where each
+/// field was in the source text, and the files that `extends` resolved to, aren't compared.
+impl PartialEq for TsConfig {
+    fn eq(&self, other: &TsConfig) -> bool {
+        self.same_fields(other) && self.json() == other.json()
+    }
+}
+
+impl Eq for TsConfig {}
+
+/// Hashes the config's [fingerprint](TsConfig::fingerprint), so that configs can be used as keys
+/// in a cache.
+impl Hash for TsConfig {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fingerprint().hash(state);
+    }
+}
+
+/// The typed fields that a config's JSON deserializes to, and those fields serialized, which
+/// [TsConfig::json] compares the config against. They're worked out the first time they're
+/// needed, and again whenever the JSON changes.
+#[derive(Clone, Default)]
+struct Baseline(OnceLock<Box<(TsConfig, serde_json::Map<String, Value>)>>);
+
+impl fmt::Debug for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Baseline")
+    }
 }
 
 impl TsConfig {
     /// Parses a .tsconfig file into a [TsConfig].
     ///
     /// The `extends` field will be respected, allowing for one .tsconfig file to inherit properties from another.
+    /// It is resolved with [NodeResolver]; use a [ConfigLoader] to resolve it differently. Relative paths
+    /// inherited from another config are rewritten to be relative to `path`, as [ConfigLoader] describes.
     /// Comments and trailing commas are both allowed, although they are not valid JSON.
+    /// Files may be encoded as UTF-8, with or without a byte order mark, or as UTF-16 with a byte order mark.
     /// ## Example
     ///
     /// Assuming the following .tsconfig files:
@@ -113,9 +412,100 @@ impl TsConfig {
     ///
     /// ```
     pub fn parse_file<P: AsRef<Path>>(path: &P) -> Result<TsConfig> {
-        let values = parse_file_to_value(path)?;
-        let cfg = serde_json::from_value(values)?;
-        Ok(cfg)
+        ConfigLoader::new().load(path)
+    }
+
+    /// Parses a .tsconfig file into a [TsConfig] without blocking the async runtime.
+    ///
+    /// This behaves like [TsConfig::parse_file], but reads files on Tokio's blocking thread pool, so must
+    /// be called from within a Tokio runtime. It is only available with the `async` feature.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::path::Path;
+    /// use tsconfig::TsConfig;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+    ///     .join("test/tsconfig.inherits.json");
+    /// let config = TsConfig::parse_file_async(&path).await.unwrap();
+    /// assert_eq!(config.compiler_options.unwrap().trace_resolution, Some(false));
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn parse_file_async<P: AsRef<Path>>(path: &P) -> Result<TsConfig> {
+        ConfigLoader::new().load_async(path).await
+    }
+
+    /// Parses a jsconfig.json file into a [TsConfig], applying the defaults that a jsconfig implies.
+    ///
+    /// A jsconfig.json is a tsconfig.json with `allowJs`, `allowSyntheticDefaultImports`, `skipLibCheck`
+    /// and `noEmit` enabled, `maxNodeModuleJsDepth` set to 2, and automatic type acquisition turned on.
+    /// These defaults only fill in options that neither the file nor anything it extends has set.
+    pub fn parse_jsconfig_file<P: AsRef<Path>>(path: &P) -> Result<TsConfig> {
+        discover::load_jsconfig(&ConfigLoader::new(), path.as_ref())
+    }
+
+    /// Parses a jsconfig.json string into a [TsConfig], applying the defaults that a jsconfig implies.
+    ///
+    /// See [TsConfig::parse_jsconfig_file] for the defaults. The 'extends' field will be ignored.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// let json = r#"{"compilerOptions": {"checkJs": true, "skipLibCheck": false}}"#;
+    ///
+    /// let config = TsConfig::parse_jsconfig_str(json).unwrap();
+    /// let opts = config.compiler_options.unwrap();
+    /// assert_eq!(opts.allow_js, Some(true));
+    /// assert_eq!(opts.check_js, Some(true));
+    /// assert_eq!(opts.skip_lib_check, Some(false));
+    ///```
+    ///
+    pub fn parse_jsconfig_str(json: &str) -> Result<TsConfig> {
+        let mut json: Ordered = from_jsonc(json)?;
+        merge(&mut json.value, jsconfig_defaults());
+        TsConfig::from_ordered(json)
+    }
+
+    /// Returns the config that `tsc --init` writes, as of TypeScript 5.9, leaving out the options it
+    /// only writes as comments.
+    ///
+    /// This is a realistic starting point for tools that scaffold new projects. [TsConfig::default],
+    /// by contrast, sets nothing at all.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{Module, TsConfig};
+    ///
+    /// let config = TsConfig::tsc_init_default();
+    /// let opts = config.compiler_options.as_ref().unwrap();
+    /// assert_eq!(opts.module, Some(Module::NodeNext));
+    /// assert_eq!(opts.strict, Some(true));
+    /// assert!(config.to_string_pretty().unwrap().contains(r#""skipLibCheck": true"#));
+    ///```
+    ///
+    pub fn tsc_init_default() -> TsConfig {
+        TsConfig::from_ordered(tsc_init_defaults())
+            .expect("the tsc --init options are a valid config")
+    }
+
+    /// Returns the config of a [Preset], such as `@tsconfig/node20`, for tools that scaffold new
+    /// projects to adjust and write out.
+    ///
+    /// It is only available with the `bundled-presets` feature.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{Module, Preset, TsConfig};
+    ///
+    /// let config = TsConfig::preset(Preset::Node20);
+    /// assert_eq!(config.compiler_options.unwrap().module, Some(Module::NodeNext));
+    /// ```
+    #[cfg(feature = "bundled-presets")]
+    pub fn preset(preset: Preset) -> TsConfig {
+        TsConfig::from_ordered(preset.to_json()).expect("bundled presets are valid configs")
     }
 
     /// Parse a JSON string into a single [TsConfig].
@@ -132,116 +522,1437 @@ impl TsConfig {
     ///```
     ///
     pub fn parse_str(json: &str) -> Result<TsConfig> {
-        // Remove trailing commas from objects.
-        let re = Regex::new(r",(?P<valid>\s*})").unwrap();
-        let mut stripped = String::with_capacity(json.len());
-        StripComments::new(json.as_bytes())
-            .read_to_string(&mut stripped)
-            .map_err(|err| ConfigError::InvalidJsonData(err))?;
-        let stripped = re.replace_all(&stripped, "$valid");
-        let r: TsConfig = serde_json::from_str(&stripped)?;
-        Ok(r)
+        TsConfig::from_ordered(from_jsonc(json)?).or_else(|err| {
+            // Errors from a `Value` carry no position, so deserialize the text itself to report one.
+            from_jsonc::<TsConfig>(json).and(Err(err))
+        })
     }
-}
 
-fn merge(a: &mut Value, b: Value) {
-    match (a, b) {
-        (&mut Value::Object(ref mut a), Value::Object(b)) => {
-            for (k, v) in b {
-                merge(a.entry(k).or_insert(Value::Null), v);
+    /// Converts a [serde_json::Value], such as one returned by [parse_file_to_value], into a [TsConfig].
+    ///
+    /// The value is retained alongside the typed fields, and can be retrieved with [TsConfig::raw].
+    /// A value carries no record of the order its keys were written in, so they're written in the
+    /// order its maps iterate them. To edit a parsed config in place, use [TsConfig::set_pointer].
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// use serde_json::json;
+    ///
+    /// let config = TsConfig::from_value(json!({"compilerOptions": {"strict": true}})).unwrap();
+    /// assert_eq!(config.compiler_options.unwrap().strict, Some(true));
+    ///```
+    ///
+    pub fn from_value(value: Value) -> Result<TsConfig> {
+        TsConfig::from_ordered(Ordered::new(value))
+    }
+
+    /// Converts JSON read from text into a [TsConfig], which keeps the order of its keys.
+    pub(crate) fn from_ordered(json: Ordered) -> Result<TsConfig> {
+        let mut config = TsConfig::deserialize(&json.value)?;
+        config.deprecations = deprecation::collect(&json.value);
+        config.raw = json.value;
+        config.order = json.order;
+        Ok(config)
+    }
+
+    /// Merges `overlay` over `base`, as tsc does when `overlay` extends `base`.
+    ///
+    /// This is the routine used to resolve `extends`. Compiler options, watch options and ts-node
+    /// options are merged one at a time, with `overlay`'s value replacing `base`'s. Other fields,
+    /// such as `files`, `include` and `exclude`, replace `base`'s as a whole, and `references` are
+    /// never inherited. Unlike [ConfigLoader], this doesn't rewrite relative paths in `base`.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let base = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true, "paths": {"a": ["a"]}}, "include": ["src"]}"#).unwrap();
+    /// let overlay = TsConfig::parse_str(r#"{"compilerOptions": {"paths": {"b": ["b"]}}, "include": ["lib"]}"#).unwrap();
+    ///
+    /// let config = TsConfig::merge(&base, &overlay);
+    /// assert_eq!(config.include, Some(vec!["lib".to_string()]));
+    /// let opts = config.compiler_options.unwrap();
+    /// assert_eq!(opts.strict, Some(true));
+    /// assert_eq!(opts.paths.unwrap().keys().collect::<Vec<_>>(), ["b"]);
+    ///```
+    ///
+    pub fn merge(base: &TsConfig, overlay: &TsConfig) -> TsConfig {
+        let mut value = match overlay.json() {
+            Cow::Borrowed(Value::Object(raw)) => Value::Object(raw.clone()),
+            Cow::Owned(value @ Value::Object(_)) => value,
+            _ => Value::Object(serde_json::Map::new()),
+        };
+        merge_extended(&mut value, base.json().into_owned());
+        let mut order = overlay.order.clone();
+        order.inherit(&base.order);
+        TsConfig::from_ordered(Ordered { value, order })
+            .expect("merging two configs gives a valid config")
+    }
+
+    /// Returns a copy of this config with the options that tsc computes from others filled in, where
+    /// they aren't set.
+    ///
+    /// `target`, `module` and `moduleResolution` each default from the one before, and `lib` from
+    /// `target`. `esModuleInterop`, `allowSyntheticDefaultImports`, `moduleDetection` and the
+    /// `resolvePackageJson*` options follow from the module settings. `strict` sets its family of
+    /// checks, `composite` sets `incremental` and always turns on `declaration`, and
+    /// `verbatimModuleSyntax` sets `isolatedModules`. `include` and `exclude` get their defaults, with `exclude` leaving out
+    /// the output directories.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{ModuleResolutionMode, TsConfig};
+    ///
+    /// let config = TsConfig::parse_str(r#"{"compilerOptions": {"module": "preserve", "strict": true}}"#).unwrap();
+    /// let opts = config.effective().compiler_options.unwrap();
+    /// assert_eq!(opts.module_resolution, Some(ModuleResolutionMode::Bundler));
+    /// assert_eq!(opts.strict_null_checks, Some(true));
+    ///```
+    ///
+    pub fn effective(&self) -> TsConfig {
+        let mut value = match self.json() {
+            Cow::Borrowed(Value::Object(raw)) => Value::Object(raw.clone()),
+            Cow::Owned(value @ Value::Object(_)) => value,
+            _ => Value::Object(serde_json::Map::new()),
+        };
+        merge(&mut value, effective::implied_defaults(self));
+        effective::force_options(self, &mut value);
+        let order = self.order.clone();
+        TsConfig::from_ordered(Ordered { value, order }).expect("tsc's defaults are valid options")
+    }
+
+    /// Returns the smallest config that tsc treats the same as this one, without the options that
+    /// are set to their default.
+    ///
+    /// Defaults are those of [effective](TsConfig::effective), which follow from the rest of the
+    /// config, and the fixed defaults in the [registry](compiler_options), such as `false`. An
+    /// option is only removed if that leaves every other option the same, so `target` stays when
+    /// `module` would otherwise default from it. Options set to `null` are kept.
+    ///
+    /// Defaults are tsc's own, not those of the configs this one extends, so minimize a
+    /// [flattened](TsConfig::flattened) config when it extends another.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use tsconfig::TsConfig;
+    ///
+    /// let config = TsConfig::parse_str(r#"{
+    ///     "compilerOptions": {"strict": true, "noImplicitAny": true, "allowJs": false, "outDir": "dist"},
+    ///     "include": ["**/*"]
+    /// }"#).unwrap();
+    /// let minimal = config.minimize();
+    /// assert_eq!(*minimal.raw(), json!({"compilerOptions": {"strict": true, "outDir": "dist"}}));
+    ///```
+    ///
+    pub fn minimize(&self) -> TsConfig {
+        let json = Ordered {
+            value: effective::minimize(self),
+            order: self.order.clone(),
+        };
+        TsConfig::from_ordered(json).expect("removing options from a valid config leaves it valid")
+    }
+
+    /// Rewrites the deprecated settings in the config for TypeScript `to`, returning what was
+    /// changed.
+    ///
+    /// Options that have a replacement, such as `out`, are renamed once `to` has deprecated them.
+    /// `importsNotUsedAsValues` and `preserveValueImports` become `verbatimModuleSyntax` if they
+    /// kept imports, and are otherwise removed. `moduleResolution: node` becomes `bundler` if the
+    /// config emits ES modules, and `node10` otherwise. Options without a replacement, and
+    /// `prepend` on references, are removed once `to` no longer accepts them.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use tsconfig::{TsConfig, TsVersion};
+    ///
+    /// let mut config = TsConfig::parse_str(r#"{
+    ///     "compilerOptions": {"module": "esnext", "moduleResolution": "node", "importsNotUsedAsValues": "error"}
+    /// }"#).unwrap();
+    /// let migrations = config.upgrade(TsVersion::new(5, 5));
+    ///
+    /// assert_eq!(
+    ///     *config.raw(),
+    ///     json!({"compilerOptions": {"module": "esnext", "moduleResolution": "bundler", "verbatimModuleSyntax": true}})
+    /// );
+    /// assert_eq!(
+    ///     migrations[0].to_string(),
+    ///     r#"Replaced 'compilerOptions.importsNotUsedAsValues': "error" with 'compilerOptions.verbatimModuleSyntax': true (TypeScript 5.0)."#
+    /// );
+    ///```
+    ///
+    pub fn upgrade(&mut self, to: TsVersion) -> Vec<Migration> {
+        let (raw, migrations) = upgrade::upgrade(self, to);
+        let config =
+            TsConfig::from_value(raw).expect("replacing deprecated options keeps a config valid");
+        for migration in &migrations {
+            if let Some((field, _)) = &migration.to {
+                self.order.rename(&migration.field, field);
             }
         }
-        (a, b) => {
-            if let Value::Null = a {
-                *a = b;
-            }
+        self.replace_with(config);
+        migrations
+    }
+
+    /// Returns a self-contained copy of this config, with the `extends` field removed.
+    ///
+    /// Configs loaded from a file already have everything they extend merged in, so the result
+    /// holds the same options as `tsc --showConfig` would report. See also [resolve_to_flat].
+    ///
+    /// Returns [ConfigError::ExtendsNotMerged] if the config extends another that hasn't been merged
+    /// in, as when it was parsed from a string; see [TsConfig::is_extends_resolved].
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+    ///     .join("test/tsconfig.inherits.json");
+    /// let config = TsConfig::parse_file(&path).unwrap().flattened().unwrap();
+    ///
+    /// assert_eq!(config.extends, None);
+    /// assert!(config.raw().get("extends").is_none());
+    /// assert_eq!(config.compiler_options.unwrap().use_define_for_class_fields, Some(false));
+    ///
+    /// let config = TsConfig::parse_str(r#"{"extends": "./tsconfig.base.json"}"#).unwrap();
+    /// assert!(config.flattened().is_err());
+    ///```
+    ///
+    pub fn flattened(&self) -> Result<TsConfig> {
+        if self.extends.is_some() && !self.is_extends_resolved() {
+            return Err(ConfigError::ExtendsNotMerged);
+        }
+        let mut config = self.clone();
+        config.sync_raw();
+        config.extends = None;
+        config.extends_paths = config.extends_paths.map(|_| Vec::new());
+        if let Value::Object(raw) = &mut config.raw {
+            raw.retain(|key, _| key != "extends");
         }
+        config.baseline = Baseline::default();
+        Ok(config)
     }
-}
 
-/// Parses a .tsconfig file into a [serde_json::Value].
-///
-/// The `extends` field will be respected, allowing for one .tsconfig file to inherit properties from another.
-/// Comments and trailing commas are both allowed, although they are not valid JSON.
-/// ## Example
-///
-/// Assuming the following .tsconfig files:
-///
-/// tsconfig.base.json:
-/// ```json
-/// {
-///     "compilerOptions": {
-///         "useDefineForClassFields": false,
-///         "traceResolution": true,
-///         "jsx": "preserve",
-///     }
-/// }
-/// ```
-/// tsconfig.inherits.json:
-/// ```json
-/// {
-///     "extends": "./tsconfig.base.json",
-///     "compilerOptions": {
-///         "traceResolution": false,
-///         "declaration": true,
-///         "jsx": "react-jsxdev",
-///     }
-/// }
-/// ```
-/// ```
-/// use std::path::Path;
-/// use tsconfig::parse_file_to_value;
-/// use serde_json::Value;
-///
-/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
-///     .join("test/tsconfig.inherits.json");
-/// let config = parse_file_to_value(&path).unwrap();
-///
-/// assert_eq!(
-///     config
-///         ["compilerOptions"]
-///         ["useDefineForClassFields"],
-///     Value::Bool(false)
-/// );
-///
-///
-/// ```
-pub fn parse_file_to_value<P: AsRef<Path>>(path: &P) -> Result<Value> {
-    let s = std::fs::read_to_string(path)
-        .map_err(|err| ConfigError::CouldNotReadFile(path.as_ref().into(), err))?;
-    let mut value = parse_to_value(&s)?;
-
-    if let Value::String(s) = &value["extends"] {
-        // This may or may not have a `.json` extension
-        let extends_path_unchecked = path
-            .as_ref()
-            .parent()
-            .unwrap_or_else(|| Path::new(""))
-            .join(s);
-
-        let extends_path_str = extends_path_unchecked.to_str().ok_or_else(|| {
-            ConfigError::InvalidPath(extends_path_unchecked.to_string_lossy().to_string())
-        })?;
-
-        // Append the extension if it doesn't already have it
-        let extends_path = if extends_path_str.ends_with(&".json") {
-            extends_path_unchecked
-        } else {
-            let with_ext = extends_path_str.to_string() + ".json";
-            Path::new(with_ext.as_str()).to_path_buf()
+    /// Returns the files that [extends](TsConfig::extends) resolved to, in the order they're
+    /// applied, or `None` if it hasn't been resolved.
+    ///
+    /// It is resolved when the config is loaded from disk, as with [TsConfig::parse_file] or
+    /// [ConfigLoader::load], but not when it's parsed from a string or a [Value]. A loaded config
+    /// that extends nothing has resolved to no files.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::path::Path;
+    /// use tsconfig::TsConfig;
+    ///
+    /// let dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test");
+    /// let config = TsConfig::parse_file(&dir.join("tsconfig.inherits.json")).unwrap();
+    /// assert_eq!(config.extends.as_ref().unwrap().specifiers(), ["./b/tsconfig.base.json"]);
+    /// assert_eq!(
+    ///     config.extends_paths().unwrap(),
+    ///     [dir.join("b/tsconfig.base.json").canonicalize().unwrap()]
+    /// );
+    ///
+    /// let config = TsConfig::parse_str(r#"{"extends": "./b/tsconfig.base.json"}"#).unwrap();
+    /// assert_eq!(config.extends_paths(), None);
+    /// ```
+    pub fn extends_paths(&self) -> Option<&[PathBuf]> {
+        self.extends_paths.as_deref()
+    }
+
+    /// Whether [extends](TsConfig::extends) has been resolved to files. See
+    /// [TsConfig::extends_paths].
+    pub fn is_extends_resolved(&self) -> bool {
+        self.extends_paths.is_some()
+    }
+
+    /// Returns the order that the keys of this config were written in.
+    pub(crate) fn order(&self) -> &Order {
+        &self.order
+    }
+
+    /// Records the files that `extends` resolved to, or that there was nothing to resolve.
+    pub(crate) fn set_extends_paths(&mut self, paths: Vec<PathBuf>) {
+        let resolved = match &self.extends {
+            None => true,
+            Some(extends) => extends.specifiers().len() == paths.len(),
         };
-        let extends_value = parse_file_to_value(&extends_path)?;
-        merge(&mut value, extends_value);
+        if resolved {
+            self.extends_paths = Some(paths);
+        }
     }
 
-    Ok(value)
-}
+    /// Replaces this config with `config`, an edited copy of it, keeping what isn't recorded in
+    /// the JSON: the spans of each field, the order of its keys, and the files that `extends`
+    /// resolved to if it's unchanged.
+    fn replace_with(&mut self, mut config: TsConfig) {
+        config.spans = std::mem::take(&mut self.spans);
+        config.order = std::mem::take(&mut self.order);
+        config.order.update(&config.raw);
+        if config.extends == self.extends {
+            config.extends_paths = self.extends_paths.take();
+        }
+        *self = config;
+    }
 
-/// Parse a JSON string into a single [serde_json::Value].
-///
-/// The 'extends' field will be ignored. Comments and trailing commas are both allowed, although they are not valid JSON.
-///
-/// ## Example
+    /// Returns the files tsc includes by name: those listed in `files`, or none if it isn't set.
+    pub fn effective_files(&self) -> FilePatterns {
+        files::files(self)
+    }
+
+    /// Returns the patterns tsc includes files by: those in `include`, or, if it isn't set, `**/*`
+    /// when `files` isn't set either, and nothing when it is.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let config = TsConfig::parse_str(r#"{"include": ["src"]}"#).unwrap();
+    /// assert!(config.effective_include().is_explicit());
+    /// assert_eq!(config.effective_include().patterns(), ["src"]);
+    ///
+    /// let config = TsConfig::parse_str("{}").unwrap();
+    /// assert!(!config.effective_include().is_explicit());
+    /// assert_eq!(config.effective_include().patterns(), ["**/*"]);
+    ///
+    /// let config = TsConfig::parse_str(r#"{"files": ["index.ts"]}"#).unwrap();
+    /// assert!(config.effective_include().patterns().is_empty());
+    /// ```
+    pub fn effective_include(&self) -> FilePatterns {
+        files::include(self)
+    }
+
+    /// Returns the patterns tsc excludes files by: those in `exclude`, or, if it isn't set,
+    /// `node_modules`, `bower_components`, `jspm_packages`, and the `outDir` and `declarationDir`
+    /// if they're set.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let config = TsConfig::parse_str(r#"{"compilerOptions": {"outDir": "dist"}}"#).unwrap();
+    /// let exclude = config.effective_exclude();
+    /// assert!(!exclude.is_explicit());
+    /// assert_eq!(
+    ///     exclude.patterns(),
+    ///     ["node_modules", "bower_components", "jspm_packages", "dist"]
+    /// );
+    /// ```
+    pub fn effective_exclude(&self) -> FilePatterns {
+        files::exclude(self)
+    }
+
+    /// Iterates over the projects this config references, in the order they're listed, or over
+    /// nothing if it sets no `references`.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let json = r#"{"references": [{"path": "./app"}, {"path": "./lib", "prepend": true}]}"#;
+    /// let config = TsConfig::parse_str(json).unwrap();
+    /// let paths: Vec<_> = config.references_iter().map(|reference| reference.path()).collect();
+    /// assert_eq!(paths, ["./app", "./lib"]);
+    /// ```
+    pub fn references_iter(&self) -> impl Iterator<Item = &Reference> {
+        let references = match &self.references {
+            Some(References::References(references)) => references.as_slice(),
+            _ => &[],
+        };
+        references.iter()
+    }
+
+    /// Returns the path of the config each reference points to, given `dir`, the directory this
+    /// config is in. See [Reference::config_path].
+    ///
+    /// ## Example
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use tsconfig::TsConfig;
+    ///
+    /// let json = r#"{"references": [{"path": "./app"}, {"path": "../lib/tsconfig.build.json"}]}"#;
+    /// let config = TsConfig::parse_str(json).unwrap();
+    /// assert_eq!(
+    ///     config.resolve_references(Path::new("/repo/packages")),
+    ///     [
+    ///         PathBuf::from("/repo/packages/app/tsconfig.json"),
+    ///         PathBuf::from("/repo/lib/tsconfig.build.json"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn resolve_references(&self, dir: &Path) -> Vec<PathBuf> {
+        self.references_iter()
+            .map(|reference| reference.config_path(dir))
+            .collect()
+    }
+
+    /// Whether this is a solution-style config, which sets `"files": []` and no `include`, so that it
+    /// contains no files of its own, and exists only to reference other projects.
+    ///
+    /// Editors skip solution-style configs when looking for the project a file belongs to, and
+    /// `tsc -b` builds the referenced projects instead. See [Solution].
+    pub fn is_solution(&self) -> bool {
+        let has_references = match &self.references {
+            Some(References::References(references)) => !references.is_empty(),
+            _ => false,
+        };
+        let no_files = self.files.as_ref().is_some_and(|files| files.is_empty());
+        no_files && self.include.is_none() && has_references
+    }
+
+    /// Returns the JSON value this config was parsed from, including any fields that this crate does not
+    /// model. For configs parsed from a file, this is the value after `extends` has been merged.
+    ///
+    /// Changes made to the typed fields since are written into it, replacing only the fields and
+    /// options that changed, so the rest keep the order and spelling they were written with. This
+    /// is the JSON that serializing, comparing and editing the config work from.
+    ///
+    /// Together with [TsConfig::from_value], this allows read-modify-write workflows that don't lose
+    /// unrecognised options:
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// let json = r#"{"compilerOptions": {"strict": true}, "angularCompilerOptions": {"strictTemplates": true}}"#;
+    ///
+    /// let config = TsConfig::parse_str(json).unwrap();
+    /// let mut raw = config.raw().into_owned();
+    /// raw["compilerOptions"]["strict"] = false.into();
+    ///
+    /// let config = TsConfig::from_value(raw).unwrap();
+    /// assert_eq!(config.compiler_options.as_ref().unwrap().strict, Some(false));
+    /// assert_eq!(
+    ///     config.to_string_pretty().unwrap(),
+    ///     r#"{
+    ///   "angularCompilerOptions": {
+    ///     "strictTemplates": true
+    ///   },
+    ///   "compilerOptions": {
+    ///     "strict": false
+    ///   }
+    /// }"#
+    /// );
+    ///```
+    ///
+    pub fn raw(&self) -> Cow<'_, Value> {
+        self.json()
+    }
+
+    /// Returns the raw JSON value of a single top-level section, such as `compilerOptions`.
+    pub fn raw_section(&self, name: &str) -> Option<Value> {
+        self.json().get(name).cloned()
+    }
+
+    /// Returns the JSON of this config, with the changes made to its typed fields since it was
+    /// parsed written into it.
+    ///
+    /// The fields are compared with those parsed from `raw`, and if any differ, both are serialized
+    /// and only the fields and options whose serialized values differ are written. So `raw` is
+    /// borrowed as it is when nothing has changed.
+    fn json(&self) -> Cow<'_, Value> {
+        let (parsed, before) = &**self.baseline.0.get_or_init(|| {
+            let parsed = TsConfig::deserialize(&self.raw).unwrap_or_else(|_| {
+                // Configs parsed leniently skip the fields that don't deserialize, and so does this.
+                let mut value = self.raw.clone();
+                remove_invalid_fields(&mut value);
+                serde_json::from_value(value).unwrap_or_default()
+            });
+            let before = match serde_json::to_value(&parsed) {
+                Ok(Value::Object(before)) => before,
+                _ => serde_json::Map::new(),
+            };
+            Box::new((parsed, before))
+        });
+        if self.same_fields(parsed) {
+            return Cow::Borrowed(&self.raw);
+        }
+        match serde_json::to_value(self) {
+            Ok(Value::Object(after)) if *before != after => {
+                let mut raw = self.raw.as_object().cloned().unwrap_or_default();
+                write_changes(&mut raw, before, &after);
+                Cow::Owned(Value::Object(raw))
+            }
+            _ => Cow::Borrowed(&self.raw),
+        }
+    }
+
+    /// Whether the typed fields of the two configs are equal.
+    fn same_fields(&self, other: &TsConfig) -> bool {
+        self.compile_on_save == other.compile_on_save
+            && self.exclude == other.exclude
+            && self.extends == other.extends
+            && self.files == other.files
+            && self.include == other.include
+            && self.references == other.references
+            && self.type_acquisition == other.type_acquisition
+            && self.watch_options == other.watch_options
+            && self.ts_node == other.ts_node
+            && self.compiler_options == other.compiler_options
+            && self.unknown_fields == other.unknown_fields
+    }
+
+    /// Writes the changes made to the typed fields into `raw`, so that it's the JSON of the config.
+    fn sync_raw(&mut self) {
+        if let Cow::Owned(raw) = self.json() {
+            self.raw = raw;
+            self.order.update(&self.raw);
+            self.baseline = Baseline::default();
+        }
+    }
+
+    /// Deserializes the JSON of this config after an edit.
+    ///
+    /// Values that a lenient parse skipped are still in the JSON, so those the edit left as they
+    /// were are skipped again rather than failing the edit.
+    fn read_edit(&self, raw: Value) -> Result<TsConfig> {
+        let err = match TsConfig::from_value(raw.clone()) {
+            Ok(config) => return Ok(config),
+            Err(err) => err,
+        };
+        let mut value = raw.clone();
+        for diagnostic in remove_invalid_fields(&mut self.raw.clone()) {
+            let pointer = pointer::from_path(&diagnostic.field);
+            if value.pointer(&pointer) == self.raw.pointer(&pointer) {
+                pointer::remove(&mut value, &pointer);
+            }
+        }
+        let mut config = TsConfig::deserialize(&value).map_err(|_| err)?;
+        config.deprecations = deprecation::collect(&raw);
+        config.raw = raw;
+        Ok(config)
+    }
+
+    /// Returns the value at a JSON Pointer, as RFC 6901 defines them, in the JSON this config was
+    /// parsed from. In a pointer, `~1` stands for `/` and `~0` for `~`.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use tsconfig::TsConfig;
+    /// let json = r#"{"compilerOptions": {"paths": {"@app/*": ["src/app/*"]}}}"#;
+    ///
+    /// let config = TsConfig::parse_str(json).unwrap();
+    /// assert_eq!(
+    ///     config.get_pointer("/compilerOptions/paths/@app~1*/0"),
+    ///     Some(json!("src/app/*"))
+    /// );
+    ///```
+    ///
+    pub fn get_pointer(&self, pointer: &str) -> Option<Value> {
+        self.json().pointer(pointer).cloned()
+    }
+
+    /// Sets the value at a JSON Pointer, updating both the JSON and the typed fields. Changes
+    /// already made to the typed fields are kept.
+    ///
+    /// Missing objects along the pointer are created, and a last token of `-` appends to an array.
+    /// Returns [ConfigError::InvalidEdit] if the pointer can't be followed, or the error from
+    /// deserializing the result if the value doesn't suit the field, leaving the config unchanged.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{Target, TsConfig};
+    ///
+    /// let mut config = TsConfig::parse_str(r#"{"compilerOptions": {"target": "es2020"}}"#).unwrap();
+    /// config.set_pointer("/compilerOptions/target", "es2022").unwrap();
+    /// assert_eq!(config.compiler_options.as_ref().unwrap().target, Some(Target::Es2022));
+    /// assert!(config.set_pointer("/compilerOptions/strict", "yes").is_err());
+    ///```
+    ///
+    pub fn set_pointer(&mut self, pointer: &str, value: impl Into<Value>) -> Result<()> {
+        let mut raw = match self.json() {
+            Cow::Borrowed(Value::Object(raw)) => Value::Object(raw.clone()),
+            Cow::Owned(raw @ Value::Object(_)) => raw,
+            _ => Value::Object(serde_json::Map::new()),
+        };
+        pointer::set(&mut raw, pointer, value.into())?;
+        let config = self.read_edit(raw)?;
+        self.replace_with(config);
+        Ok(())
+    }
+
+    /// Sets the value at a dotted path, such as `compilerOptions.noEmit`, updating both the JSON and
+    /// the typed fields.
+    ///
+    /// Compiler options are checked against the [registry](compiler_option): an option tsc doesn't
+    /// have is a [ConfigError::UnknownField], a value of the wrong type is a
+    /// [ConfigError::InvalidEdit], and an unknown enum value is a [ConfigError::UnknownValue].
+    /// Other top-level fields must be ones this crate knows. Numeric segments index into arrays, and
+    /// keys that contain dots need [set_pointer](TsConfig::set_pointer) instead. On error, the
+    /// config is left unchanged.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{ConfigError, TsConfig};
+    ///
+    /// let mut config = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true}}"#).unwrap();
+    /// config.set("compilerOptions.noEmit", true).unwrap();
+    /// config.set("compilerOptions.paths.@app/*", vec!["src/app/*"]).unwrap();
+    /// assert_eq!(config.compiler_options.as_ref().unwrap().no_emit, Some(true));
+    ///
+    /// let err = config.set("compilerOptions.noEmti", true).unwrap_err();
+    /// assert!(matches!(err, ConfigError::UnknownField(field) if field == "compilerOptions.noEmti"));
+    /// let err = config.set("compilerOptions.noEmit", "yes").unwrap_err();
+    /// assert_eq!(err.to_string(), r#"Invalid edit: `compilerOptions.noEmit` must be a boolean, not "yes""#);
+    ///```
+    ///
+    pub fn set(&mut self, path: &str, value: impl Into<Value>) -> Result<()> {
+        let value = value.into();
+        let mut segments = path.split('.');
+        let field = segments.next().unwrap_or_default();
+        if let ("compilerOptions", Some(name)) = (field, segments.next()) {
+            let field = format!("compilerOptions.{}", name);
+            let option =
+                compiler_option(name).ok_or_else(|| ConfigError::UnknownField(field.clone()))?;
+            if segments.next().is_none() && !value.is_null() {
+                if !option.kind.accepts(&value) {
+                    return Err(ConfigError::InvalidEdit(format!(
+                        "`{}` must be {}, not {}",
+                        field,
+                        option.kind.describe(),
+                        value
+                    )));
+                }
+                let single = serde_json::json!({ "compilerOptions": { name: value.clone() } });
+                TsConfig::from_value(single)?.check_unknown_values()?;
+            }
+        }
+
+        let mut config = self.clone();
+        config.set_pointer(&pointer::from_path(path), value)?;
+        if config.unknown_fields.contains_key(field) && !self.unknown_fields.contains_key(field) {
+            return Err(ConfigError::UnknownField(field.to_string()));
+        }
+        *self = config;
+        Ok(())
+    }
+
+    /// Removes the value at a dotted path, such as `compilerOptions.paths`, returning it, or `None`
+    /// if it wasn't set.
+    ///
+    /// Numeric segments index into arrays, and removing an array item moves those after it down.
+    /// Returns the error from deserializing the result if removing the value leaves a field
+    /// invalid, such as the `path` of a reference, leaving the config unchanged.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use tsconfig::TsConfig;
+    ///
+    /// let mut config = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true, "noEmit": true}}"#).unwrap();
+    /// assert_eq!(config.unset("compilerOptions.noEmit").unwrap(), Some(json!(true)));
+    /// assert_eq!(config.unset("compilerOptions.noEmit").unwrap(), None);
+    /// assert_eq!(config.compiler_options.unwrap().no_emit, None);
+    ///```
+    ///
+    pub fn unset(&mut self, path: &str) -> Result<Option<Value>> {
+        let mut raw = self.json().into_owned();
+        let removed = match pointer::remove(&mut raw, &pointer::from_path(path)) {
+            Some(removed) => removed,
+            None => return Ok(None),
+        };
+        let config = self.read_edit(raw)?;
+        self.replace_with(config);
+        Ok(Some(removed))
+    }
+
+    /// Applies an RFC 7386 JSON Merge Patch to the config, such as environment-specific overrides
+    /// for a base config.
+    ///
+    /// Objects in `patch` are merged into the config recursively, `null` removes a field, and any
+    /// other value replaces the field as a whole. If the result isn't a valid config, the error is
+    /// returned and the config is left unchanged.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use tsconfig::TsConfig;
+    ///
+    /// let mut config = TsConfig::parse_str(r#"{"compilerOptions": {"sourceMap": true, "outDir": "dist"}}"#).unwrap();
+    /// config
+    ///     .apply_merge_patch(json!({"compilerOptions": {"sourceMap": null, "removeComments": true}}))
+    ///     .unwrap();
+    ///
+    /// let opts = config.compiler_options.unwrap();
+    /// assert_eq!(opts.source_map, None);
+    /// assert_eq!(opts.remove_comments, Some(true));
+    /// assert_eq!(opts.out_dir.as_deref(), Some("dist"));
+    ///```
+    ///
+    pub fn apply_merge_patch(&mut self, patch: Value) -> Result<()> {
+        let mut raw = self.json().into_owned();
+        merge_patch(&mut raw, patch);
+        let config = self.read_edit(raw)?;
+        self.replace_with(config);
+        Ok(())
+    }
+
+    /// Returns a warning for each deprecated option the config sets, such as `out` or `prepend`
+    /// on a reference, including those inherited through `extends`.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// let json = r#"{"compilerOptions": {"out": "bundle.js", "strict": true}}"#;
+    ///
+    /// let config = TsConfig::parse_str(json).unwrap();
+    /// let warnings = config.deprecations();
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].option, "out");
+    /// assert_eq!(warnings[0].replacement, Some("outFile"));
+    ///```
+    ///
+    pub fn deprecations(&self) -> &[DeprecationWarning] {
+        &self.deprecations
+    }
+
+    /// Deserializes a top-level section into a type of the caller's choosing.
+    ///
+    /// This is intended for sections added by other tools, such as `angularCompilerOptions` or
+    /// `vueCompilerOptions`, which this crate does not model. Returns `Ok(None)` if the section is absent.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde::Deserialize;
+    /// use tsconfig::TsConfig;
+    ///
+    /// #[derive(Deserialize)]
+    /// #[serde(rename_all = "camelCase")]
+    /// struct AngularCompilerOptions {
+    ///     strict_templates: Option<bool>,
+    /// }
+    ///
+    /// let json = r#"{"angularCompilerOptions": {"strictTemplates": true}}"#;
+    /// let config = TsConfig::parse_str(json).unwrap();
+    ///
+    /// let angular: AngularCompilerOptions = config.section("angularCompilerOptions").unwrap().unwrap();
+    /// assert_eq!(angular.strict_templates, Some(true));
+    /// assert!(config.section::<AngularCompilerOptions>("vueCompilerOptions").unwrap().is_none());
+    ///```
+    ///
+    pub fn section<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>> {
+        match self.raw_section(name) {
+            Some(value) => Ok(Some(T::deserialize(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns a hash of the options this config sets, for use as a cache key by build tools.
+    ///
+    /// The hash only changes when the effective options do. Comments, formatting and the order of
+    /// fields make no difference, nor does the case of options such as `target` that tsc reads
+    /// case-insensitively. For a config loaded from a file, everything it extends is included, and
+    /// the `extends` field itself is not. The hash is the same across releases and platforms.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let a = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true, "target": "ES2020"}}"#).unwrap();
+    /// let b = TsConfig::parse_str(r#"{
+    ///   "compilerOptions": {
+    ///     // Same options, different order.
+    ///     "target": "es2020",
+    ///     "strict": true,
+    ///   },
+    /// }"#).unwrap();
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    ///
+    /// let c = TsConfig::parse_str(r#"{"compilerOptions": {"strict": false, "target": "ES2020"}}"#).unwrap();
+    /// assert_ne!(a.fingerprint(), c.fingerprint());
+    ///```
+    ///
+    pub fn fingerprint(&self) -> u64 {
+        fingerprint::fingerprint(&self.json())
+    }
+
+    /// Compares the options set by this config with those set by `other`.
+    ///
+    /// Like [TsConfig::fingerprint], this ignores comments, formatting, the order of fields, and the
+    /// case of options that tsc reads case-insensitively. Fields this crate doesn't model are
+    /// compared too.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use tsconfig::TsConfig;
+    ///
+    /// let old = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true, "target": "es2020"}}"#).unwrap();
+    /// let new = TsConfig::parse_str(r#"{"compilerOptions": {"target": "es2022", "noEmit": true}}"#).unwrap();
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.added, [("compilerOptions.noEmit".to_string(), json!(true))]);
+    /// assert_eq!(diff.removed, [("compilerOptions.strict".to_string(), json!(true))]);
+    /// assert_eq!(
+    ///     diff.changed,
+    ///     [("compilerOptions.target".to_string(), json!("es2020"), json!("es2022"))]
+    /// );
+    ///```
+    ///
+    pub fn diff(&self, other: &TsConfig) -> ConfigDiff {
+        diff::diff(&self.json(), &other.json())
+    }
+
+    /// Returns this config as `tsc --showConfig` would print it, for comparing against tsc itself.
+    ///
+    /// As with tsc, `extends` is left out, since everything it extends is already merged in, as are
+    /// sections for other tools such as `ts-node`. Option values are written in the lowercase form
+    /// tsc uses, and relative paths start with `./` or `../`. Unlike tsc, `files` is not expanded
+    /// into the list of files matched by `include`, and options implied by others, such as the ones
+    /// implied by `strict`, are not added.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use tsconfig::TsConfig;
+    ///
+    /// let json = r#"{"compilerOptions": {"target": "ES2022", "outDir": "dist"}, "include": ["src"]}"#;
+    /// let config = TsConfig::parse_str(json).unwrap();
+    /// assert_eq!(
+    ///     config.show_config(),
+    ///     json!({"compilerOptions": {"target": "es2022", "outDir": "./dist"}, "include": ["src"]})
+    /// );
+    ///```
+    ///
+    pub fn show_config(&self) -> Value {
+        show_config::show_config(&self.json())
+    }
+
+    /// Serializes the [JSON](TsConfig::raw) of this config, pretty-printed.
+    ///
+    /// Comments are not preserved, but every field is, including those this crate does not model,
+    /// and changes made to the typed fields are included.
+    pub fn to_string_pretty(&self) -> Result<String> {
+        let options = WriteOptions::new().with_final_newline(false);
+        write::write(&self.json(), &self.order, &options)
+    }
+
+    /// Serializes the [JSON](TsConfig::raw) of this config, indented, ordered and terminated as
+    /// `options` says. See [WriteOptions].
+    pub fn to_string_with(&self, options: &WriteOptions) -> Result<String> {
+        write::write(&self.json(), &self.order, options)
+    }
+
+    /// Parse a JSON5 string into a single [TsConfig].
+    ///
+    /// JSON5 is a superset of JSONC that also allows single-quoted strings, unquoted keys and other
+    /// ECMAScript syntax. It is only available with the `json5` feature.
+    ///
+    /// The 'extends' field will be ignored.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{TsConfig, Jsx};
+    /// let json = "{compilerOptions: {jsx: 'react-jsx'}}";
+    ///
+    /// let config = TsConfig::parse_json5_str(json).unwrap();
+    /// assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactJsx));
+    ///```
+    ///
+    #[cfg(feature = "json5")]
+    pub fn parse_json5_str(json: &str) -> Result<TsConfig> {
+        TsConfig::from_ordered(json5::from_str(json)?)
+    }
+
+    /// Parse a single [TsConfig] from any [Read] implementation, such as a network stream or an archive entry.
+    ///
+    /// Comments and trailing commas are stripped as the input streams through, without first reading it
+    /// into memory. The 'extends' field will be ignored.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{TsConfig, Jsx};
+    /// let json = r#"{"compilerOptions": {"jsx": /*here's a comment*/ "react-jsx"},}"#;
+    ///
+    /// let config = TsConfig::parse_reader(json.as_bytes()).unwrap();
+    /// assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactJsx));
+    ///```
+    ///
+    pub fn parse_reader<R: Read>(reader: R) -> Result<TsConfig> {
+        let json = serde_json::from_reader(jsonc::StripReader::new(reader))?;
+        TsConfig::from_ordered(json)
+    }
+
+    /// Parse a JSON string into a single [TsConfig], recording the location of every field.
+    ///
+    /// This behaves like [TsConfig::parse_str], but the source location of each field can afterwards be
+    /// looked up with [TsConfig::span_of].
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// let json = "{\n  \"compilerOptions\": {\n    \"strict\": true\n  }\n}";
+    ///
+    /// let config = TsConfig::parse_str_with_spans(json).unwrap();
+    /// let span = config.span_of("compilerOptions.strict").unwrap();
+    /// assert_eq!((span.line, span.column), (3, 5));
+    /// assert_eq!(&json[span.start..span.end], "\"strict\": true");
+    ///```
+    ///
+    pub fn parse_str_with_spans(json: &str) -> Result<TsConfig> {
+        let mut config = TsConfig::parse_str(json)?;
+        config.spans = span::collect(json);
+        Ok(config)
+    }
+
+    /// Returns the location of a field, given its dotted path (such as `compilerOptions.strict`).
+    ///
+    /// Spans are only recorded when the config was parsed with [TsConfig::parse_str_with_spans];
+    /// otherwise this always returns `None`.
+    pub fn span_of(&self, path: &str) -> Option<Span> {
+        self.spans.get(path).copied()
+    }
+
+    /// Parses a .tsconfig file into a [TsConfig], rejecting any field outside the known schema.
+    ///
+    /// This behaves like [TsConfig::parse_file], but returns [ConfigError::UnknownField] if the merged
+    /// config contains a top-level field or compiler option that this crate does not recognise, and
+    /// [ConfigError::UnknownValue] if an option such as `target` has a value that it does not
+    /// recognise, rather than keeping the value in `Other`.
+    pub fn parse_file_strict<P: AsRef<Path>>(path: &P) -> Result<TsConfig> {
+        let config = TsConfig::parse_file(path)?;
+        config.check_unknown_fields()?;
+        config.check_unknown_values()?;
+        Ok(config)
+    }
+
+    /// Parse a JSON string into a single [TsConfig], rejecting any field outside the known schema.
+    ///
+    /// This behaves like [TsConfig::parse_str], but returns [ConfigError::UnknownField] naming the
+    /// offending key if the config contains a top-level field, compiler option, watch option or
+    /// `typeAcquisition` field that this crate does not recognise. The `$schema` field that editors
+    /// read is allowed, while ts-node's section, which belongs to another tool, isn't checked. Likewise, an option value that would otherwise be kept in `Other`, such as an unknown
+    /// `target`, is returned as [ConfigError::UnknownValue], with the closest known value as a suggestion.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{ConfigError, TsConfig};
+    /// let json = r#"{"compilerOptions": {"strict": true, "stirct": true}}"#;
+    ///
+    /// let err = TsConfig::parse_str_strict(json).unwrap_err();
+    /// assert!(matches!(err, ConfigError::UnknownField(field) if field == "compilerOptions.stirct"));
+    ///
+    /// let json = r#"{"compilerOptions": {"target": "ES20017"}}"#;
+    /// let err = TsConfig::parse_str_strict(json).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Unknown value `ES20017` for compilerOptions.target; did you mean `ES2017`?"
+    /// );
+    ///```
+    ///
+    pub fn parse_str_strict(json: &str) -> Result<TsConfig> {
+        let config = TsConfig::parse_str(json)?;
+        config.check_unknown_fields()?;
+        config.check_unknown_values()?;
+        Ok(config)
+    }
+
+    fn check_unknown_fields(&self) -> Result<()> {
+        let mut unknown: Vec<String> = self
+            .unknown_fields
+            .keys()
+            .filter(|key| *key != "$schema")
+            .cloned()
+            .collect();
+        if let Some(opts) = &self.compiler_options {
+            unknown.extend(
+                opts.unknown_fields
+                    .keys()
+                    .map(|key| format!("compilerOptions.{}", key)),
+            );
+        }
+        // These sections don't keep the fields they don't know, so look for them in the JSON.
+        let raw = self.json();
+        for (section, known) in [
+            ("watchOptions", WATCH_OPTIONS),
+            ("typeAcquisition", TYPE_ACQUISITION),
+        ] {
+            if let Some(Value::Object(fields)) = raw.get(section) {
+                unknown.extend(
+                    fields
+                        .keys()
+                        .filter(|key| !known.contains(&key.as_str()))
+                        .map(|key| format!("{}.{}", section, key)),
+                );
+            }
+        }
+        unknown.sort();
+
+        match unknown.into_iter().next() {
+            Some(field) => Err(ConfigError::UnknownField(field)),
+            None => Ok(()),
+        }
+    }
+
+    fn check_unknown_values(&self) -> Result<()> {
+        let mut values: Vec<(String, Option<&str>, &[&str])> = Vec::new();
+        if let Some(opts) = &self.compiler_options {
+            values.push((
+                "compilerOptions.target".to_string(),
+                opts.target.as_ref().and_then(Target::unknown),
+                Target::NAMES,
+            ));
+            values.push((
+                "compilerOptions.module".to_string(),
+                opts.module.as_ref().and_then(Module::unknown),
+                Module::NAMES,
+            ));
+            values.push((
+                "compilerOptions.moduleResolution".to_string(),
+                opts.module_resolution
+                    .as_ref()
+                    .and_then(ModuleResolutionMode::unknown),
+                ModuleResolutionMode::NAMES,
+            ));
+            values.push((
+                "compilerOptions.importsNotUsedAsValues".to_string(),
+                opts.imports_not_used_as_values
+                    .as_ref()
+                    .and_then(ImportsNotUsedAsValues::unknown),
+                ImportsNotUsedAsValues::NAMES,
+            ));
+            for (index, lib) in opts.lib.iter().flatten().enumerate() {
+                values.push((
+                    format!("compilerOptions.lib.{}", index),
+                    lib.unknown(),
+                    Lib::NAMES,
+                ));
+            }
+            values.push((
+                "compilerOptions.watchFile".to_string(),
+                opts.watch_file.as_ref().and_then(WatchFile::unknown),
+                WatchFile::NAMES,
+            ));
+            values.push((
+                "compilerOptions.watchDirectory".to_string(),
+                opts.watch_directory
+                    .as_ref()
+                    .and_then(WatchDirectory::unknown),
+                WatchDirectory::NAMES,
+            ));
+            values.push((
+                "compilerOptions.fallbackPolling".to_string(),
+                opts.fallback_polling
+                    .as_ref()
+                    .and_then(FallbackPolling::unknown),
+                FallbackPolling::NAMES,
+            ));
+        }
+        if let Some(opts) = &self.watch_options {
+            values.push((
+                "watchOptions.watchFile".to_string(),
+                opts.watch_file.as_ref().and_then(WatchFile::unknown),
+                WatchFile::NAMES,
+            ));
+            values.push((
+                "watchOptions.watchDirectory".to_string(),
+                opts.watch_directory
+                    .as_ref()
+                    .and_then(WatchDirectory::unknown),
+                WatchDirectory::NAMES,
+            ));
+            values.push((
+                "watchOptions.fallbackPolling".to_string(),
+                opts.fallback_polling
+                    .as_ref()
+                    .and_then(FallbackPolling::unknown),
+                FallbackPolling::NAMES,
+            ));
+        }
+
+        let mut unknown = values
+            .into_iter()
+            .filter_map(|(field, value, names)| Some((field, value?, names)));
+        match unknown.next() {
+            Some((field, value, names)) => Err(ConfigError::UnknownValue {
+                field,
+                value: value.to_string(),
+                suggestion: suggest::closest(value, names).map(str::to_string),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the top-level fields that this crate does not recognise, keyed by their name in the file.
+    ///
+    /// These include options this crate doesn't model yet, as well as sections added by other tools,
+    /// such as `angularCompilerOptions`.
+    pub fn unknown_fields(&self) -> &HashMap<String, Value> {
+        &self.unknown_fields
+    }
+
+    /// Parse a JSON string into a single [TsConfig], skipping fields that fail to deserialize.
+    ///
+    /// Instead of aborting on the first invalid value, every top-level field and compiler option that
+    /// cannot be deserialized is left unset and reported as a [Diagnostic]. Only malformed JSON is
+    /// still treated as an error. Spans are recorded as in [TsConfig::parse_str_with_spans].
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{TsConfig, Jsx};
+    /// let json = r#"{"compilerOptions": {"jsx": "react-jsx", "strict": "yes", "noEmit": 1}}"#;
+    ///
+    /// let (config, diagnostics) = TsConfig::parse_str_lenient(json).unwrap();
+    /// assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactJsx));
+    /// assert_eq!(diagnostics.len(), 2);
+    /// assert_eq!(diagnostics[0].field, "compilerOptions.strict");
+    ///```
+    ///
+    pub fn parse_str_lenient(json: &str) -> Result<(TsConfig, Vec<Diagnostic>)> {
+        let Ordered { value: raw, order } = from_jsonc(json)?;
+        let mut value = raw.clone();
+        let spans = span::collect(json);
+
+        let mut diagnostics = remove_invalid_fields(&mut value);
+        for diagnostic in &mut diagnostics {
+            diagnostic.span = spans.get(&diagnostic.field).copied();
+        }
+        diagnostics.sort_by_key(|d| d.span.map_or(usize::MAX, |span| span.start));
+
+        let mut config: TsConfig = serde_json::from_value(value)?;
+        config.spans = spans;
+        config.deprecations = deprecation::collect(&raw);
+        config.raw = raw;
+        config.order = order;
+        Ok((config, diagnostics))
+    }
+
+    /// Parse a possibly malformed JSON string into a [TsConfig], recovering as much as possible.
+    ///
+    /// This is intended for editors, which frequently need to parse half-typed input. It never fails:
+    /// syntax errors such as missing closing braces or commas are reported as [Diagnostic]s, along with
+    /// every field that fails to deserialize (as in [TsConfig::parse_str_lenient]), and the returned
+    /// config contains everything that could still be read.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// let json = r#"{"include": ["src"], "compilerOptions": {"strict": true, "noEmit": "#;
+    ///
+    /// let (config, diagnostics) = TsConfig::parse_str_recover(json);
+    /// assert_eq!(config.include, Some(vec!["src".to_string()]));
+    /// assert_eq!(config.compiler_options.unwrap().strict, Some(true));
+    /// assert!(!diagnostics.is_empty());
+    ///```
+    ///
+    pub fn parse_str_recover(json: &str) -> (TsConfig, Vec<Diagnostic>) {
+        let (Ordered { value: raw, order }, errors) = jsonc::parse_recover(json);
+        let mut value = raw.clone();
+        let spans = span::collect(json);
+
+        let mut diagnostics: Vec<Diagnostic> = errors
+            .into_iter()
+            .map(|err| Diagnostic {
+                field: err.path,
+                message: err.message,
+                span: Some(Span::new(json, err.start, err.end)),
+            })
+            .collect();
+        if !value.is_object() {
+            if !value.is_null() {
+                diagnostics.push(Diagnostic::new("", "expected the config to be an object"));
+            }
+            value = Value::Object(serde_json::Map::new());
+        }
+        for mut diagnostic in remove_invalid_fields(&mut value) {
+            diagnostic.span = spans.get(&diagnostic.field).copied();
+            diagnostics.push(diagnostic);
+        }
+        diagnostics.sort_by_key(|d| d.span.map_or(usize::MAX, |span| span.start));
+
+        // Every field that remains deserializes on its own, so this cannot fail.
+        let mut config: TsConfig = serde_json::from_value(value).unwrap_or_default();
+        config.spans = spans;
+        config.deprecations = deprecation::collect(&raw);
+        config.raw = raw;
+        config.order = order;
+        (config, diagnostics)
+    }
+}
+
+/// Removes every top-level field and compiler option that does not deserialize on its own,
+/// returning a diagnostic for each.
+fn remove_invalid_fields(value: &mut Value) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let obj = match value.as_object_mut() {
+        Some(obj) => obj,
+        None => return diagnostics,
+    };
+
+    if let Some(Value::Object(options)) = obj.get_mut("compilerOptions") {
+        options.retain(|key, v| {
+            let mut probe = serde_json::Map::new();
+            probe.insert(key.clone(), v.clone());
+            let probe = serde_json::json!({ "compilerOptions": probe });
+            match TsConfig::deserialize(&probe) {
+                Ok(_) => true,
+                Err(err) => {
+                    let field = format!("compilerOptions.{}", key);
+                    diagnostics.push(Diagnostic::new(field, err.to_string()));
+                    false
+                }
+            }
+        });
+    }
+
+    obj.retain(|key, v| {
+        let mut probe = serde_json::Map::new();
+        probe.insert(key.clone(), v.clone());
+        match TsConfig::deserialize(&Value::Object(probe)) {
+            Ok(_) => true,
+            Err(err) => {
+                diagnostics.push(Diagnostic::new(key.clone(), err.to_string()));
+                false
+            }
+        }
+    });
+
+    diagnostics
+}
+
+/// The options that `tsc --init` sets, as of TypeScript 5.9, in the order it writes them.
+fn tsc_init_defaults() -> Ordered {
+    let json = r#"{
+        "compilerOptions": {
+            "module": "nodenext",
+            "target": "esnext",
+            "types": [],
+            "sourceMap": true,
+            "declaration": true,
+            "declarationMap": true,
+            "noUncheckedIndexedAccess": true,
+            "exactOptionalPropertyTypes": true,
+            "strict": true,
+            "jsx": "react-jsx",
+            "verbatimModuleSyntax": true,
+            "isolatedModules": true,
+            "noUncheckedSideEffectImports": true,
+            "moduleDetection": "force",
+            "skipLibCheck": true
+        }
+    }"#;
+    serde_json::from_str(json).expect("the tsc --init options are valid JSON")
+}
+
+/// The options that VS Code and tsserver assume for a jsconfig.json.
+fn jsconfig_defaults() -> Value {
+    serde_json::json!({
+        "compilerOptions": {
+            "allowJs": true,
+            "allowSyntheticDefaultImports": true,
+            "maxNodeModuleJsDepth": 2,
+            "noEmit": true,
+            "skipLibCheck": true,
+        },
+        "typeAcquisition": {
+            "enable": true,
+        },
+    })
+}
+
+/// Sections whose options are inherited one at a time, rather than as a whole.
+const MERGED_SECTIONS: &[&str] = &["compilerOptions", "watchOptions", "ts-node"];
+
+/// Merges the config `base`, which `config` extends, into `config`, following tsc.
+///
+/// The options in `compilerOptions` and `watchOptions`, and in ts-node's section, are inherited
+/// one at a time, each replacing the base's value as a whole. Every other field, such as `files`,
+/// `include` or `exclude`, is only inherited if `config` doesn't set it. An option that `config`
+/// sets to `null` isn't inherited. `references` are never inherited.
+fn merge_extended(config: &mut Value, base: Value) {
+    let (config, base) = match (config, base) {
+        (Value::Object(config), Value::Object(base)) => (config, base),
+        _ => return,
+    };
+    for (key, value) in base {
+        if key == "references" {
+            continue;
+        }
+        match (config.get_mut(&key), value) {
+            (Some(Value::Object(options)), Value::Object(base_options))
+                if MERGED_SECTIONS.contains(&key.as_str()) =>
+            {
+                for (option, value) in base_options {
+                    options.entry(option).or_insert(value);
+                }
+            }
+            (Some(_), _) => {}
+            (None, value) => {
+                config.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Writes the fields and options that differ between `before` and `after`, a config's serialized
+/// fields before and after they were changed, into `raw`, its JSON. Sections are compared option
+/// by option, and a field that became `null` is removed.
+fn write_changes(
+    raw: &mut serde_json::Map<String, Value>,
+    before: &serde_json::Map<String, Value>,
+    after: &serde_json::Map<String, Value>,
+) {
+    let removed = before.keys().filter(|key| !after.contains_key(*key));
+    let keys: Vec<String> = after.keys().chain(removed).cloned().collect();
+    for key in keys {
+        let old = before.get(&key).unwrap_or(&Value::Null);
+        let new = after.get(&key).unwrap_or(&Value::Null);
+        if old == new {
+            continue;
+        }
+        match (old, new, raw.get_mut(&key)) {
+            (Value::Object(old), Value::Object(new), Some(Value::Object(section))) => {
+                write_changes(section, old, new)
+            }
+            (_, Value::Null, _) => raw.retain(|name, _| *name != key),
+            (_, new, _) => {
+                let value = without_nulls(new.clone());
+                match raw.get_mut(&key) {
+                    Some(existing) => *existing = value,
+                    None => {
+                        raw.insert(key, value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Removes the members set to `null` from every object in `value`, which stand for unset fields
+/// when a config is serialized.
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(members) => Value::Object(
+            members
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, without_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(without_nulls).collect()),
+        value => value,
+    }
+}
+
+/// Applies `patch` to `target`, as an RFC 7386 JSON Merge Patch.
+fn merge_patch(target: &mut Value, patch: Value) {
+    let patch = match patch {
+        Value::Object(patch) => patch,
+        patch => {
+            *target = patch;
+            return;
+        }
+    };
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+    let target = target
+        .as_object_mut()
+        .expect("target was just made an object");
+    for (key, value) in patch {
+        if value.is_null() {
+            target.retain(|k, _| *k != key);
+        } else {
+            merge_patch(target.entry(key).or_insert(Value::Null), value);
+        }
+    }
+}
+
+fn merge(a: &mut Value, b: Value) {
+    match (a, b) {
+        (&mut Value::Object(ref mut a), Value::Object(b)) => {
+            for (k, v) in b {
+                merge(a.entry(k).or_insert(Value::Null), v);
+            }
+        }
+        (a, b) => {
+            if let Value::Null = a {
+                *a = b;
+            }
+        }
+    }
+}
+
+/// Parses a .tsconfig file into a [serde_json::Value].
+///
+/// The `extends` field will be respected, allowing for one .tsconfig file to inherit properties from another.
+/// Comments and trailing commas are both allowed, although they are not valid JSON.
+/// Files may be encoded as UTF-8, with or without a byte order mark, or as UTF-16 with a byte order mark.
+/// ## Example
+///
+/// Assuming the following .tsconfig files:
+///
+/// tsconfig.base.json:
+/// ```json
+/// {
+///     "compilerOptions": {
+///         "useDefineForClassFields": false,
+///         "traceResolution": true,
+///         "jsx": "preserve",
+///     }
+/// }
+/// ```
+/// tsconfig.inherits.json:
+/// ```json
+/// {
+///     "extends": "./tsconfig.base.json",
+///     "compilerOptions": {
+///         "traceResolution": false,
+///         "declaration": true,
+///         "jsx": "react-jsxdev",
+///     }
+/// }
+/// ```
+/// ```
+/// use std::path::Path;
+/// use tsconfig::parse_file_to_value;
+/// use serde_json::Value;
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/tsconfig.inherits.json");
+/// let config = parse_file_to_value(&path).unwrap();
+///
+/// assert_eq!(
+///     config
+///         ["compilerOptions"]
+///         ["useDefineForClassFields"],
+///     Value::Bool(false)
+/// );
+///
+///
+/// ```
+pub fn parse_file_to_value<P: AsRef<Path>>(path: &P) -> Result<Value> {
+    ConfigLoader::new().load_value(path)
+}
+
+/// Parses a .tsconfig file, and collapses its whole `extends` chain into one self-contained [TsConfig].
+///
+/// This is shorthand for [TsConfig::parse_file] followed by [TsConfig::flattened].
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::resolve_to_flat;
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/a/tsconfig.inherits_again.json");
+/// let config = resolve_to_flat(&path).unwrap();
+///
+/// assert_eq!(config.extends, None);
+/// assert_eq!(config.compiler_options.unwrap().trace_resolution, Some(false));
+/// ```
+pub fn resolve_to_flat<P: AsRef<Path>>(path: &P) -> Result<TsConfig> {
+    TsConfig::parse_file(path)?.flattened()
+}
+
+/// Parse a JSON string into a single [serde_json::Value].
+///
+/// The 'extends' field will be ignored. Comments and trailing commas are both allowed, although they are not valid JSON.
+///
+/// ## Example
 /// ```
 /// use tsconfig::parse_to_value;
 /// use serde_json::Value;
@@ -254,14 +1965,56 @@ pub fn parse_file_to_value<P: AsRef<Path>>(path: &P) -> Result<Value> {
 ///```
 ///
 pub fn parse_to_value(json: &str) -> Result<Value> {
-    // Remove trailing commas from objects.
-    let re = Regex::new(r",(?P<valid>\s*})").unwrap();
-    let mut stripped = String::with_capacity(json.len());
-    StripComments::new(json.as_bytes())
-        .read_to_string(&mut stripped)
-        .map_err(|err| ConfigError::InvalidJsonData(err))?;
-    let stripped = re.replace_all(&stripped, "$valid");
-    let r: Value = serde_json::from_str(&stripped)?;
+    from_jsonc(json)
+}
+
+/// Deserializes JSONC text.
+fn from_jsonc<T: DeserializeOwned>(json: &str) -> Result<T> {
+    let stripped = jsonc::strip(json);
+    let r: T = serde_json::from_str(&stripped)?;
+    Ok(r)
+}
+
+/// Parse a single [serde_json::Value] from any [Read] implementation.
+///
+/// Comments and trailing commas are stripped as the input streams through, without first reading it
+/// into memory. The 'extends' field will be ignored.
+///
+/// ## Example
+/// ```
+/// use tsconfig::parse_reader_to_value;
+///
+/// let json = r#"{"compilerOptions": {"jsx": /*here's a comment*/ "react-jsx"},}"#;
+///
+/// let config = parse_reader_to_value(json.as_bytes()).unwrap();
+/// assert_eq!(config["compilerOptions"]["jsx"], "react-jsx");
+///```
+///
+pub fn parse_reader_to_value<R: Read>(reader: R) -> Result<Value> {
+    let r: Value = serde_json::from_reader(jsonc::StripReader::new(reader))?;
+    Ok(r)
+}
+
+/// Parse a JSON5 string into a single [serde_json::Value].
+///
+/// JSON5 is a superset of JSONC that also allows single-quoted strings, unquoted keys and other
+/// ECMAScript syntax. It is only available with the `json5` feature.
+///
+/// The 'extends' field will be ignored.
+///
+/// ## Example
+/// ```
+/// use tsconfig::parse_json5_to_value;
+///
+/// let json = "{compilerOptions: {jsx: 'react-jsx', /* comment */ strict: true,},}";
+///
+/// let config = parse_json5_to_value(json).unwrap();
+/// assert_eq!(config["compilerOptions"]["jsx"], "react-jsx");
+///```
+///
+#[cfg(feature = "json5")]
+pub fn parse_json5_to_value(json: &str) -> Result<Value> {
+    let r: Value = json5::from_str(json)?;
     Ok(r)
 }
 
@@ -272,7 +2025,8 @@ pub fn parse_to_value(json: &str) -> Result<Value> {
 /// between components, and organize your code in new and improved ways.
 ///
 /// You can read more about how references works in the Project References section of [the handbook](https://www.typescriptlang.org/docs/handbook/project-references.html).
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum References {
     Bool(bool),
@@ -286,12 +2040,41 @@ pub enum References {
 /// between components, and organize your code in new and improved ways.
 ///
 /// You can read more about how references works in the Project References section of [the handbook](https://www.typescriptlang.org/docs/handbook/project-references.html).
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Reference {
     pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prepend: Option<bool>,
 }
 
+impl Reference {
+    /// The referenced project, as written: its directory, or its config file.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Whether the referenced project's output is prepended to this one's `outFile`, which is
+    /// off unless set. `prepend` was removed in TypeScript 5.5.
+    pub fn prepend(&self) -> bool {
+        self.prepend.unwrap_or(false)
+    }
+
+    /// Returns the path of the referenced config, given `dir`, the directory of the config that
+    /// references it.
+    ///
+    /// As with tsc, a reference to a directory, rather than to a `.json` file, is to the
+    /// tsconfig.json in that directory.
+    pub fn config_path(&self, dir: &Path) -> PathBuf {
+        let target = fs::normalize(&dir.join(&self.path));
+        if target.extension() == Some("json".as_ref()) {
+            target
+        } else {
+            target.join("tsconfig.json")
+        }
+    }
+}
+
 /// Defines how automatic type acquisition behaves.
 ///
 /// When you have a JavaScript project in your editor, TypeScript will provide types for your node_modules automatically
@@ -340,588 +2123,1581 @@ pub struct Reference {
 ///
 /// This means that having a file like jquery.js in your project would not automatically download the types for JQuery from DefinitelyTyped.
 ///
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
 pub enum TypeAcquisition {
     Bool(bool),
     Object {
-        enable: bool,
+        enable: Option<bool>,
         include: Option<Vec<String>>,
         exclude: Option<Vec<String>>,
+        #[serde(rename = "disableFilenameBasedTypeAcquisition")]
         disable_filename_based_type_acquisition: Option<bool>,
     },
 }
 
+/// Configures how `tsc --watch` watches files and directories.
+///
+/// TypeScript 3.8 shipped a new strategy for watching directories, which is crucial for efficiently picking up
+/// changes to node_modules. On operating systems like Linux, TypeScript installs directory watchers (as opposed
+/// to file watchers) on node_modules and many of its subdirectories to detect changes in dependencies.
+///
+/// ```json
+/// {
+///   "watchOptions": {
+///     "watchFile": "useFsEvents",
+///     "watchDirectory": "useFsEvents",
+///     "fallbackPolling": "dynamicPriority",
+///     "synchronousWatchDirectory": true,
+///     "excludeDirectories": ["**/node_modules", "_build"],
+///     "excludeFiles": ["build/fileWhichChangesOften.ts"]
+///   }
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WatchOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_file: Option<WatchFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_directory: Option<WatchDirectory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback_polling: Option<FallbackPolling>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synchronous_watch_directory: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_directories: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_files: Option<Vec<String>>,
+}
+
+/// The fields of [WatchOptions], as they're written in a config.
+const WATCH_OPTIONS: &[&str] = &[
+    "watchFile",
+    "watchDirectory",
+    "fallbackPolling",
+    "synchronousWatchDirectory",
+    "excludeDirectories",
+    "excludeFiles",
+];
+
+/// The fields of [TypeAcquisition::Object], as they're written in a config.
+const TYPE_ACQUISITION: &[&str] = &[
+    "enable",
+    "include",
+    "exclude",
+    "disableFilenameBasedTypeAcquisition",
+];
+
+option_enum! {
+    /// How tsc watches individual files.
+    ///
+    /// As with tsc, the value is matched case-insensitively, and values this crate doesn't know are kept
+    /// in `Other`.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    pub enum WatchFile {
+        FixedPollingInterval = "fixedPollingInterval",
+        PriorityPollingInterval = "priorityPollingInterval",
+        DynamicPriorityPolling = "dynamicPriorityPolling",
+        FixedChunkSizePolling = "fixedChunkSizePolling",
+        UseFsEvents = "useFsEvents",
+        UseFsEventsOnParentDirectory = "useFsEventsOnParentDirectory",
+        ..Other
+    }
+}
+
+option_enum! {
+    /// How tsc watches directories, on systems without recursive file watching.
+    ///
+    /// As with tsc, the value is matched case-insensitively, and values this crate doesn't know are kept
+    /// in `Other`.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    pub enum WatchDirectory {
+        UseFsEvents = "useFsEvents",
+        FixedPollingInterval = "fixedPollingInterval",
+        DynamicPriorityPolling = "dynamicPriorityPolling",
+        FixedChunkSizePolling = "fixedChunkSizePolling",
+        ..Other
+    }
+}
+
+option_enum! {
+    /// How tsc polls for changes when it can't use file system events.
+    ///
+    /// As with tsc, the value is matched case-insensitively, and values this crate doesn't know are kept
+    /// in `Other`.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    pub enum FallbackPolling {
+        FixedInterval = "fixedInterval",
+        PriorityInterval = "priorityInterval",
+        DynamicPriority = "dynamicPriority",
+        FixedChunkSize = "fixedChunkSize",
+        ..Other
+    }
+}
+
+/// Options for [ts-node](https://typestrong.org/ts-node/), read from the `"ts-node"` section of a tsconfig.
+///
+/// The nested `compilerOptions` override the top-level compiler options when running under ts-node.
+/// Options that this crate does not model are kept, and are available from [TsNodeOptions::unknown_fields].
+///
+/// ```json
+/// {
+///   "ts-node": {
+///     "transpileOnly": true,
+///     "files": true,
+///     "compilerOptions": {
+///       "module": "commonjs"
+///     }
+///   }
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TsNodeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compiler: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compiler_host: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compiler_options: Option<CompilerOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub esm: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental_specifier_resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore: Option<Vec<String>>,
+    /// Diagnostic codes to ignore, given either as numbers or strings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_diagnostics: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_error: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_types: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_ts_exts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretty: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_ignore: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swc: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transpile_only: Option<bool>,
+    /// Either the name of a transpiler module, or a `[name, options]` pair.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transpiler: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_check: Option<bool>,
+    #[serde(flatten)]
+    unknown_fields: HashMap<String, Value>,
+}
+
+/// Hashes the options as they're written in a config, with the keys of `moduleTypes` sorted.
+impl Hash for TsNodeOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        fingerprint::hash(self, "ts-node", state);
+    }
+}
+
+impl TsNodeOptions {
+    /// Returns the ts-node options that this crate does not recognise, keyed by their name in the file.
+    pub fn unknown_fields(&self) -> &HashMap<String, Value> {
+        &self.unknown_fields
+    }
+}
+
 /// These options make up the bulk of TypeScript’s configuration and it covers how the language should work.
-#[derive(Deserialize, Debug, Clone)]
+///
+/// [CompilerOptions::default] leaves every option unset, so that tsc's own defaults apply. For the
+/// options that `tsc --init` sets, see [TsConfig::tsc_init_default]. New options are added as
+/// TypeScript gains them, so set fields on a default value rather than building one field by field.
+/// Each option also has a getter of the same name and a setter such as [CompilerOptions::set_strict].
+#[non_exhaustive]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CompilerOptions {
+    /// Allows imports of files with any extension, such as `./styles.css`, whose types come from a
+    /// declaration file named like `styles.d.css.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_arbitrary_extensions: Option<bool>,
+    /// Allows imports to name `.ts`, `.mts` and `.tsx` files. Only allowed with `noEmit` or
+    /// `emitDeclarationOnly`, since the specifiers would not work in the emitted JavaScript.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_importing_ts_extensions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_js: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub check_js: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub composite: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub declaration: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub declaration_map: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub downlevel_iteration: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub import_helpers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub incremental: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub isolated_modules: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub isolated_declarations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx: Option<Jsx>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lib: Option<Vec<Lib>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub module: Option<Module>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub module_detection: Option<ModuleDetectionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_emit: Option<bool>,
+    /// Skips type checking, so that tsc only emits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remove_comments: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_map: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<Target>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts_build_info_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub always_strict: Option<bool>,
+    /// Treats optional properties as exactly as written, rather than also allowing `undefined`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact_optional_property_types: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_any: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_implicit_override: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_this: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_bind_call_apply: Option<bool>,
+    /// Types the `TReturn` of built-in iterators as `undefined` rather than `any`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict_builtin_iterator_return: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_function_types: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_null_checks: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_property_initialization: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_unknown_in_catch_variables: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_synthetic_default_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_umd_global_access: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub base_url: Option<String>,
+    /// Extra conditions to match in the `exports` and `imports` of a package.json, on top of those
+    /// implied by the module resolution mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_conditions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub es_module_interop: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub module_resolution: Option<ModuleResolutionMode>,
-    pub paths: Option<HashMap<String, Vec<String>>>,
+    /// Suffixes to try, in order, before the extension when resolving a module, such as
+    /// `[".ios", ".native", ""]`. The empty string stands for the unsuffixed file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_suffixes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<Paths>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugins: Option<Vec<Plugin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preserve_symlinks: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dirs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub type_roots: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_source_map: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_sources: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub map_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_fallthrough_cases_in_switch: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_returns: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_property_access_from_index_signature: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unchecked_indexed_access: Option<bool>,
+    /// Reports side effect imports, like `import "./polyfills"`, that can't be resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unchecked_side_effect_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unused_locals: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_parameters: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub emit_decorator_metadata: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub experimental_decorators: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_unreachable_code: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_unused_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub assume_changes_only_affect_direct_dependencies: Option<bool>,
     #[deprecated]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub declaration_dir: Option<String>,
     #[deprecated]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub diagnostics: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_referenced_project_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_size_limit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_solution_searching: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_source_of_project_reference_redirect: Option<bool>,
     #[serde(rename = "emitBOM")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub emit_bom: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub emit_declaration_only: Option<bool>,
+    /// Reports TypeScript-only constructs that have runtime behaviour, such as enums and namespaces,
+    /// so that the code can be run by stripping its types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub erasable_syntax_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub explain_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub extended_diagnostics: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub force_consistent_casing_in_file_names: Option<bool>,
-    // XXX: Is generateCpuProfile available from tsconfig? Or just the CLI?
-    pub generate_cpu_profile: Option<bool>,
+    /// Path of a file to write a CPU profile of the compiler to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_cpu_profile: Option<String>,
 
-    pub imports_not_used_as_values: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imports_not_used_as_values: Option<ImportsNotUsedAsValues>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_factory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_fragment_factory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_import_source: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub keyof_strings_only: Option<bool>,
+    /// Whether a `@typescript/lib-*` package in node_modules may replace a built-in lib file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lib_replacement: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub list_emitted_files: Option<bool>,
+    /// Language tag, such as `ja` or `pt-BR`, for the messages tsc prints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub list_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_node_module_js_depth: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_emit_helpers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_emit_on_error: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_error_truncation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_use_strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_lib: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_resolve: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_strict_generic_checks: Option<bool>,
     #[deprecated]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preserve_const_enums: Option<bool>,
+    /// Deprecated in TypeScript 5.0 in favour of `verbatimModuleSyntax`.
+    #[deprecated]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_value_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub react_namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resolve_json_module: Option<bool>,
+    /// Whether to follow the `exports` field of a package.json. Defaults to true under the `node16`,
+    /// `nodenext` and `bundler` module resolution modes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve_package_json_exports: Option<bool>,
+    /// Whether to follow the `imports` field of a package.json. Defaults to true under the `node16`,
+    /// `nodenext` and `bundler` module resolution modes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve_package_json_imports: Option<bool>,
+    /// Rewrites relative imports of `.ts`, `.tsx`, `.mts` and `.cts` files to the matching
+    /// JavaScript extension in the emitted output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrite_relative_import_extensions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skip_default_lib_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skip_lib_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strip_internal: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub suppress_excess_property_errors: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub suppress_implicit_any_index_errors: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trace_resolution: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub use_define_for_class_fields: Option<bool>,
+    /// Replaces `importsNotUsedAsValues` and `preserveValueImports`: imports and exports without a
+    /// `type` modifier are always kept, and type-only ones are always dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbatim_module_syntax: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preserve_watch_output: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pretty: Option<bool>,
-    pub fallback_polling: Option<String>,
-    pub watch_directory: Option<String>,
-    pub watch_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback_polling: Option<FallbackPolling>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_directory: Option<WatchDirectory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_file: Option<WatchFile>,
+    #[serde(flatten)]
+    unknown_fields: HashMap<String, Value>,
+}
+
+/// Hashes the options as they're written in a config, with unknown options sorted by name, so that
+/// they can be used as keys in a cache.
+impl Hash for CompilerOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        fingerprint::hash(self, "compilerOptions", state);
+    }
+}
+
+impl CompilerOptions {
+    /// Returns the compiler options that this crate does not recognise, keyed by their name in the file.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    /// let json = r#"{"compilerOptions": {"strict": true, "someFutureOption": "on"}}"#;
+    ///
+    /// let config = TsConfig::parse_str(json).unwrap();
+    /// let unknown = config.compiler_options.unwrap().unknown_fields().clone();
+    /// assert_eq!(unknown["someFutureOption"], "on");
+    /// assert!(!unknown.contains_key("strict"));
+    ///```
+    pub fn unknown_fields(&self) -> &HashMap<String, Value> {
+        &self.unknown_fields
+    }
+
+    /// Returns `target`, or the target tsc uses when it isn't set, which follows from `module`.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{CompilerOptions, Module, Target};
+    ///
+    /// let mut opts = CompilerOptions::default();
+    /// assert_eq!(opts.effective_target(), Target::Es5);
+    /// opts.module = Some(Module::NodeNext);
+    /// assert_eq!(opts.effective_target(), Target::EsNext);
+    ///```
+    pub fn effective_target(&self) -> Target {
+        self.target.clone().unwrap_or(match self.module {
+            Some(Module::Node16) => Target::Es2022,
+            Some(Module::NodeNext) => Target::EsNext,
+            _ => Target::Es5,
+        })
+    }
+
+    /// Returns `module`, or the module format tsc uses when it isn't set: CommonJS for targets
+    /// before ES2015, and ES2015 otherwise.
+    pub fn effective_module(&self) -> Module {
+        self.module.clone().unwrap_or_else(|| {
+            if effective::rank(&self.effective_target()) < effective::rank(&Target::Es2015) {
+                Module::CommonJs
+            } else {
+                Module::Es2015
+            }
+        })
+    }
+
+    /// Returns `moduleResolution`, or the strategy tsc uses when it isn't set, which follows from
+    /// the [effective module](CompilerOptions::effective_module).
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{CompilerOptions, Module, ModuleResolutionMode};
+    ///
+    /// let mut opts = CompilerOptions::default();
+    /// assert_eq!(opts.effective_module_resolution(), ModuleResolutionMode::Node);
+    /// opts.module = Some(Module::Preserve);
+    /// assert_eq!(opts.effective_module_resolution(), ModuleResolutionMode::Bundler);
+    ///```
+    pub fn effective_module_resolution(&self) -> ModuleResolutionMode {
+        self.module_resolution
+            .clone()
+            .unwrap_or(match self.effective_module() {
+                Module::CommonJs => ModuleResolutionMode::Node,
+                Module::Node16 => ModuleResolutionMode::Node16,
+                Module::NodeNext => ModuleResolutionMode::NodeNext,
+                Module::Preserve => ModuleResolutionMode::Bundler,
+                _ => ModuleResolutionMode::Classic,
+            })
+    }
+
+    /// Whether every check that `strict` turns on is enabled, either by `strict` or on its own.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let config = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true, "strictNullChecks": false}}"#).unwrap();
+    /// assert!(!config.compiler_options.unwrap().is_strict());
+    ///```
+    pub fn is_strict(&self) -> bool {
+        let strict = self.strict.unwrap_or(false);
+        [
+            self.always_strict,
+            self.no_implicit_any,
+            self.no_implicit_this,
+            self.strict_bind_call_apply,
+            self.strict_builtin_iterator_return,
+            self.strict_function_types,
+            self.strict_null_checks,
+            self.strict_property_initialization,
+            self.use_unknown_in_catch_variables,
+        ]
+        .iter()
+        .all(|check| check.unwrap_or(strict))
+    }
+
+    /// Whether tsc emits declaration files, which either `declaration` or `composite` turns on. As
+    /// in tsc's `getEmitDeclarations`, `composite` emits them even with `declaration` set to `false`.
+    pub fn declaration_enabled(&self) -> bool {
+        self.declaration.unwrap_or(false) || self.composite.unwrap_or(false)
+    }
+
+    /// Whether tsc emits source maps for the JavaScript it writes, either as files or inline.
+    /// Nothing is emitted with `noEmit` or `emitDeclarationOnly`, so neither are source maps.
+    pub fn emits_source_maps(&self) -> bool {
+        let emits_js =
+            !self.no_emit.unwrap_or(false) && !self.emit_declaration_only.unwrap_or(false);
+        emits_js && (self.source_map.unwrap_or(false) || self.inline_source_map.unwrap_or(false))
+    }
+}
+
+/// A plugin listed in `compilerOptions.plugins`.
+///
+/// Language service plugins are identified by `name`, while transformer plugins loaded by tools such as
+/// ts-patch or roblox-ts use `transform`. Every other key is plugin-specific, and is kept in `options`.
+///
+/// ```json
+/// {
+///   "compilerOptions": {
+///     "plugins": [
+///       { "name": "typescript-styled-plugin", "tags": ["styled", "css"] },
+///       { "transform": "rbxts-transform-env" }
+///     ]
+///   }
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Plugin {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform: Option<String>,
+    #[serde(flatten)]
+    pub options: serde_json::Map<String, Value>,
+}
+
+/// Hashes the plugin as it's written in a config, with its options sorted by name.
+impl Hash for Plugin {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        fingerprint::hash(self, "compilerOptions.plugins", state);
+    }
+}
+
+option_enum! {
+    /// Module detection mode
+    ///
+    /// This setting controls how TypeScript determines whether a file is a script or a module.
+    /// These choices include:
+    ///   - "auto" (default) - TypeScript will not only look for import and export statements, but it will also check whether the "type" field in a package.json is set to "module" when running with module: nodenext or node16, and check whether the current file is a JSX file when running under jsx: react-jsx.
+    ///   - "legacy" - The same behavior as 4.6 and prior, usings import and export statements to determine whether a file is a module.
+    ///   - "force" - Ensures that every non-declaration file is treated as a module.
+    ///
+    /// As with tsc, the value is matched case-insensitively.
+    #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+    pub enum ModuleDetectionMode {
+        #[default]
+        Auto = "auto",
+        Legacy = "legacy",
+        Force = "force",
+    }
+}
+
+option_enum! {
+    /// What to do with imports that are only used for their types.
+    ///
+    /// Deprecated in TypeScript 5.0 in favour of `verbatimModuleSyntax`.
+    ///
+    ///   - "remove" (default) - Drops import statements that only reference types.
+    ///   - "preserve" - Keeps every import statement, so that its side effects still happen.
+    ///   - "error" - Keeps every import statement, but reports an error for value imports that are only used as types.
+    ///
+    /// As with tsc, the value is matched case-insensitively, and values this crate doesn't know are kept
+    /// in `Other`.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+    pub enum ImportsNotUsedAsValues {
+        #[default]
+        Remove = "remove",
+        Preserve = "preserve",
+        Error = "error",
+        ..Other
+    }
+}
+
+option_enum! {
+    /// Module resolution mode
+    ///
+    /// Specify the module resolution strategy: 'node' (Node.js) or 'classic' (used in TypeScript before the release of 1.6). You probably won’t need to use classic in modern code.
+    /// There is a handbook reference page [on Module Resolution](https://www.typescriptlang.org/docs/handbook/module-resolution.html).
+    ///
+    /// `node10` is another name for `node`, and values this crate doesn't know are kept in `Other`.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    pub enum ModuleResolutionMode {
+        Classic = "Classic",
+        Node = "Node10" | "Node",
+        Node16 = "Node16",
+        NodeNext = "NodeNext",
+        Bundler = "Bundler",
+        ..Other
+    }
+}
+
+option_enum! {
+    /// Controls how JSX constructs are emitted in JavaScript files. This only affects output of JS files that started in .tsx files.
+    ///
+    ///
+    /// For example, this sample code:
+    ///
+    /// ```tsx
+    /// export const helloWorld = () => <h1>Hello world</h1>;
+    /// ```
+    ///
+    /// Default: "react"
+    ///
+    /// ```tsx
+    /// export const helloWorld = () => React.createElement("h1", null, "Hello world");
+    /// ```
+    ///
+    /// Preserve: "preserve"
+    ///
+    /// ```tsx
+    /// export const helloWorld = () => <h1>Hello world</h1>;
+    /// ```
+    ///
+    /// React Native: "react-native"
+    ///
+    /// ```tsx
+    /// export const helloWorld = () => <h1>Hello world</h1>;
+    /// ```
+    ///
+    /// React 17 transform: "react-jsx"
+    ///
+    /// ```tsx
+    /// import { jsx as _jsx } from "react/jsx-runtime";
+    /// export const helloWorld = () => _jsx("h1", { children: "Hello world" }, void 0);
+    /// ```
+    ///
+    /// React 17 dev transform: "react-jsxdev"
+    ///
+    /// ```tsx
+    /// import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+    /// const _jsxFileName = "/home/runner/work/TypeScript-Website/TypeScript-Website/packages/typescriptlang-org/index.tsx";
+    /// export const helloWorld = () => _jsxDEV("h1", { children: "Hello world" }, void 0, false, { fileName: _jsxFileName, lineNumber: 7, columnNumber: 32 }, this);
+    /// ```
+    #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+    pub enum Jsx {
+        /// Emit .js files with JSX changed to the equivalent React.createElement calls
+        React = "react",
+        /// Emit .js files with the JSX changed to _jsx calls
+        ReactJsx = "react-jsx",
+        /// Emit .js files with the JSX to _jsx calls
+        ReactJsxdev = "react-jsxdev",
+        /// Emit .js files with the JSX unchanged
+        ReactNative = "react-native",
+        /// Emit .jsx files with the JSX unchanged
+        Preserve = "preserve",
+    }
+}
+
+target_enum! {
+    /// The transpilation target for the emitted JavaScript.
+    ///
+    /// Modern browsers support all `ES6` features, so `ES6` is a good choice. You might choose to set a lower target if your code
+    /// is deployed to older environments, or a higher target if your code is guaranteed to run in newer environments.
+    ///
+    /// The target setting changes which JS features are downleveled and which are left intact. For example, an arrow
+    /// function () => this will be turned into an equivalent function expression if target is `ES5` or lower.
+    ///
+    /// Changing target also changes the default value of lib. You may “mix and match” target and lib settings
+    /// as desired, but you could just set target for convenience.
+    ///
+    /// For developer platforms like Node will have a certain baselines for the their target depending on their version.
+    /// You can find a set of community organized TSConfigs at tsconfig/bases for common platforms and their versions.
+    ///
+    /// The special `ESNext` value refers to the highest version your version of TypeScript supports. This setting should be
+    /// used with caution, since it doesn’t mean the same thing between different TypeScript versions and can
+    /// make upgrades less predictable.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 }
-
-/// Module detection mode
-///
-/// This setting controls how TypeScript determines whether a file is a script or a module.
-/// These choices include:
-///   - "auto" (default) - TypeScript will not only look for import and export statements, but it will also check whether the "type" field in a package.json is set to "module" when running with module: nodenext or node16, and check whether the current file is a JSX file when running under jsx: react-jsx.
-///   - "legacy" - The same behavior as 4.6 and prior, usings import and export statements to determine whether a file is a module.
-///   - "force" - Ensures that every non-declaration file is treated as a module.
-#[derive(Deserialize, Debug, PartialEq, Copy, Clone, Default)]
-#[serde(rename_all = "lowercase")]
-pub enum ModuleDetectionMode {
-    #[default]
-    Auto,
-    Legacy,
-    Force,
+lib_enum! {
+    /// Available definitions for built-in JS APIs.
+    ///
+    /// TypeScript includes a default set of type definitions for built-in JS APIs (like Math), as well as type definitions for things found in browser environments (like document). TypeScript also includes APIs for newer JS features matching the target you specify; for example the definition for Map is available if target is ES6 or newer.
+    ///
+    /// You may want to change these for a few reasons:
+    ///
+    /// * Your program doesn't run in a browser, so you don’t want the "dom" type definitions
+    /// * Your runtime platform provides certain JavaScript API objects (maybe through polyfills), but doesn't yet support the full syntax of a given ECMAScript version
+    /// * You have polyfills or native implementations for some, but not all, of a higher level ECMAScript version
+    ///
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 }
 
-/// Module resolution mode
-///
-/// Specify the module resolution strategy: 'node' (Node.js) or 'classic' (used in TypeScript before the release of 1.6). You probably won’t need to use classic in modern code.
-/// There is a handbook reference page [on Module Resolution](https://www.typescriptlang.org/docs/handbook/module-resolution.html).
-#[derive(Deserialize, Debug, PartialEq, Copy, Clone)]
-pub enum ModuleResolutionMode {
-    #[serde(rename = "classic", alias = "Classic")]
-    Classic,
-    #[serde(rename = "node", alias = "Node", alias = "node10", alias = "Node10")]
-    Node,
-    #[serde(rename = "node16", alias = "Node16")]
-    Node16,
-    #[serde(rename = "nodenext", alias = "NodeNext")]
-    NodeNext,
-    #[serde(rename = "bundler", alias = "Bundler")]
-    Bundler,
+module_enum! {
+    /// Sets the module system for the program.
+    ///
+    /// See the [Modules reference page](https://www.typescriptlang.org/docs/handbook/modules.html)
+    /// for more information. You very likely want "CommonJS" for node projects.
+    ///
+    /// Changing module affects moduleResolution which also has a reference page.
+    ///
+    /// Here’s some example output for this file:
+    ///
+    /// ```tsx
+    /// // @filename: index.ts
+    /// import { valueOfPi } from "./constants";
+    ///
+    ///
+    /// export const twoPi = valueOfPi * 2;
+    /// ```
+    ///
+    /// ## CommonJS
+    ///
+    /// ```js
+    /// "use strict";
+    /// Object.defineProperty(exports, "__esModule", { value: true });
+    /// exports.twoPi = void 0;
+    /// const constants_1 = require("./constants");
+    /// exports.twoPi = constants_1.valueOfPi * 2;
+    /// ```
+    ///
+    /// ## UMD
+    ///
+    /// ```js
+    /// (function (factory) {
+    ///     if (typeof module === "object" && typeof module.exports === "object") {
+    ///         var v = factory(require, exports);
+    ///         if (v !== undefined) module.exports = v;
+    ///     }
+    ///     else if (typeof define === "function" && define.amd) {
+    ///         define(["require", "exports", "./constants"], factory);
+    ///     }
+    /// })(function (require, exports) {
+    ///     "use strict";
+    ///     Object.defineProperty(exports, "__esModule", { value: true });
+    ///     exports.twoPi = void 0;
+    ///     const constants_1 = require("./constants");
+    ///     exports.twoPi = constants_1.valueOfPi * 2;
+    /// });
+    /// ```
+    ///
+    /// ## AMD
+    ///
+    /// ```js
+    /// define(["require", "exports", "./constants"], function (require, exports, constants_1) {
+    ///     "use strict";
+    ///     Object.defineProperty(exports, "__esModule", { value: true });
+    ///     exports.twoPi = void 0;
+    ///     exports.twoPi = constants_1.valueOfPi * 2;
+    /// });
+    /// ```
+    ///
+    /// ## System
+    ///
+    /// ```js
+    /// System.register(["./constants"], function (exports_1, context_1) {
+    ///     "use strict";
+    ///     var constants_1, twoPi;
+    ///     var __moduleName = context_1 && context_1.id;
+    ///     return {
+    ///         setters: [
+    ///             function (constants_1_1) {
+    ///                 constants_1 = constants_1_1;
+    ///             }
+    ///         ],
+    ///         execute: function () {
+    ///             exports_1("twoPi", twoPi = constants_1.valueOfPi * 2);
+    ///         }
+    ///     };
+    /// });
+    /// ```
+    ///
+    /// ## ESNext
+    ///
+    /// ```js
+    /// import { valueOfPi } from "./constants";
+    /// export const twoPi = valueOfPi * 2;
+    /// ```
+    ///
+    /// ## ES2020
+    ///
+    /// ```js
+    /// import { valueOfPi } from "./constants";
+    /// export const twoPi = valueOfPi * 2;
+    /// ```
+    ///
+    /// ## None
+    ///
+    /// ```js
+    /// "use strict";
+    /// Object.defineProperty(exports, "__esModule", { value: true });
+    /// exports.twoPi = void 0;
+    /// const constants_1 = require("./constants");
+    /// exports.twoPi = constants_1.valueOfPi * 2;
+    /// ```
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 }
 
-/// Controls how JSX constructs are emitted in JavaScript files. This only affects output of JS files that started in .tsx files.
-///
-///
-/// For example, this sample code:
-///
-/// ```tsx
-/// export const helloWorld = () => <h1>Hello world</h1>;
-/// ```
-///
-/// Default: "react"
-///
-/// ```tsx
-/// export const helloWorld = () => React.createElement("h1", null, "Hello world");
-/// ```
-///
-/// Preserve: "preserve"
-///
-/// ```tsx
-/// export const helloWorld = () => <h1>Hello world</h1>;
-/// ```
-///
-/// React Native: "react-native"
-///
-/// ```tsx
-/// export const helloWorld = () => <h1>Hello world</h1>;
-/// ```
-///
-/// React 17 transform: "react-jsx"
-///
-/// ```tsx
-/// import { jsx as _jsx } from "react/jsx-runtime";
-/// export const helloWorld = () => _jsx("h1", { children: "Hello world" }, void 0);
-/// ```
-///
-/// React 17 dev transform: "react-jsxdev"
-///
-/// ```tsx
-/// import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
-/// const _jsxFileName = "/home/runner/work/TypeScript-Website/TypeScript-Website/packages/typescriptlang-org/index.tsx";
-/// export const helloWorld = () => _jsxDEV("h1", { children: "Hello world" }, void 0, false, { fileName: _jsxFileName, lineNumber: 7, columnNumber: 32 }, this);
-/// ```
-#[derive(Deserialize, Debug, PartialEq, Copy, Clone)]
-#[serde(rename_all = "kebab-case")]
-pub enum Jsx {
-    /// Emit .js files with JSX changed to the equivalent React.createElement calls
-    React,
-    /// Emit .js files with the JSX changed to _jsx calls
-    ReactJsx,
-    /// Emit .js files with the JSX to _jsx calls
-    ReactJsxdev,
-    /// Emit .js files with the JSX unchanged
-    ReactNative,
-    /// Emit .jsx files with the JSX unchanged
-    Preserve,
-}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn parse_jsx() {
+        let json = r#"{"compilerOptions": {"jsx": "react-jsx"}}"#;
 
-/// The transpilation target for the emitted JavaScript.
-///
-/// Modern browsers support all `ES6` features, so `ES6` is a good choice. You might choose to set a lower target if your code
-/// is deployed to older environments, or a higher target if your code is guaranteed to run in newer environments.
-///
-/// The target setting changes which JS features are downleveled and which are left intact. For example, an arrow
-/// function () => this will be turned into an equivalent function expression if target is `ES5` or lower.
-///
-/// Changing target also changes the default value of lib. You may “mix and match” target and lib settings
-/// as desired, but you could just set target for convenience.
-///
-/// For developer platforms like Node will have a certain baselines for the their target depending on their version.
-/// You can find a set of community organized TSConfigs at tsconfig/bases for common platforms and their versions.
-///
-/// The special `ESNext` value refers to the highest version your version of TypeScript supports. This setting should be
-/// used with caution, since it doesn’t mean the same thing between different TypeScript versions and can
-/// make upgrades less predictable.
-#[derive(Debug, PartialEq, Clone)]
-pub enum Target {
-    Es3,
-    Es5,
-    Es2015,
-    Es6,
-    Es2016,
-    Es7,
-    Es2017,
-    Es2018,
-    Es2019,
-    Es2020,
-    Es2021,
-    Es2022,
-    Es2023,
-    EsNext,
-    Other(String),
-}
-impl<'de> Deserialize<'de> for Target {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        let s = s.to_uppercase();
-
-        let d = match s.as_str() {
-            "ES5" => Target::Es5,
-            "ES2015" => Target::Es2015,
-            "ES6" => Target::Es6,
-            "ES2016" => Target::Es2016,
-            "ES7" => Target::Es7,
-            "ES2017" => Target::Es2017,
-            "ES2018" => Target::Es2018,
-            "ES2019" => Target::Es2019,
-            "ES2020" => Target::Es2020,
-            "ES2021" => Target::Es2021,
-            "ES2022" => Target::Es2022,
-            "ES2023" => Target::Es2023,
-            "ESNEXT" => Target::EsNext,
-            other => Target::Other(other.to_string()),
+        let config = TsConfig::parse_str(json).unwrap();
+        assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactJsx));
+    }
+
+    #[test]
+    fn parse_target() {
+        let target = |value: &str| {
+            let json = format!(r#"{{"compilerOptions": {{"target": "{}"}}}}"#, value);
+            let config = TsConfig::parse_str(&json).unwrap();
+            config.compiler_options.unwrap().target.unwrap()
         };
+        assert_eq!(target("ES3"), Target::Es3);
+        assert_eq!(target("es2021"), Target::Es2021);
+        assert_eq!(target("ES2024"), Target::Es2024);
+        assert_eq!(target("ESNext"), Target::EsNext);
+        assert_eq!(target("es2030"), Target::Other("es2030".to_string()));
+    }
+
+    #[test]
+    fn parse_lib() {
+        let json = r#"{"compilerOptions": {"lib": [
+            "ESNext", "es2024.promise", "ES2017.Object", "es2017.intl", "es2016.array.include",
+            "dom.asynciterable", "esnext.disposable", "decorators.legacy", "es2022.full"
+        ]}}"#;
+        let config = TsConfig::parse_str(json).unwrap();
+        assert_eq!(
+            config.compiler_options.unwrap().lib.unwrap(),
+            [
+                Lib::EsNext,
+                Lib::Es2024Promise,
+                Lib::Es2017Object,
+                Lib::Es2017Intl,
+                Lib::Es2016ArrayInclude,
+                Lib::DomAsyncIterable,
+                Lib::EsNextDisposable,
+                Lib::DecoratorsLegacy,
+                Lib::Es2022Full,
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_option_aliases() {
+        let json = r#"{"compilerOptions": {"target": "ES6", "module": "es6", "lib": [
+            "es7", "ES6", "lib.es2020.full.d.ts", "lib.dom.iterable.d.ts"
+        ]}}"#;
+        let options = TsConfig::parse_str(json).unwrap().compiler_options.unwrap();
+        assert_eq!(options.target, Some(Target::Es2015));
+        assert_eq!(options.module, Some(Module::Es2015));
+        assert_eq!(
+            options.lib.unwrap(),
+            [Lib::Es2016, Lib::Es2015, Lib::Es2020Full, Lib::DomIterable]
+        );
 
-        Ok(d)
+        assert_eq!("es7".parse::<Target>().unwrap().as_str(), "ES2016");
+        assert_eq!("LIB.ES2015.D.TS".parse::<Lib>().unwrap().as_str(), "es2015");
+        assert_eq!(Lib::Other("es2030.foo".to_string()).as_str(), "es2030.foo");
+        assert_eq!(Jsx::ReactJsx.as_str(), "react-jsx");
     }
-}
 
-/// Available definitions for built-in JS APIs.
-///
-/// TypeScript includes a default set of type definitions for built-in JS APIs (like Math), as well as type definitions for things found in browser environments (like document). TypeScript also includes APIs for newer JS features matching the target you specify; for example the definition for Map is available if target is ES6 or newer.
-///
-/// You may want to change these for a few reasons:
-///
-/// * Your program doesn't run in a browser, so you don’t want the "dom" type definitions
-/// * Your runtime platform provides certain JavaScript API objects (maybe through polyfills), but doesn't yet support the full syntax of a given ECMAScript version
-/// * You have polyfills or native implementations for some, but not all, of a higher level ECMAScript version
-///
-#[derive(Debug, PartialEq, Clone)]
-pub enum Lib {
-    Es5,
-    Es2015,
-    Es6,
-    Es2016,
-    Es7,
-    Es2017,
-    Es2018,
-    Es2019,
-    Es2020,
-    Es2021,
-    Es2022,
-    Es2023,
-    EsNext,
-    Dom,
-    WebWorker,
-    ScriptHost,
-    DomIterable,
-    Es2015Core,
-    Es2015Generator,
-    Es2015Iterable,
-    Es2015Promise,
-    Es2015Proxy,
-    Es2015Reflect,
-    Es2015Symbol,
-    Es2015SymbolWellKnown,
-    Es2016ArrayInclude,
-    Es2017Object,
-    Es2017Intl,
-    Es2017SharedMemory,
-    Es2017String,
-    Es2017TypedArrays,
-    Es2018Intl,
-    Es2018Promise,
-    Es2018RegExp,
-    Es2019Array,
-    Es2019Object,
-    Es2019String,
-    Es2019Symbol,
-    Es2020String,
-    Es2020SymbolWellknown,
-    EsNextAsyncIterable,
-    EsNextArray,
-    EsNextIntl,
-    EsNextSymbol,
-    Other(String),
-}
+    #[test]
+    fn parse_module() {
+        let module = |value: &str| {
+            let json = format!(r#"{{"compilerOptions": {{"module": "{}"}}}}"#, value);
+            let config = TsConfig::parse_str(&json).unwrap();
+            config.compiler_options.unwrap().module.unwrap()
+        };
+        assert_eq!(module("NodeNext"), Module::NodeNext);
+        assert_eq!(module("node16"), Module::Node16);
+        assert_eq!(module("ES2022"), Module::Es2022);
+        assert_eq!(module("preserve"), Module::Preserve);
+        assert_eq!(module("commonjs"), Module::CommonJs);
+    }
+
+    #[test]
+    fn parse_module_detection() {
+        let detection = |value: &str| {
+            let json = format!(
+                r#"{{"compilerOptions": {{"moduleDetection": "{}"}}}}"#,
+                value
+            );
+            let config = TsConfig::parse_str(&json)?;
+            Ok::<_, ConfigError>(config.compiler_options.unwrap().module_detection.unwrap())
+        };
+        assert_eq!(detection("auto").unwrap(), ModuleDetectionMode::Auto);
+        assert_eq!(detection("Legacy").unwrap(), ModuleDetectionMode::Legacy);
+        assert_eq!(detection("FORCE").unwrap(), ModuleDetectionMode::Force);
+        assert!(detection("always").is_err());
+    }
+
+    #[test]
+    fn parse_imports_not_used_as_values() {
+        let imports = |value: &str| {
+            let json = format!(
+                r#"{{"compilerOptions": {{"importsNotUsedAsValues": "{}"}}}}"#,
+                value
+            );
+            let config = TsConfig::parse_str(&json).unwrap();
+            config
+                .compiler_options
+                .unwrap()
+                .imports_not_used_as_values
+                .unwrap()
+        };
+        assert_eq!(imports("remove"), ImportsNotUsedAsValues::Remove);
+        assert_eq!(imports("Preserve"), ImportsNotUsedAsValues::Preserve);
+        assert_eq!(imports("ERROR"), ImportsNotUsedAsValues::Error);
+        assert_eq!(
+            imports("keep"),
+            ImportsNotUsedAsValues::Other("keep".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_diagnostic_options() {
+        let json = r#"{"compilerOptions": {"locale": "pt-BR", "generateCpuProfile": "profile.cpuprofile"}}"#;
+        let options = TsConfig::parse_str(json).unwrap().compiler_options.unwrap();
+        assert_eq!(options.locale.as_deref(), Some("pt-BR"));
+        assert_eq!(
+            options.generate_cpu_profile.as_deref(),
+            Some("profile.cpuprofile")
+        );
+    }
+
+    #[test]
+    fn parse_watch_modes() {
+        let json = r#"{"compilerOptions": {
+            "watchFile": "FIXEDCHUNKSIZEPOLLING",
+            "watchDirectory": "dynamicprioritypolling",
+            "fallbackPolling": "sometimes"
+        }}"#;
+        let options = TsConfig::parse_str(json).unwrap().compiler_options.unwrap();
+        assert_eq!(options.watch_file, Some(WatchFile::FixedChunkSizePolling));
+        assert_eq!(
+            options.watch_directory,
+            Some(WatchDirectory::DynamicPriorityPolling)
+        );
+        assert_eq!(
+            options.fallback_polling,
+            Some(FallbackPolling::Other("sometimes".to_string()))
+        );
+    }
 
-impl<'de> Deserialize<'de> for Lib {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    fn assert_round_trips<T>(values: &[T])
     where
-        D: Deserializer<'de>,
+        T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
     {
-        let s = String::deserialize(deserializer)?;
-        let s = s.to_uppercase();
-
-        let d = match s.as_str() {
-            "ES5" => Lib::Es5,
-            "ES2015" => Lib::Es2015,
-            "ES6" => Lib::Es6,
-            "ES2016" => Lib::Es2016,
-            "ES7" => Lib::Es7,
-            "ES2017" => Lib::Es2017,
-            "ES2018" => Lib::Es2018,
-            "ES2019" => Lib::Es2019,
-            "ES2020" => Lib::Es2020,
-            "ES2021" => Lib::Es2021,
-            "ES2022" => Lib::Es2022,
-            "ES2023" => Lib::Es2023,
-            "ESNext" => Lib::EsNext,
-            "DOM" => Lib::Dom,
-            "WEBWORKER" => Lib::WebWorker,
-            "SCRIPTHOST" => Lib::ScriptHost,
-            "DOM.ITERABLE" => Lib::DomIterable,
-            "ES2015.CORE" => Lib::Es2015Core,
-            "ES2015.GENERATOR" => Lib::Es2015Generator,
-            "ES2015.ITERABLE" => Lib::Es2015Iterable,
-            "ES2015.PROMISE" => Lib::Es2015Promise,
-            "ES2015.PROXY" => Lib::Es2015Proxy,
-            "ES2015.REFLECT" => Lib::Es2015Reflect,
-            "ES2015.SYMBOL" => Lib::Es2015Symbol,
-            "ES2015.SYMBOL.WELLKNOWN" => Lib::Es2015SymbolWellKnown,
-            "ES2015.ARRAY.INCLUDE" => Lib::Es2016ArrayInclude,
-            "ES2015.OBJECT" => Lib::Es2017Object,
-            "ES2017INTL" => Lib::Es2017Intl,
-            "ES2015.SHAREDMEMORY" => Lib::Es2017SharedMemory,
-            "ES2017.STRING" => Lib::Es2017String,
-            "ES2017.TYPEDARRAYS" => Lib::Es2017TypedArrays,
-            "ES2018.INTL" => Lib::Es2018Intl,
-            "ES2018.PROMISE" => Lib::Es2018Promise,
-            "ES2018.REGEXP" => Lib::Es2018RegExp,
-            "ES2019.ARRAY" => Lib::Es2019Array,
-            "ES2019.OBJECT" => Lib::Es2019Object,
-            "ES2019.STRING" => Lib::Es2019String,
-            "ES2019.SYMBOL" => Lib::Es2019Symbol,
-            "ES2020.STRING" => Lib::Es2020String,
-            "ES2020.SYMBOL.WELLKNOWN" => Lib::Es2020SymbolWellknown,
-            "ESNEXT.ASYNCITERABLE" => Lib::EsNextAsyncIterable,
-            "ESNEXT.ARRAY" => Lib::EsNextArray,
-            "ESNEXT.INTL" => Lib::EsNextIntl,
-            "ESNEXT.SYMBOL" => Lib::EsNextSymbol,
-            other => Lib::Other(other.to_string()),
-        };
+        for value in values {
+            let json = serde_json::to_value(value).unwrap();
+            assert_eq!(&serde_json::from_value::<T>(json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn serializes_canonical_option_values() {
+        assert_eq!(serde_json::to_value(Target::Es2015).unwrap(), "ES2015");
+        assert_eq!(serde_json::to_value(Target::EsNext).unwrap(), "ESNext");
+        assert_eq!(Module::CommonJs.to_string(), "CommonJS");
+        assert_eq!(Module::NodeNext.to_string(), "NodeNext");
+        assert_eq!(ModuleResolutionMode::Node.to_string(), "Node10");
+        assert_eq!(Jsx::ReactJsx.to_string(), "react-jsx");
+        assert_eq!(
+            Lib::Es2015SymbolWellKnown.to_string(),
+            "es2015.symbol.wellknown"
+        );
+        assert_eq!(
+            Lib::Other("es2030.foo".to_string()).to_string(),
+            "es2030.foo"
+        );
+        assert_eq!(WatchFile::UseFsEvents.to_string(), "useFsEvents");
+    }
 
-        Ok(d)
+    #[test]
+    fn option_values_round_trip() {
+        assert_round_trips(&[
+            Target::Es3,
+            Target::Es5,
+            Target::Es2015,
+            Target::Es2016,
+            Target::Es2017,
+            Target::Es2018,
+            Target::Es2019,
+            Target::Es2020,
+            Target::Es2021,
+            Target::Es2022,
+            Target::Es2023,
+            Target::Es2024,
+            Target::EsNext,
+            Target::Other("ES2030".to_string()),
+        ]);
+        assert_round_trips(&[
+            Module::CommonJs,
+            Module::Es2015,
+            Module::Es2020,
+            Module::Es2022,
+            Module::None,
+            Module::Umd,
+            Module::Amd,
+            Module::System,
+            Module::EsNext,
+            Module::Node16,
+            Module::NodeNext,
+            Module::Preserve,
+            Module::Other("NODE20".to_string()),
+        ]);
+        assert_round_trips(&[
+            Lib::Es5,
+            Lib::Es2015,
+            Lib::Es2016,
+            Lib::Es2017,
+            Lib::Es2018,
+            Lib::Es2019,
+            Lib::Es2020,
+            Lib::Es2021,
+            Lib::Es2022,
+            Lib::Es2023,
+            Lib::Es2024,
+            Lib::EsNext,
+            Lib::Dom,
+            Lib::WebWorker,
+            Lib::ScriptHost,
+            Lib::DomIterable,
+            Lib::DomAsyncIterable,
+            Lib::Es2015Core,
+            Lib::Es2015Generator,
+            Lib::Es2015Iterable,
+            Lib::Es2015Promise,
+            Lib::Es2015Proxy,
+            Lib::Es2015Reflect,
+            Lib::Es2015Symbol,
+            Lib::Es2015SymbolWellKnown,
+            Lib::Es2016ArrayInclude,
+            Lib::Es2017Object,
+            Lib::Es2017Intl,
+            Lib::Es2017SharedMemory,
+            Lib::Es2017String,
+            Lib::Es2017TypedArrays,
+            Lib::Es2018Intl,
+            Lib::Es2018Promise,
+            Lib::Es2018RegExp,
+            Lib::Es2019Array,
+            Lib::Es2019Object,
+            Lib::Es2019String,
+            Lib::Es2019Symbol,
+            Lib::Es2020String,
+            Lib::Es2020SymbolWellknown,
+            Lib::Es2021Intl,
+            Lib::Es2021Promise,
+            Lib::Es2021String,
+            Lib::Es2021WeakRef,
+            Lib::Es2022Array,
+            Lib::Es2022Error,
+            Lib::Es2022Intl,
+            Lib::Es2022Object,
+            Lib::Es2022RegExp,
+            Lib::Es2022SharedMemory,
+            Lib::Es2022String,
+            Lib::Es2023Array,
+            Lib::Es2023Collection,
+            Lib::Es2023Intl,
+            Lib::Es2024ArrayBuffer,
+            Lib::Es2024Collection,
+            Lib::Es2024Object,
+            Lib::Es2024Promise,
+            Lib::Es2024RegExp,
+            Lib::Es2024SharedMemory,
+            Lib::Es2024String,
+            Lib::EsNextAsyncIterable,
+            Lib::EsNextArray,
+            Lib::EsNextDisposable,
+            Lib::EsNextIntl,
+            Lib::EsNextSymbol,
+            Lib::Decorators,
+            Lib::DecoratorsLegacy,
+            Lib::Es2016Full,
+            Lib::Es2017Full,
+            Lib::Es2018Full,
+            Lib::Es2019Full,
+            Lib::Es2020Full,
+            Lib::Es2021Full,
+            Lib::Es2022Full,
+            Lib::Es2023Full,
+            Lib::Es2024Full,
+            Lib::EsNextFull,
+            Lib::Other("ES2030.FOO".to_string()),
+        ]);
+        assert_round_trips(&[
+            Jsx::React,
+            Jsx::ReactJsx,
+            Jsx::ReactJsxdev,
+            Jsx::ReactNative,
+            Jsx::Preserve,
+        ]);
+        assert_round_trips(&[
+            ModuleResolutionMode::Classic,
+            ModuleResolutionMode::Node,
+            ModuleResolutionMode::Node16,
+            ModuleResolutionMode::NodeNext,
+            ModuleResolutionMode::Bundler,
+            ModuleResolutionMode::Other("node20".to_string()),
+        ]);
+        assert_round_trips(&[
+            ModuleDetectionMode::Auto,
+            ModuleDetectionMode::Legacy,
+            ModuleDetectionMode::Force,
+        ]);
+        assert_round_trips(&[
+            ImportsNotUsedAsValues::Remove,
+            ImportsNotUsedAsValues::Preserve,
+            ImportsNotUsedAsValues::Error,
+            ImportsNotUsedAsValues::Other("keep".to_string()),
+        ]);
+        assert_round_trips(&[
+            WatchFile::FixedPollingInterval,
+            WatchFile::PriorityPollingInterval,
+            WatchFile::DynamicPriorityPolling,
+            WatchFile::FixedChunkSizePolling,
+            WatchFile::UseFsEvents,
+            WatchFile::UseFsEventsOnParentDirectory,
+            WatchFile::Other("inotify".to_string()),
+        ]);
+        assert_round_trips(&[
+            WatchDirectory::UseFsEvents,
+            WatchDirectory::FixedPollingInterval,
+            WatchDirectory::DynamicPriorityPolling,
+            WatchDirectory::FixedChunkSizePolling,
+            WatchDirectory::Other("inotify".to_string()),
+        ]);
+        assert_round_trips(&[
+            FallbackPolling::FixedInterval,
+            FallbackPolling::PriorityInterval,
+            FallbackPolling::DynamicPriority,
+            FallbackPolling::FixedChunkSize,
+            FallbackPolling::Other("sometimes".to_string()),
+        ]);
     }
-}
 
-/// Sets the module system for the program.
-///
-/// See the [Modules reference page](https://www.typescriptlang.org/docs/handbook/modules.html)
-/// for more information. You very likely want "CommonJS" for node projects.
-///
-/// Changing module affects moduleResolution which also has a reference page.
-///
-/// Here’s some example output for this file:
-///
-/// ```tsx
-/// // @filename: index.ts
-/// import { valueOfPi } from "./constants";
-///
-///
-/// export const twoPi = valueOfPi * 2;
-/// ```
-///
-/// ## CommonJS
-///
-/// ```js
-/// "use strict";
-/// Object.defineProperty(exports, "__esModule", { value: true });
-/// exports.twoPi = void 0;
-/// const constants_1 = require("./constants");
-/// exports.twoPi = constants_1.valueOfPi * 2;
-/// ```
-///
-/// ## UMD
-///
-/// ```js
-/// (function (factory) {
-///     if (typeof module === "object" && typeof module.exports === "object") {
-///         var v = factory(require, exports);
-///         if (v !== undefined) module.exports = v;
-///     }
-///     else if (typeof define === "function" && define.amd) {
-///         define(["require", "exports", "./constants"], factory);
-///     }
-/// })(function (require, exports) {
-///     "use strict";
-///     Object.defineProperty(exports, "__esModule", { value: true });
-///     exports.twoPi = void 0;
-///     const constants_1 = require("./constants");
-///     exports.twoPi = constants_1.valueOfPi * 2;
-/// });
-/// ```
-///
-/// ## AMD
-///
-/// ```js
-/// define(["require", "exports", "./constants"], function (require, exports, constants_1) {
-///     "use strict";
-///     Object.defineProperty(exports, "__esModule", { value: true });
-///     exports.twoPi = void 0;
-///     exports.twoPi = constants_1.valueOfPi * 2;
-/// });
-/// ```
-///
-/// ## System
-///
-/// ```js
-/// System.register(["./constants"], function (exports_1, context_1) {
-///     "use strict";
-///     var constants_1, twoPi;
-///     var __moduleName = context_1 && context_1.id;
-///     return {
-///         setters: [
-///             function (constants_1_1) {
-///                 constants_1 = constants_1_1;
-///             }
-///         ],
-///         execute: function () {
-///             exports_1("twoPi", twoPi = constants_1.valueOfPi * 2);
-///         }
-///     };
-/// });
-/// ```
-///
-/// ## ESNext
-///
-/// ```js
-/// import { valueOfPi } from "./constants";
-/// export const twoPi = valueOfPi * 2;
-/// ```
-///
-/// ## ES2020
-///
-/// ```js
-/// import { valueOfPi } from "./constants";
-/// export const twoPi = valueOfPi * 2;
-/// ```
-///
-/// ## None
-///
-/// ```js
-/// "use strict";
-/// Object.defineProperty(exports, "__esModule", { value: true });
-/// exports.twoPi = void 0;
-/// const constants_1 = require("./constants");
-/// exports.twoPi = constants_1.valueOfPi * 2;
-/// ```
-#[derive(Debug, Clone, PartialEq)]
-pub enum Module {
-    CommonJs,
-    Es6,
-    Es2015,
-    Es2020,
-    None,
-    Umd,
-    Amd,
-    System,
-    EsNext,
-    Other(String),
-}
+    #[test]
+    fn parses_option_values_from_str() {
+        assert_eq!("es2022".parse(), Ok(Target::Es2022));
+        assert_eq!("NodeNext".parse(), Ok(Module::NodeNext));
+        assert_eq!("DOM.Iterable".parse(), Ok(Lib::DomIterable));
+        assert_eq!("bundler".parse(), Ok(ModuleResolutionMode::Bundler));
+        assert_eq!("react-jsx".parse(), Ok(Jsx::ReactJsx));
+
+        let err = "react-dom".parse::<Jsx>().unwrap_err();
+        assert_eq!(err.value(), "react-dom");
+        assert_eq!(
+            err.to_string(),
+            "unknown value `react-dom`, expected one of `react`, `react-jsx`, `react-jsxdev`, `react-native`, `preserve`"
+        );
+    }
 
-impl<'de> Deserialize<'de> for Module {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    fn assert_option_enum<T>(table: Vec<(T, &[&str])>)
     where
-        D: Deserializer<'de>,
+        T: FromStr + fmt::Display + Serialize + DeserializeOwned + PartialEq + fmt::Debug,
+        T::Err: fmt::Debug,
     {
-        let s = String::deserialize(deserializer)?;
-        let s = s.to_uppercase();
-
-        let r = match s.as_str() {
-            "COMMONJS" => Module::CommonJs,
-            "ESNEXT" => Module::EsNext,
-            "ES6" => Module::Es6,
-            "ES2015" => Module::Es2015,
-            "ES2020" => Module::Es2020,
-            "NONE" => Module::None,
-            "UMD" => Module::Umd,
-            "AMD" => Module::Amd,
-            "SYSTEM" => Module::System,
-            other => Module::Other(other.to_string()),
+        for (variant, names) in table {
+            assert_eq!(variant.to_string(), names[0]);
+            for name in names {
+                for name in [name.to_string(), name.to_lowercase(), name.to_uppercase()] {
+                    assert_eq!(name.parse::<T>().unwrap(), variant, "parsing {}", name);
+                }
+            }
+            let json = serde_json::to_value(&variant).unwrap();
+            assert_eq!(serde_json::from_value::<T>(json).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn option_enums_match_their_names() {
+        assert_option_enum(FallbackPolling::table());
+        assert_option_enum(ImportsNotUsedAsValues::table());
+        assert_option_enum(Jsx::table());
+        assert_option_enum(Lib::table());
+        assert_option_enum(Module::table());
+        assert_option_enum(ModuleDetectionMode::table());
+        assert_option_enum(ModuleResolutionMode::table());
+        assert_option_enum(Target::table());
+        assert_option_enum(WatchDirectory::table());
+        assert_option_enum(WatchFile::table());
+    }
+
+    #[test]
+    fn tsc_init_default_parses_strictly() {
+        let json = TsConfig::tsc_init_default().to_string_pretty().unwrap();
+        let options = TsConfig::parse_str_strict(&json)
+            .unwrap()
+            .compiler_options
+            .unwrap();
+        assert_eq!(options.target, Some(Target::EsNext));
+        assert_eq!(options.module_detection, Some(ModuleDetectionMode::Force));
+        assert_eq!(options.types, Some(vec![]));
+    }
+
+    #[test]
+    fn tsc_init_default_serializes_tweaks() {
+        let mut config = TsConfig::tsc_init_default();
+        config.include = Some(vec!["src".to_string()]);
+        let options = config.compiler_options.as_mut().unwrap();
+        options.strict = Some(false);
+        options.types = None;
+        options.out_dir = Some("dist".to_string());
+
+        let json = config.to_string_pretty().unwrap();
+        assert_eq!(
+            json,
+            r#"{
+  "compilerOptions": {
+    "module": "nodenext",
+    "target": "esnext",
+    "sourceMap": true,
+    "declaration": true,
+    "declarationMap": true,
+    "noUncheckedIndexedAccess": true,
+    "exactOptionalPropertyTypes": true,
+    "strict": false,
+    "jsx": "react-jsx",
+    "verbatimModuleSyntax": true,
+    "isolatedModules": true,
+    "noUncheckedSideEffectImports": true,
+    "moduleDetection": "force",
+    "skipLibCheck": true,
+    "outDir": "dist"
+  },
+  "include": [
+    "src"
+  ]
+}"#
+        );
+        assert_eq!(TsConfig::parse_str(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn strict_parse_rejects_unknown_values() {
+        let json = r#"{"compilerOptions": {"target": "ES2017", "lib": ["dom", "es2017.objekt"]}}"#;
+        match TsConfig::parse_str_strict(json).unwrap_err() {
+            ConfigError::UnknownValue {
+                field,
+                value,
+                suggestion,
+            } => {
+                assert_eq!(field, "compilerOptions.lib.1");
+                assert_eq!(value, "es2017.objekt");
+                assert_eq!(suggestion.as_deref(), Some("es2017.object"));
+            }
+            other => panic!("expected an unknown value, got {:?}", other),
+        }
+
+        let json = r#"{"watchOptions": {"watchFile": "inotify"}}"#;
+        let err = TsConfig::parse_str_strict(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown value `inotify` for watchOptions.watchFile"
+        );
+
+        let json = r#"{"compilerOptions": {"target": "es2030"}}"#;
+        let config = TsConfig::parse_str(json).unwrap();
+        assert_eq!(
+            config.compiler_options.unwrap().target,
+            Some(Target::Other("es2030".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_module_resolution() {
+        let resolution = |value: &str| {
+            let json = format!(
+                r#"{{"compilerOptions": {{"moduleResolution": "{}"}}}}"#,
+                value
+            );
+            let config = TsConfig::parse_str(&json).unwrap();
+            config.compiler_options.unwrap().module_resolution.unwrap()
         };
+        assert_eq!(resolution("Bundler"), ModuleResolutionMode::Bundler);
+        assert_eq!(resolution("NODE16"), ModuleResolutionMode::Node16);
+        assert_eq!(resolution("nodenext"), ModuleResolutionMode::NodeNext);
+        assert_eq!(resolution("node10"), ModuleResolutionMode::Node);
+        assert_eq!(resolution("Classic"), ModuleResolutionMode::Classic);
+        assert_eq!(
+            resolution("node20"),
+            ModuleResolutionMode::Other("node20".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_paths() {
+        let json = r#"{
+        "compilerOptions": {
+            "baseUrl": "src",
+            "paths": {
+                "tests/*": ["tests/*"],
+                "blah": ["bloop"]
+            }
+        }
+    }
+        
+        "#;
+
+        let config = TsConfig::parse_str(json).unwrap();
+        assert_eq!(
+            config
+                .compiler_options
+                .unwrap()
+                .paths
+                .unwrap()
+                .get("tests/*"),
+            Some(&vec!["tests/*".to_string()])
+        );
+    }
+
+    #[test]
+    fn parse_empty() {
+        TsConfig::parse_str("{}").unwrap();
+        TsConfig::parse_str(r#"{"compilerOptions": {}}"#).unwrap();
+    }
+
+    #[test]
+    fn parse_default() {
+        let json = include_str!("../test/tsconfig.default.json");
+        TsConfig::parse_str(json).unwrap();
+    }
+
+    #[test]
+    fn parse_compile_on_save() {
+        let json = r#"{"compileOnSave": true, "compilerOptions": {}}"#;
+        let config = TsConfig::parse_str_strict(json).unwrap();
+        assert_eq!(config.compile_on_save, Some(true));
+
+        let json = include_str!("../test/tsconfig.complete.json");
+        let config = TsConfig::parse_str(json).unwrap();
+        assert_eq!(config.compile_on_save, Some(false));
+    }
 
-        Ok(r)
+    #[test]
+    fn parse_watch_options() {
+        let json = r#"{
+            "watchOptions": {
+                "watchFile": "useFsEvents",
+                "watchDirectory": "useFsEvents",
+                "fallbackPolling": "dynamicPriority",
+                "synchronousWatchDirectory": true,
+                "excludeDirectories": ["**/node_modules", "_build"],
+                "excludeFiles": ["build/fileWhichChangesOften.ts"]
+            }
+        }"#;
+        let config = TsConfig::parse_str_strict(json).unwrap();
+        let watch = config.watch_options.unwrap();
+        assert_eq!(watch.watch_file, Some(WatchFile::UseFsEvents));
+        assert_eq!(watch.watch_directory, Some(WatchDirectory::UseFsEvents));
+        assert_eq!(
+            watch.fallback_polling,
+            Some(FallbackPolling::DynamicPriority)
+        );
+        assert_eq!(watch.synchronous_watch_directory, Some(true));
+        assert_eq!(
+            watch.exclude_directories,
+            Some(vec!["**/node_modules".to_string(), "_build".to_string()])
+        );
+        assert_eq!(
+            watch.exclude_files,
+            Some(vec!["build/fileWhichChangesOften.ts".to_string()])
+        );
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
     #[test]
-    fn parse_jsx() {
-        let json = r#"{"compilerOptions": {"jsx": "react-jsx"}}"#;
+    fn parse_ts_node_options() {
+        let json = r#"{
+            "compilerOptions": {"module": "esnext"},
+            "ts-node": {
+                "transpileOnly": true,
+                "ignoreDiagnostics": [2307, "TS1234"],
+                "moduleTypes": {"webpack.config.ts": "cjs"},
+                "someFutureOption": "x",
+                "compilerOptions": {"module": "commonjs"}
+            }
+        }"#;
+        let config = TsConfig::parse_str_strict(json).unwrap();
+        let ts_node = config.ts_node.unwrap();
+        assert_eq!(ts_node.unknown_fields()["someFutureOption"], "x");
+        assert_eq!(ts_node.transpile_only, Some(true));
+        assert_eq!(ts_node.ignore_diagnostics.unwrap().len(), 2);
+        assert_eq!(
+            ts_node.module_types.as_ref().unwrap()["webpack.config.ts"],
+            "cjs".to_string()
+        );
+        assert_eq!(
+            ts_node.compiler_options.unwrap().module,
+            Some(Module::CommonJs)
+        );
+    }
 
+    #[test]
+    fn parse_type_acquisition_bool() {
+        let json = r#"{"typeAcquisition": true}"#;
         let config = TsConfig::parse_str(json).unwrap();
-        assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactJsx));
+        assert!(matches!(
+            config.type_acquisition,
+            Some(TypeAcquisition::Bool(true))
+        ));
     }
 
     #[test]
-    fn parse_paths() {
+    fn parse_type_acquisition_object() {
         let json = r#"{
-        "compilerOptions": {
-            "baseUrl": "src",
-            "paths": {
-                "tests/*": ["tests/*"],
-                "blah": ["bloop"]
+            "typeAcquisition": {
+                "enable": true,
+                "include": ["jest"],
+                "disableFilenameBasedTypeAcquisition": true
             }
+        }"#;
+        let config = TsConfig::parse_str_strict(json).unwrap();
+        match config.type_acquisition.unwrap() {
+            TypeAcquisition::Object {
+                enable,
+                include,
+                exclude,
+                disable_filename_based_type_acquisition,
+            } => {
+                assert_eq!(enable, Some(true));
+                assert_eq!(include, Some(vec!["jest".to_string()]));
+                assert_eq!(exclude, None);
+                assert_eq!(disable_filename_based_type_acquisition, Some(true));
+            }
+            other => panic!("expected an object, got {:?}", other),
         }
-    }
-        
-        "#;
 
+        let json = r#"{"typeAcquisition": {"exclude": ["jquery"]}}"#;
         let config = TsConfig::parse_str(json).unwrap();
-        assert_eq!(
-            config
-                .compiler_options
-                .unwrap()
-                .paths
-                .unwrap()
-                .get("tests/*"),
-            Some(&vec!["tests/*".to_string()])
-        );
+        assert!(matches!(
+            config.type_acquisition,
+            Some(TypeAcquisition::Object { enable: None, .. })
+        ));
     }
 
     #[test]
-    fn parse_empty() {
-        TsConfig::parse_str("{}").unwrap();
-        TsConfig::parse_str(r#"{"compilerOptions": {}}"#).unwrap();
+    fn parse_plugins() {
+        let json = r#"{
+            "compilerOptions": {
+                "plugins": [
+                    {"name": "typescript-styled-plugin", "tags": ["styled"]},
+                    {"transform": "rbxts-transform-env", "after": true}
+                ]
+            }
+        }"#;
+        let config = TsConfig::parse_str_strict(json).unwrap();
+        let plugins = config.compiler_options.unwrap().plugins.unwrap();
+
+        assert_eq!(plugins[0].name.as_deref(), Some("typescript-styled-plugin"));
+        assert_eq!(plugins[0].transform, None);
+        assert_eq!(plugins[0].options["tags"], serde_json::json!(["styled"]));
+
+        assert_eq!(plugins[1].name, None);
+        assert_eq!(plugins[1].transform.as_deref(), Some("rbxts-transform-env"));
+        assert_eq!(plugins[1].options["after"], true);
+        assert!(!plugins[1].options.contains_key("transform"));
     }
 
     #[test]
-    fn parse_default() {
-        let json = include_str!("../test/tsconfig.default.json");
-        TsConfig::parse_str(json).unwrap();
+    fn deserialize_vendor_sections() {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct VueCompilerOptions {
+            target: f64,
+            plugins: Vec<String>,
+        }
+
+        let json = r#"{"compilerOptions": {"strict": true}, "vueCompilerOptions": {"target": 2.7, "plugins": ["a"]}}"#;
+        let config = TsConfig::parse_str(json).unwrap();
+        let vue: VueCompilerOptions = config.section("vueCompilerOptions").unwrap().unwrap();
+        assert_eq!(vue.target, 2.7);
+        assert_eq!(vue.plugins, vec!["a".to_string()]);
+
+        // Known sections are available too, missing ones are None, and type errors are reported.
+        let options: CompilerOptions = config.section("compilerOptions").unwrap().unwrap();
+        assert_eq!(options.strict, Some(true));
+        assert!(config.section::<Value>("watchOptions").unwrap().is_none());
+        assert!(config.section::<bool>("vueCompilerOptions").is_err());
     }
 
     #[test]
@@ -942,20 +3718,394 @@ mod test {
         TsConfig::parse_str(json).unwrap();
     }
 
+    #[test]
+    fn captures_unknown_fields() {
+        let json = r#"{
+            "bleep": true,
+            "angularCompilerOptions": {"strictTemplates": true},
+            "compilerOptions": {"someNewUnsupportedProperty": false, "strict": true}
+        }"#;
+        let config = TsConfig::parse_str(json).unwrap();
+
+        let unknown = config.unknown_fields();
+        assert_eq!(unknown.len(), 2);
+        assert_eq!(unknown["bleep"], Value::Bool(true));
+        assert_eq!(
+            unknown["angularCompilerOptions"]["strictTemplates"],
+            Value::Bool(true)
+        );
+
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.strict, Some(true));
+        assert_eq!(opts.unknown_fields().len(), 1);
+        assert_eq!(
+            opts.unknown_fields()["someNewUnsupportedProperty"],
+            Value::Bool(false)
+        );
+    }
+
+    #[test]
+    fn strict_parse_rejects_unknown_fields() {
+        let err = TsConfig::parse_str_strict(r#"{"bleep": true}"#).unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownField(field) if field == "bleep"));
+
+        let json = r#"{"compilerOptions": {"someNewUnsupportedProperty": false}}"#;
+        let err = TsConfig::parse_str_strict(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown field `compilerOptions.someNewUnsupportedProperty`"
+        );
+
+        let json = r#"{"watchOptions": {"watchFile": "useFsEvents", "watchFiel": "useFsEvents"}}"#;
+        let err = TsConfig::parse_str_strict(json).unwrap_err();
+        assert!(
+            matches!(err, ConfigError::UnknownField(field) if field == "watchOptions.watchFiel")
+        );
+
+        let json = r#"{"typeAcquisition": {"enable": true, "exlude": ["jquery"]}}"#;
+        let err = TsConfig::parse_str_strict(json).unwrap_err();
+        assert!(
+            matches!(err, ConfigError::UnknownField(field) if field == "typeAcquisition.exlude")
+        );
+
+        let json = r#"{
+            "$schema": "https://json.schemastore.org/tsconfig",
+            "include": ["src"],
+            "compilerOptions": {"strict": true},
+            "watchOptions": {"excludeDirectories": ["dist"]},
+            "typeAcquisition": {"enable": false}
+        }"#;
+        TsConfig::parse_str_strict(json).unwrap();
+
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/a/tsconfig.inherits_again.json");
+        TsConfig::parse_file_strict(&path).unwrap();
+    }
+
     #[test]
     fn ignores_dangling_commas() {
         let json = r#"{"compilerOptions": {"noImplicitAny": false,"explainFiles": true,}}"#;
         let cfg = TsConfig::parse_str(json).unwrap();
-        assert_eq!(cfg.compiler_options.unwrap().explain_files.unwrap(), true);
+        assert!(cfg.compiler_options.unwrap().explain_files.unwrap());
 
         let json = r#"{"compilerOptions": {"noImplicitAny": false,"explainFiles": true, }}"#;
         let cfg = TsConfig::parse_str(json).unwrap();
-        assert_eq!(cfg.compiler_options.unwrap().explain_files.unwrap(), true);
+        assert!(cfg.compiler_options.unwrap().explain_files.unwrap());
 
         let json = r#"{"compilerOptions": {"noImplicitAny": false,"explainFiles": true,
     }}"#;
         let cfg = TsConfig::parse_str(json).unwrap();
-        assert_eq!(cfg.compiler_options.unwrap().explain_files.unwrap(), true);
+        assert!(cfg.compiler_options.unwrap().explain_files.unwrap());
+
+        let json = r#"{"include": ["src", "test",], "compilerOptions": {"lib": ["dom",]}}"#;
+        let cfg = TsConfig::parse_str(json).unwrap();
+        assert_eq!(
+            cfg.include,
+            Some(vec!["src".to_string(), "test".to_string()])
+        );
+        assert_eq!(cfg.compiler_options.unwrap().lib, Some(vec![Lib::Dom]));
+    }
+
+    #[test]
+    fn keeps_comment_like_strings() {
+        let json = r#"{"compilerOptions": {"outDir": "dist/*,}*/", "baseUrl": "//src"}}"#;
+        let cfg = TsConfig::parse_str(json).unwrap();
+        let opts = cfg.compiler_options.unwrap();
+        assert_eq!(opts.out_dir, Some("dist/*,}*/".to_string()));
+        assert_eq!(opts.base_url, Some("//src".to_string()));
+    }
+
+    #[test]
+    fn reports_error_position_in_original_text() {
+        let json = "{\n  /* a comment\n     over two lines */\n  \"compilerOptions\": {\n    \"strict\": 12,\n  }\n}";
+        let err = TsConfig::parse_str(json).unwrap_err();
+        match err {
+            ConfigError::ParseError(err) => {
+                assert_eq!(err.line(), 5);
+                assert_eq!(err.column(), 16);
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn lenient_parse_collects_every_error() {
+        let json = r#"{
+            "include": "src",
+            "files": ["a.ts"],
+            "compilerOptions": {
+                "strict": "yes",
+                "target": "es2020",
+                "paths": [],
+            }
+        }"#;
+        let (config, diagnostics) = TsConfig::parse_str_lenient(json).unwrap();
+
+        assert_eq!(config.files, Some(vec!["a.ts".to_string()]));
+        assert_eq!(config.include, None);
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.target, Some(Target::Es2020));
+        assert_eq!(opts.strict, None);
+
+        let fields: Vec<_> = diagnostics.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["include", "compilerOptions.strict", "compilerOptions.paths"]
+        );
+        assert_eq!(diagnostics[1].span.unwrap().line, 5);
+    }
+
+    #[test]
+    fn lenient_parse_can_be_edited() {
+        let json = r#"{"compilerOptions": {"strict": "yes", "target": "es2020"}}"#;
+
+        let (mut config, _) = TsConfig::parse_str_lenient(json).unwrap();
+        config.set("compilerOptions.noEmit", true).unwrap();
+        assert_eq!(
+            config.raw()["compilerOptions"],
+            serde_json::json!({"strict": "yes", "target": "es2020", "noEmit": true})
+        );
+        config.set("compilerOptions.strict", false).unwrap();
+        assert_eq!(config.compiler_options.unwrap().strict, Some(false));
+
+        let (mut config, _) = TsConfig::parse_str_lenient(json).unwrap();
+        config
+            .apply_merge_patch(serde_json::json!({"compilerOptions": {"noEmit": true}}))
+            .unwrap();
+        assert_eq!(config.compiler_options.unwrap().no_emit, Some(true));
+
+        let (mut config, _) = TsConfig::parse_str_recover(json);
+        assert_eq!(
+            config.unset("compilerOptions.target").unwrap(),
+            Some("es2020".into())
+        );
+        assert!(config.set_pointer("/compilerOptions/strict", "no").is_err());
+    }
+
+    #[test]
+    fn lenient_parse_still_rejects_malformed_json() {
+        assert!(TsConfig::parse_str_lenient(r#"{"compilerOptions": {"#).is_err());
+    }
+
+    #[test]
+    fn recover_parse_keeps_valid_members() {
+        let json = r#"{
+            "files": ["a.ts",, "b.ts"],
+            "compilerOptions": {
+                "strict": true
+                "target": "es2020",
+                "noEmit": 3,
+        "#;
+        let (config, diagnostics) = TsConfig::parse_str_recover(json);
+
+        assert_eq!(
+            config.files,
+            Some(vec!["a.ts".to_string(), "b.ts".to_string()])
+        );
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.strict, Some(true));
+        assert_eq!(opts.target, Some(Target::Es2020));
+        assert_eq!(opts.no_emit, None);
+
+        let fields: Vec<_> = diagnostics.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "files",
+                "compilerOptions",
+                "compilerOptions.noEmit",
+                "compilerOptions",
+                "",
+            ]
+        );
+        assert_eq!(diagnostics[1].span.unwrap().line, 5);
+    }
+
+    #[test]
+    fn recover_parse_handles_non_objects() {
+        let (config, diagnostics) = TsConfig::parse_str_recover("[1, 2]");
+        assert!(config.compiler_options.is_none());
+        assert_eq!(diagnostics.len(), 1);
+
+        let (_, diagnostics) = TsConfig::parse_str_recover("");
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn recover_parse_survives_deep_nesting() {
+        let (config, diagnostics) = TsConfig::parse_str_recover(&"{\"a\":".repeat(10000));
+        assert!(config.compiler_options.is_none());
+        assert!(!diagnostics.is_empty());
+
+        let (_, diagnostics) = TsConfig::parse_str_recover(&"[".repeat(200_000));
+        assert!(!diagnostics.is_empty());
+    }
+
+    #[cfg(feature = "json5")]
+    #[test]
+    fn parse_json5() {
+        let json = r#"{
+            // Unquoted keys and single quotes
+            include: ['src/**/*'],
+            compilerOptions: {
+                target: 'ES2020',
+                strict: true,
+                paths: {'@app/*': ['src/*']},
+            },
+        }"#;
+        let config = TsConfig::parse_json5_str(json).unwrap();
+        assert_eq!(config.include, Some(vec!["src/**/*".to_string()]));
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.target, Some(Target::Es2020));
+        assert_eq!(opts.strict, Some(true));
+
+        assert!(TsConfig::parse_str("{include: ['src']}").is_err());
+    }
+
+    #[test]
+    fn parse_from_reader() {
+        let file = std::fs::File::open(
+            Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+                .join("test/tsconfig.complete.json"),
+        )
+        .unwrap();
+        let config = TsConfig::parse_reader(std::io::BufReader::new(file)).unwrap();
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.target, Some(Target::EsNext));
+        assert_eq!(opts.jsx_factory, Some("React.createElement".to_string()));
+
+        for json in [
+            include_str!("../test/tsconfig.default.json"),
+            r#"{"compilerOptions": {}},"#,
+        ] {
+            assert_eq!(
+                parse_reader_to_value(json.as_bytes()).unwrap(),
+                parse_to_value(json).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn serializes_only_the_fields_that_are_set() {
+        let config = TsConfig::parse_str(
+            r#"{"extends": "./base.json", "compilerOptions": {"strict": true}}"#,
+        )
+        .unwrap();
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"extends": "./base.json", "compilerOptions": {"strict": true}})
+        );
+        assert!(!serde_json::to_string(&config).unwrap().contains("null"));
+    }
+
+    #[test]
+    fn writes_typed_edits_into_the_json() {
+        let mut config = TsConfig::tsc_init_default();
+        let original = config.clone();
+        let opts = config.compiler_options.as_mut().unwrap();
+        opts.strict = Some(false);
+        opts.out_dir = Some("dist".to_string());
+        assert_ne!(config.fingerprint(), original.fingerprint());
+        assert_eq!(
+            original.diff(&config).added,
+            [(
+                "compilerOptions.outDir".to_string(),
+                serde_json::json!("dist")
+            )]
+        );
+        let text = config.to_string_pretty().unwrap();
+        let position = |key: &str| text.find(&format!("\"{}\"", key)).unwrap();
+        assert!(text.contains(r#""outDir": "dist""#));
+        assert!(text.contains(r#""strict": false"#));
+        assert!(position("exactOptionalPropertyTypes") < position("strict"));
+        assert!(position("strict") < position("jsx"));
+        assert!(position("skipLibCheck") < position("outDir"));
+
+        let mut config =
+            TsConfig::parse_str(r#"{"compilerOptions": {"target": "esnext"}, "x": null}"#).unwrap();
+        config.compiler_options.as_mut().unwrap().target = None;
+        config.include = Some(vec!["src".to_string()]);
+        assert_eq!(
+            *config.raw(),
+            serde_json::json!({"compilerOptions": {}, "x": null, "include": ["src"]})
+        );
+
+        let config = TsConfig {
+            include: Some(vec!["src".to_string()]),
+            ..TsConfig::default()
+        };
+        assert_eq!(*config.raw(), serde_json::json!({"include": ["src"]}));
+        let merged = TsConfig::merge(&config, &TsConfig::default());
+        assert_eq!(merged.include, Some(vec!["src".to_string()]));
+
+        let (mut config, _) = TsConfig::parse_str_lenient(
+            r#"{"include": "src", "compilerOptions": {"target": "esnext"}}"#,
+        )
+        .unwrap();
+        config.exclude = Some(vec![]);
+        assert_eq!(
+            *config.raw(),
+            serde_json::json!({"include": "src", "compilerOptions": {"target": "esnext"}, "exclude": []})
+        );
+    }
+
+    #[test]
+    fn keeps_typed_edits_when_editing_the_json() {
+        let mut config = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true}}"#).unwrap();
+        config.compiler_options.as_mut().unwrap().strict = Some(false);
+        config.set("compilerOptions.outDir", "dist").unwrap();
+        let opts = config.compiler_options.as_ref().unwrap();
+        assert_eq!(opts.strict, Some(false));
+        assert_eq!(opts.out_dir.as_deref(), Some("dist"));
+
+        config.include = Some(vec!["src".to_string()]);
+        config
+            .apply_merge_patch(serde_json::json!({"compilerOptions": {"noEmit": true}}))
+            .unwrap();
+        assert_eq!(config.include, Some(vec!["src".to_string()]));
+
+        config.exclude = Some(vec!["dist".to_string()]);
+        config.unset("compilerOptions.outDir").unwrap();
+        config.set_pointer("/files", vec!["index.ts"]).unwrap();
+        assert_eq!(
+            *config.raw(),
+            serde_json::json!({
+                "compilerOptions": {"strict": false, "noEmit": true},
+                "include": ["src"],
+                "exclude": ["dist"],
+                "files": ["index.ts"]
+            })
+        );
+    }
+
+    #[test]
+    fn retains_raw_values() {
+        let json = r#"{
+            "compilerOptions": {"strict": true, "someFutureOption": 1},
+            "vueCompilerOptions": {"target": 3},
+        }"#;
+        let config = TsConfig::parse_str(json).unwrap();
+        assert_eq!(
+            config.raw_section("compilerOptions").unwrap()["someFutureOption"],
+            1
+        );
+        assert_eq!(config.raw()["vueCompilerOptions"]["target"], 3);
+
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/tsconfig.inherits.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+        assert_eq!(
+            config.raw()["compilerOptions"]["useDefineForClassFields"],
+            false
+        );
+
+        let (config, _) = TsConfig::parse_str_lenient(r#"{"include": "src"}"#).unwrap();
+        assert_eq!(config.include, None);
+        assert_eq!(config.raw()["include"], "src");
+
+        let round_tripped = TsConfig::from_value(config.raw().into_owned()).unwrap_err();
+        assert!(matches!(round_tripped, ConfigError::ParseError(_)));
     }
 
     #[test]
@@ -978,6 +4128,143 @@ mod test {
         assert_eq!(value.compiler_options.unwrap().remove_comments, Some(true));
     }
 
+    #[test]
+    // The JSON a config caches is worked out from its fields, so it never changes its hash.
+    #[allow(clippy::mutable_key_type)]
+    fn configs_compare_and_hash_by_content() {
+        use std::collections::HashSet;
+
+        let json = r#"{"compilerOptions": {"strict": true, "target": "es2022"}}"#;
+        let a = TsConfig::parse_str(json).unwrap();
+        let b = TsConfig::parse_str_with_spans(json).unwrap();
+        let c = TsConfig::parse_str(r#"{"compilerOptions": {"strict": false}}"#).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let mut modified = a.clone();
+        modified.compiler_options.as_mut().unwrap().strict = Some(false);
+        assert_ne!(a, modified);
+
+        let configs: HashSet<_> = vec![a, b, c].into_iter().collect();
+        assert_eq!(configs.len(), 2);
+
+        let options = |json: &str| TsConfig::parse_str(json).unwrap().compiler_options.unwrap();
+        let a = options(
+            r#"{"compilerOptions": {"unknownA": 1, "plugins": [{"name": "p", "x": 1, "y": 2}], "unknownB": {"x": 1, "y": 2}}}"#,
+        );
+        let b = options(
+            r#"{"compilerOptions": {"unknownB": {"y": 2, "x": 1}, "plugins": [{"y": 2, "x": 1, "name": "p"}], "unknownA": 1}}"#,
+        );
+        let c = options(r#"{"compilerOptions": {"unknownA": 2}}"#);
+        assert_eq!(a, b);
+        let options: HashSet<_> = vec![a, b, c].into_iter().collect();
+        assert_eq!(options.len(), 2);
+    }
+
+    #[test]
+    fn applies_merge_patches() {
+        let mut value = serde_json::json!({"a": "b", "c": {"d": "e", "f": "g"}, "h": [1]});
+        merge_patch(
+            &mut value,
+            serde_json::json!({"a": "z", "c": {"f": null}, "h": [2, 3]}),
+        );
+        assert_eq!(
+            value,
+            serde_json::json!({"a": "z", "c": {"d": "e"}, "h": [2, 3]})
+        );
+
+        let mut config = TsConfig::parse_str(
+            r#"{"compilerOptions": {"a1": 1, "strict": true, "b2": 2, "noEmit": true}}"#,
+        )
+        .unwrap();
+        config
+            .apply_merge_patch(serde_json::json!({"compilerOptions": {"a1": null}}))
+            .unwrap();
+        assert_eq!(
+            config.to_string_pretty().unwrap(),
+            "{\n  \"compilerOptions\": {\n    \"strict\": true,\n    \"b2\": 2,\n    \"noEmit\": true\n  }\n}"
+        );
+
+        let mut config = TsConfig::parse_str(r#"{"include": ["src"]}"#).unwrap();
+        let err = config
+            .apply_merge_patch(serde_json::json!({"compilerOptions": {"strict": "yes"}}))
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::ParseError(_)));
+        assert_eq!(*config.raw(), serde_json::json!({"include": ["src"]}));
+    }
+
+    #[test]
+    fn merge_follows_tsc_inheritance() {
+        let base = TsConfig::parse_str(
+            r#"{
+                "compilerOptions": {"outDir": "dist", "strict": true},
+                "watchOptions": {"watchFile": "useFsEvents", "excludeDirectories": ["a"]},
+                "files": ["a.ts"],
+                "references": [{"path": "../core"}]
+            }"#,
+        )
+        .unwrap();
+        let overlay = TsConfig::parse_str(
+            r#"{"compilerOptions": {"outDir": null}, "watchOptions": {"excludeDirectories": ["b"]}}"#,
+        )
+        .unwrap();
+
+        let config = TsConfig::merge(&base, &overlay);
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.out_dir, None);
+        assert_eq!(opts.strict, Some(true));
+        let watch = config.watch_options.unwrap();
+        assert_eq!(watch.watch_file, Some(WatchFile::UseFsEvents));
+        assert_eq!(watch.exclude_directories, Some(vec!["b".to_string()]));
+        assert_eq!(config.files, Some(vec!["a.ts".to_string()]));
+        assert!(config.references.is_none());
+
+        let config = TsConfig::merge(&base, &TsConfig::default());
+        assert_eq!(config.files, Some(vec!["a.ts".to_string()]));
+    }
+
+    #[test]
+    fn resolves_references_from_the_config_dir() {
+        let json = r#"{"references": [{"path": "."}, {"path": "../b/./c.json", "prepend": true}]}"#;
+        let config = TsConfig::parse_str(json).unwrap();
+        let prepend: Vec<_> = config.references_iter().map(Reference::prepend).collect();
+        assert_eq!(prepend, [false, true]);
+        assert_eq!(
+            config.resolve_references(Path::new("/repo/a")),
+            [
+                PathBuf::from("/repo/a/tsconfig.json"),
+                PathBuf::from("/repo/b/c.json")
+            ]
+        );
+
+        let config = TsConfig::parse_str(r#"{"references": false}"#).unwrap();
+        assert_eq!(config.references_iter().count(), 0);
+    }
+
+    #[test]
+    fn parse_jsconfig_with_defaults() {
+        let config = TsConfig::parse_jsconfig_str("{}").unwrap();
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.allow_js, Some(true));
+        assert_eq!(opts.max_node_module_js_depth, Some(2));
+        assert_eq!(opts.no_emit, Some(true));
+        assert!(matches!(
+            config.type_acquisition,
+            Some(TypeAcquisition::Object {
+                enable: Some(true),
+                ..
+            })
+        ));
+
+        let json = r#"{"compilerOptions": {"allowJs": false}, "typeAcquisition": false}"#;
+        let config = TsConfig::parse_jsconfig_str(json).unwrap();
+        assert_eq!(config.compiler_options.unwrap().allow_js, Some(false));
+        assert!(matches!(
+            config.type_acquisition,
+            Some(TypeAcquisition::Bool(false))
+        ));
+    }
+
     #[test]
     fn parse_basic_file() {
         let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
@@ -1049,6 +4336,44 @@ mod test {
         assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactNative));
     }
 
+    #[test]
+    fn flatten_inheritance_chain() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/a/tsconfig.inherits_again.json");
+        let config = resolve_to_flat(&path).unwrap();
+        assert_eq!(config.extends, None);
+        assert!(!config.to_string_pretty().unwrap().contains("extends"));
+
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.use_define_for_class_fields, Some(false));
+        assert_eq!(opts.jsx, Some(Jsx::ReactNative));
+
+        let config = TsConfig::parse_str(r#"{"extends": "./tsconfig.base.json"}"#).unwrap();
+        assert!(matches!(
+            config.flattened(),
+            Err(ConfigError::ExtendsNotMerged)
+        ));
+        let config = TsConfig::parse_str(r#"{"include": ["src"]}"#).unwrap();
+        assert_eq!(config.flattened().unwrap(), config);
+    }
+
+    #[test]
+    fn parse_files_with_byte_order_marks() {
+        for name in &[
+            "tsconfig.utf8bom.json",
+            "tsconfig.utf16le.json",
+            "tsconfig.utf16be.json",
+        ] {
+            let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+                .join("test")
+                .join(name);
+            let config = TsConfig::parse_file(&path).unwrap();
+            let opts = config.compiler_options.unwrap();
+            assert_eq!(opts.strict, Some(true));
+            assert_eq!(opts.out_dir, Some("dist".to_string()));
+        }
+    }
+
     #[test]
     fn parse_no_extension_file() {
         let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
@@ -1067,4 +4392,90 @@ mod test {
             Some(false)
         );
     }
+
+    #[test]
+    fn sets_and_unsets_values_by_dotted_path() {
+        let mut config = TsConfig::parse_str(r#"{"references": [{"path": "../a"}]}"#).unwrap();
+        config.set("compilerOptions.target", "es6").unwrap();
+        config
+            .set("compilerOptions.lib", vec!["es2015", "dom"])
+            .unwrap();
+        config.set("watchOptions.watchFile", "useFsEvents").unwrap();
+        let opts = config.compiler_options.as_ref().unwrap();
+        assert_eq!(opts.target, Some(Target::Es2015));
+        assert_eq!(opts.lib, Some(vec![Lib::Es2015, Lib::Dom]));
+
+        let err = config.set("compilerOptions.target", "es20017").unwrap_err();
+        assert!(
+            matches!(err, ConfigError::UnknownValue { suggestion: Some(s), .. } if s == "ES2017")
+        );
+        let err = config.set("compilerOptions.lib", vec![1, 2]).unwrap_err();
+        assert!(err.to_string().contains("must be a list of one of"));
+        let err = config.set("compilerOption.strict", true).unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownField(field) if field == "compilerOption"));
+        assert!(config.set("references.0.path", 1).is_err());
+        assert_eq!(
+            config.compiler_options.as_ref().unwrap().target,
+            Some(Target::Es2015)
+        );
+
+        assert!(config.unset("references.0.path").is_err());
+        assert_eq!(
+            config.unset("references.0").unwrap(),
+            Some(serde_json::json!({"path": "../a"}))
+        );
+        assert_eq!(
+            config.unset("compilerOptions.lib.1").unwrap(),
+            Some("dom".into())
+        );
+        assert_eq!(
+            config.compiler_options.unwrap().lib,
+            Some(vec![Lib::Es2015])
+        );
+    }
+
+    #[test]
+    fn compiler_option_getters_follow_tsc_defaults() {
+        let opts = |json: &str| {
+            let json = format!(r#"{{"compilerOptions": {}}}"#, json);
+            TsConfig::parse_str(&json)
+                .unwrap()
+                .compiler_options
+                .unwrap()
+        };
+
+        let composite = opts(r#"{"composite": true, "target": "es2017", "sourceMap": true}"#);
+        assert!(composite.declaration_enabled());
+        assert!(composite.emits_source_maps());
+        assert_eq!(composite.effective_module(), Module::Es2015);
+        assert_eq!(
+            composite.effective_module_resolution(),
+            ModuleResolutionMode::Classic
+        );
+        assert!(!composite.is_strict());
+
+        let no_emit = opts(
+            r#"{"composite": true, "declaration": false, "inlineSourceMap": true, "noEmit": true}"#,
+        );
+        assert!(no_emit.declaration_enabled());
+        assert!(!no_emit.emits_source_maps());
+        assert!(!opts(r#"{"declaration": false}"#).declaration_enabled());
+
+        let strict = opts(r#"{"noImplicitAny": true, "strict": true}"#);
+        assert!(strict.is_strict());
+        assert_eq!(strict.effective_module(), Module::CommonJs);
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn describes_configs_with_a_json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(TsConfig)).unwrap();
+        assert!(schema["properties"]["compilerOptions"].is_object());
+        assert!(schema["properties"].get("raw").is_none());
+
+        let target = &schema["$defs"]["Target"]["anyOf"][0]["enum"];
+        assert_eq!(target[1], "ES5");
+        let jsx = &schema["$defs"]["Jsx"]["enum"];
+        assert!(jsx.as_array().unwrap().contains(&"react-jsx".into()));
+    }
 }
@@ -0,0 +1,1242 @@
+//! Loading config files from disk, following their `extends` chains.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::fs::{normalize, FileSystem, OsFileSystem};
+use crate::order::Ordered;
+use crate::{
+    encoding, from_jsonc, merge_extended, ConfigCache, ConfigError, ResolvedTsConfig, Result,
+    TsConfig, TsConfigJson,
+};
+
+/// Resolves the `extends` specifier of a config file to the path of the config it inherits from.
+///
+/// The default implementation is [NodeResolver]. Bundlers and other tools with their own module
+/// resolution can implement this trait, and pass it to [ConfigLoader::with_resolver]. Closures with
+/// the same signature as [ExtendsResolver::resolve] implement it too.
+///
+/// Resolvers that need to look at the filesystem should do so through `fs`, which is the
+/// [FileSystem] the loader reads configs from.
+///
+/// ## Example
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use tsconfig::{ConfigLoader, ExtendsResolver, FileSystem, Result};
+///
+/// /// Resolves every specifier against the repository root, rather than the extending config.
+/// struct FromRoot(PathBuf);
+///
+/// impl ExtendsResolver for FromRoot {
+///     fn resolve(&self, _from: &Path, specifier: &str, _fs: &dyn FileSystem) -> Result<PathBuf> {
+///         Ok(self.0.join(specifier))
+///     }
+/// }
+///
+/// let root = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test");
+/// let loader = ConfigLoader::new().with_resolver(FromRoot(root.clone()));
+/// let config = loader.load(&root.join("tsconfig.inherits.json")).unwrap();
+/// assert_eq!(config.compiler_options.unwrap().trace_resolution, Some(false));
+/// ```
+pub trait ExtendsResolver: Send + Sync {
+    /// Resolves `specifier`, found in the `extends` field of the config file at `from`.
+    fn resolve(&self, from: &Path, specifier: &str, fs: &dyn FileSystem) -> Result<PathBuf>;
+}
+
+impl<F> ExtendsResolver for F
+where
+    F: Fn(&Path, &str, &dyn FileSystem) -> Result<PathBuf> + Send + Sync,
+{
+    fn resolve(&self, from: &Path, specifier: &str, fs: &dyn FileSystem) -> Result<PathBuf> {
+        self(from, specifier, fs)
+    }
+}
+
+/// Resolves `extends` the way tsc does.
+///
+/// Specifiers that are absolute, or start with `./` or `../`, are paths relative to the extending
+/// config, with `.json` appended if the path as written does not exist. Anything else is looked up
+/// as a package in the `node_modules` directories above the extending config: either as a file
+/// inside the package, such as `@tsconfig/node20/tsconfig.json`, or as the package itself, in which
+/// case the file named by the `tsconfig` field of its package.json is used, falling back to its
+/// tsconfig.json.
+///
+/// If nothing is found, the error is a [ConfigError::UnresolvedExtends] listing every path that was
+/// tried.
+///
+/// ## Symlinks
+///
+/// Package managers such as pnpm install packages as symlinks into a shared store. Like tsc, a
+/// config found in `node_modules` is resolved to its real path by default, so relative paths that
+/// it sets are rebased from the directory it is really in. With
+/// [NodeResolver::with_preserve_symlinks], the path through `node_modules` is kept instead, which
+/// matches tsc's `preserveSymlinks` option. Relative specifiers are never resolved to their real
+/// path.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::{ConfigLoader, NodeResolver};
+///
+/// let loader = ConfigLoader::new().with_resolver(NodeResolver::new().with_preserve_symlinks(true));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeResolver {
+    preserve_symlinks: bool,
+}
+
+impl NodeResolver {
+    /// Creates a resolver that resolves packages to their real paths.
+    pub fn new() -> NodeResolver {
+        NodeResolver::default()
+    }
+
+    /// Whether to keep the path through `node_modules` to a package, rather than resolving symlinks.
+    pub fn with_preserve_symlinks(self, preserve_symlinks: bool) -> NodeResolver {
+        NodeResolver { preserve_symlinks }
+    }
+
+    /// Whether symlinks to packages are kept as they are.
+    pub fn preserve_symlinks(&self) -> bool {
+        self.preserve_symlinks
+    }
+}
+
+impl ExtendsResolver for NodeResolver {
+    fn resolve(&self, from: &Path, specifier: &str, fs: &dyn FileSystem) -> Result<PathBuf> {
+        let dir = normalize(from.parent().unwrap_or_else(|| Path::new("")));
+        let mut search = Search::new(fs);
+        let resolved = if is_relative_specifier(specifier) {
+            search.file(normalize(&dir.join(specifier)))
+        } else if specifier.is_empty() {
+            None
+        } else {
+            dir.ancestors()
+                .find_map(|ancestor| search.package(ancestor.join("node_modules").join(specifier)))
+                .map(|path| {
+                    if self.preserve_symlinks {
+                        path
+                    } else {
+                        fs.canonicalize(&path).unwrap_or(path)
+                    }
+                })
+        };
+        resolved.ok_or_else(|| search.unresolved(from, specifier))
+    }
+}
+
+pub(crate) fn is_relative_specifier(specifier: &str) -> bool {
+    let specifier = specifier.replace('\\', "/");
+    specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || Path::new(&specifier).is_absolute()
+}
+
+/// Looks for config files, recording every path that it looks at.
+pub(crate) struct Search<'a> {
+    fs: &'a dyn FileSystem,
+    searched: Vec<PathBuf>,
+}
+
+impl<'a> Search<'a> {
+    pub(crate) fn new(fs: &'a dyn FileSystem) -> Search<'a> {
+        Search {
+            fs,
+            searched: Vec::new(),
+        }
+    }
+
+    fn exists(&mut self, path: &Path) -> bool {
+        self.searched.push(path.to_path_buf());
+        self.fs.exists(path)
+    }
+
+    /// Finds the file at `path`, or with `.json` appended if it doesn't already end in `.json`.
+    fn file(&mut self, path: PathBuf) -> Option<PathBuf> {
+        if self.exists(&path) {
+            return Some(path);
+        }
+        if path.extension() == Some("json".as_ref()) {
+            return None;
+        }
+        let mut with_extension = path.into_os_string();
+        with_extension.push(".json");
+        let with_extension = PathBuf::from(with_extension);
+        if self.exists(&with_extension) {
+            Some(with_extension)
+        } else {
+            None
+        }
+    }
+
+    /// Finds the config at `candidate`, which is the path of an installed package or of a file inside
+    /// one.
+    pub(crate) fn package(&mut self, candidate: PathBuf) -> Option<PathBuf> {
+        if let Some(file) = self.file(candidate.clone()) {
+            return Some(file);
+        }
+        let tsconfig_field = self
+            .fs
+            .read_file(&candidate.join("package.json"))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok())
+            .and_then(|manifest| manifest["tsconfig"].as_str().map(|s| candidate.join(s)));
+        if let Some(file) = tsconfig_field.filter(|path| self.exists(path)) {
+            return Some(file);
+        }
+        Some(candidate.join("tsconfig.json")).filter(|path| self.exists(path))
+    }
+
+    /// Returns an error recording where `specifier` was searched for.
+    pub(crate) fn unresolved(self, from: &Path, specifier: &str) -> ConfigError {
+        ConfigError::UnresolvedExtends {
+            specifier: specifier.to_string(),
+            from: from.to_path_buf(),
+            searched: self.searched,
+            chain: Vec::new(),
+        }
+    }
+}
+
+/// Loads config files from disk, following their `extends` chains with a configurable
+/// [ExtendsResolver].
+///
+/// [TsConfig::parse_file] uses a default `ConfigLoader`, which resolves `extends` with [NodeResolver].
+///
+/// A config can extend a list of configs, as TypeScript 5.0 allows. They're merged in order, so
+/// each overrides those before it, and the config itself overrides them all.
+///
+/// As with tsc, relative paths in an extended config are relative to that config, rather than to the
+/// config extending it. When they are merged, the loader rewrites them to be relative to the
+/// extending config: `files`, `include` and `exclude`, the path-valued compiler options, and `paths`
+/// when no `baseUrl` is set. Paths starting with `${configDir}` are left alone, since tsc resolves
+/// them against the config that is finally loaded. Use [ConfigLoader::with_raw_paths] to keep paths
+/// exactly as they were written.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::{ConfigLoader, NodeResolver};
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/a/tsconfig.package.json");
+///
+/// // This config extends a package installed in test/node_modules.
+/// let config = ConfigLoader::new().with_resolver(NodeResolver::new()).load(&path).unwrap();
+/// assert_eq!(config.compiler_options.unwrap().strict, Some(true));
+/// ```
+#[derive(Clone)]
+pub struct ConfigLoader {
+    resolver: Arc<dyn ExtendsResolver>,
+    fs: Arc<dyn FileSystem>,
+    cache: Option<ConfigCache>,
+    rebase_paths: bool,
+    max_depth: Option<usize>,
+}
+
+impl Default for ConfigLoader {
+    fn default() -> Self {
+        ConfigLoader {
+            resolver: Arc::new(NodeResolver::new()),
+            fs: Arc::new(OsFileSystem),
+            cache: None,
+            rebase_paths: true,
+            max_depth: None,
+        }
+    }
+}
+
+impl ConfigLoader {
+    /// Creates a loader that reads from the real filesystem, and resolves `extends` with [NodeResolver].
+    pub fn new() -> ConfigLoader {
+        ConfigLoader::default()
+    }
+
+    /// Sets the resolver used for `extends` specifiers.
+    pub fn with_resolver<R: ExtendsResolver + 'static>(self, resolver: R) -> ConfigLoader {
+        ConfigLoader {
+            resolver: Arc::new(resolver),
+            ..self
+        }
+    }
+
+    /// Sets the filesystem that configs are read from, and that the resolver looks in.
+    pub fn with_file_system<F: FileSystem + 'static>(self, fs: F) -> ConfigLoader {
+        ConfigLoader {
+            fs: Arc::new(fs),
+            ..self
+        }
+    }
+
+    /// Sets a cache for parsed config files, which may be shared with other loaders.
+    pub fn with_cache(self, cache: ConfigCache) -> ConfigLoader {
+        ConfigLoader {
+            cache: Some(cache),
+            ..self
+        }
+    }
+
+    /// Whether to keep relative paths inherited through `extends` as they were written, instead of
+    /// rewriting them to be relative to the extending config.
+    pub fn with_raw_paths(self, raw_paths: bool) -> ConfigLoader {
+        ConfigLoader {
+            rebase_paths: !raw_paths,
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of configs that may be extended in a chain, counting the base of the
+    /// base and so on. Loading a deeper chain returns [ConfigError::ExtendsTooDeep].
+    ///
+    /// By default there is no limit, though a chain that loops back on itself is always an error.
+    pub fn with_max_depth(self, max_depth: usize) -> ConfigLoader {
+        ConfigLoader {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    pub(crate) fn file_system(&self) -> &dyn FileSystem {
+        &*self.fs
+    }
+
+    /// Loads the config file at `path` into a [TsConfig], merging in everything it extends.
+    ///
+    /// The returned config records the files its `extends` resolved to, as
+    /// [TsConfig::extends_paths] describes.
+    pub fn load<P: AsRef<Path>>(&self, path: &P) -> Result<TsConfig> {
+        self.load_merged(path.as_ref())?.into_config()
+    }
+
+    /// Loads the config file at `path`, merging in everything it extends, without converting it
+    /// into a [TsConfig] yet.
+    pub(crate) fn load_merged(&self, path: &Path) -> Result<Loaded> {
+        let file = read_config(&*self.fs, self.cache.as_ref(), path)?;
+        self.load_chain(self.rebase_paths, path, file)
+    }
+
+    /// Loads the config file at `path` into a [serde_json::Value], merging in everything it extends.
+    ///
+    /// Returns [ConfigError::ExtendsCycle] if a config extends itself, directly or indirectly, and
+    /// [ConfigError::UnresolvedExtends] if an `extends` specifier can't be resolved, listing the
+    /// configs loaded up to that point and the paths that were searched.
+    pub fn load_value<P: AsRef<Path>>(&self, path: &P) -> Result<Value> {
+        self.load_value_and_files(path).map(|(value, _)| value)
+    }
+
+    /// Loads the config file at `path` like [ConfigLoader::load_value], also returning the canonical
+    /// paths of every file it extends, directly or indirectly, starting with its own.
+    pub(crate) fn load_value_and_files<P: AsRef<Path>>(
+        &self,
+        path: &P,
+    ) -> Result<(Value, Vec<PathBuf>)> {
+        let path = path.as_ref();
+        let file = read_config(&*self.fs, self.cache.as_ref(), path)?;
+        let loaded = self.load_chain(self.rebase_paths, path, file)?;
+        Ok((loaded.json.value, loaded.files))
+    }
+
+    /// Reads the config file at `path` as it's written, without following `extends`.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::path::Path;
+    /// use tsconfig::ConfigLoader;
+    ///
+    /// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+    ///     .join("test/tsconfig.inherits.json");
+    /// let json = ConfigLoader::new().read_json(&path).unwrap();
+    /// assert!(!json.config().is_extends_resolved());
+    /// assert_eq!(json.config().compiler_options.as_ref().unwrap().use_define_for_class_fields, None);
+    /// ```
+    pub fn read_json<P: AsRef<Path>>(&self, path: &P) -> Result<TsConfigJson> {
+        let path = path.as_ref();
+        let file = read_config(&*self.fs, self.cache.as_ref(), path)?;
+        let config = TsConfig::from_ordered(file.json)?;
+        Ok(TsConfigJson::new(
+            path.to_path_buf(),
+            file.canonical,
+            config,
+        ))
+    }
+
+    /// Reads the config file at `path`, and resolves it as [TsConfigJson::resolve_with] describes.
+    pub fn resolve<P: AsRef<Path>>(&self, path: &P) -> Result<ResolvedTsConfig> {
+        self.read_json(path)?.resolve_with(self)
+    }
+
+    /// Merges everything that `json` extends into it, with inherited paths always rebased.
+    pub(crate) fn resolve_json(&self, json: &TsConfigJson) -> Result<Loaded> {
+        let file = ConfigFile {
+            canonical: json.canonical().to_path_buf(),
+            json: Ordered {
+                value: json.raw().into_owned(),
+                order: json.config().order().clone(),
+            },
+        };
+        self.load_chain(true, json.path(), file)
+    }
+
+    /// Follows everything that `file`, read from `path`, extends, and merges it.
+    fn load_chain(&self, rebase_paths: bool, path: &Path, file: ConfigFile) -> Result<Loaded> {
+        let mut chain = Chain::new(rebase_paths, self.max_depth);
+        chain.push(path, file)?;
+        while let Some((from, specifier)) = chain.next_specifier() {
+            match self.resolver.resolve(&from, &specifier, &*self.fs) {
+                Ok(resolved) => {
+                    let file = read_config(&*self.fs, self.cache.as_ref(), &resolved)?;
+                    chain.push(&resolved, file)?;
+                }
+                Err(err) => match bundled_preset(&specifier) {
+                    Some((path, file)) => chain.push(&path, file)?,
+                    None => return Err(chain.unresolved(err)),
+                },
+            }
+        }
+        Ok(chain.finish())
+    }
+
+    /// Loads the config file at `path` into a [TsConfig] without blocking the async runtime.
+    ///
+    /// Files are read, and `extends` is resolved, on Tokio's blocking thread pool, so this must be
+    /// called from within a Tokio runtime. It is only available with the `async` feature.
+    #[cfg(feature = "async")]
+    pub async fn load_async<P: AsRef<Path>>(&self, path: &P) -> Result<TsConfig> {
+        self.load_chain_async(path).await?.into_config()
+    }
+
+    /// Loads the config file at `path` into a [serde_json::Value] without blocking the async runtime.
+    ///
+    /// See [ConfigLoader::load_async]. It is only available with the `async` feature.
+    #[cfg(feature = "async")]
+    pub async fn load_value_async<P: AsRef<Path>>(&self, path: &P) -> Result<Value> {
+        Ok(self.load_chain_async(path).await?.json.value)
+    }
+
+    /// Loads the config file at `path` like [ConfigLoader::load_chain], without blocking the async
+    /// runtime.
+    #[cfg(feature = "async")]
+    async fn load_chain_async<P: AsRef<Path>>(&self, path: &P) -> Result<Loaded> {
+        let read = |path: PathBuf| {
+            let (fs, cache) = (self.fs.clone(), self.cache.clone());
+            unblock(move || read_config(&*fs, cache.as_ref(), &path))
+        };
+        let mut chain = Chain::new(self.rebase_paths, self.max_depth);
+        let path = path.as_ref().to_path_buf();
+        chain.push(&path, read(path.clone()).await?)?;
+        while let Some((from, specifier)) = chain.next_specifier() {
+            let resolved = {
+                let (fs, resolver) = (self.fs.clone(), self.resolver.clone());
+                let specifier = specifier.clone();
+                unblock(move || resolver.resolve(&from, &specifier, &*fs)).await
+            };
+            match resolved {
+                Ok(resolved) => {
+                    let file = read(resolved.clone()).await?;
+                    chain.push(&resolved, file)?;
+                }
+                Err(err) => match bundled_preset(&specifier) {
+                    Some((path, file)) => chain.push(&path, file)?,
+                    None => return Err(chain.unresolved(err)),
+                },
+            }
+        }
+        Ok(chain.finish())
+    }
+}
+
+/// Runs blocking IO on Tokio's blocking thread pool.
+#[cfg(feature = "async")]
+async fn unblock<T, F>(f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(err) => std::panic::resume_unwind(err.into_panic()),
+    }
+}
+
+/// Falls back to a bundled copy of an `@tsconfig/*` package that couldn't be resolved.
+#[cfg(feature = "bundled-presets")]
+fn bundled_preset(specifier: &str) -> Option<(PathBuf, ConfigFile)> {
+    crate::presets::find(specifier).map(|(path, text)| {
+        let file = ConfigFile {
+            canonical: path.clone(),
+            json: from_jsonc(text).expect("bundled presets are valid JSON"),
+        };
+        (path, file)
+    })
+}
+
+#[cfg(not(feature = "bundled-presets"))]
+fn bundled_preset(_specifier: &str) -> Option<(PathBuf, ConfigFile)> {
+    None
+}
+
+/// A config file as read from disk.
+struct ConfigFile {
+    canonical: PathBuf,
+    json: Ordered,
+}
+
+fn read_config(
+    fs: &dyn FileSystem,
+    cache: Option<&ConfigCache>,
+    path: &Path,
+) -> Result<ConfigFile> {
+    let read_error = |err| ConfigError::CouldNotReadFile(path.into(), err);
+    let canonical = fs.canonicalize(path).map_err(read_error)?;
+
+    let modified = cache.and_then(|_| fs.modified(&canonical).ok());
+    if let (Some(cache), Some(modified)) = (cache, modified) {
+        if let Some(json) = cache.get(&canonical, modified) {
+            return Ok(ConfigFile { canonical, json });
+        }
+    }
+
+    let text = fs
+        .read_file(path)
+        .and_then(encoding::decode)
+        .map_err(read_error)?;
+    let json: Ordered = from_jsonc(&text)?;
+    if let (Some(cache), Some(modified)) = (cache, modified) {
+        cache.insert(canonical.clone(), modified, json.clone());
+    }
+    Ok(ConfigFile { canonical, json })
+}
+
+/// A config with everything it extends merged in.
+pub(crate) struct Loaded {
+    pub(crate) json: Ordered,
+    /// The canonical paths of the config and every file it extends, directly or indirectly, in
+    /// the order they were read.
+    pub(crate) files: Vec<PathBuf>,
+    /// The canonical paths of the files the config extends directly, in the order they're applied.
+    pub(crate) bases: Vec<PathBuf>,
+}
+
+impl Loaded {
+    /// Converts the merged JSON into a [TsConfig], which records the files it extends.
+    pub(crate) fn into_config(self) -> Result<TsConfig> {
+        let mut config = TsConfig::from_ordered(self.json)?;
+        config.set_extends_paths(self.bases);
+        Ok(config)
+    }
+}
+
+/// The part of loading an `extends` chain that doesn't do any IO, shared by the sync and async loaders.
+///
+/// The caller passes the requested config to [Chain::push], then resolves each specifier that
+/// [Chain::next_specifier] returns and pushes the config it names, until there are none left.
+/// Configs are followed depth first: each is merged as soon as everything it extends has been, so
+/// that a list of specifiers is applied in order, each overriding those before it, as tsc does.
+/// [Chain::finish] returns the result.
+struct Chain {
+    rebase_paths: bool,
+    max_depth: Option<usize>,
+    /// The config that was requested, followed by the base of each that is still being loaded.
+    stack: Vec<Link>,
+    files: Vec<PathBuf>,
+    /// The canonical paths of the bases that the requested config extends directly.
+    bases: Vec<PathBuf>,
+    merged: Option<Ordered>,
+}
+
+/// A config in a [Chain], with the bases it extends that have been loaded so far.
+struct Link {
+    path: PathBuf,
+    canonical: PathBuf,
+    json: Ordered,
+    /// The specifiers in its `extends` that are yet to be loaded.
+    pending: VecDeque<String>,
+    /// Its bases loaded so far, merged in order and rebased onto its directory.
+    bases: Option<Ordered>,
+}
+
+impl Chain {
+    fn new(rebase_paths: bool, max_depth: Option<usize>) -> Chain {
+        Chain {
+            rebase_paths,
+            max_depth,
+            stack: Vec::new(),
+            files: Vec::new(),
+            bases: Vec::new(),
+            merged: None,
+        }
+    }
+
+    /// The canonical paths of the configs being loaded, from the one that was requested to the
+    /// last one pushed.
+    fn canonical(&self) -> Vec<PathBuf> {
+        self.stack
+            .iter()
+            .map(|link| link.canonical.clone())
+            .collect()
+    }
+
+    /// Adds the config read from `path`, which the last config pushed extends.
+    fn push(&mut self, path: &Path, file: ConfigFile) -> Result<()> {
+        if self
+            .stack
+            .iter()
+            .any(|link| link.canonical == file.canonical)
+        {
+            let mut chain = self.canonical();
+            chain.push(file.canonical);
+            return Err(ConfigError::ExtendsCycle { chain });
+        }
+        if let Some(max_depth) = self.max_depth.filter(|max| self.stack.len() > *max) {
+            let mut chain = self.canonical();
+            chain.push(file.canonical);
+            return Err(ConfigError::ExtendsTooDeep { chain, max_depth });
+        }
+
+        let pending = match &file.json.value["extends"] {
+            Value::String(specifier) => VecDeque::from([specifier.clone()]),
+            Value::Array(specifiers) => specifiers
+                .iter()
+                .filter_map(|specifier| specifier.as_str().map(str::to_string))
+                .collect(),
+            _ => VecDeque::new(),
+        };
+        if !self.files.contains(&file.canonical) {
+            self.files.push(file.canonical.clone());
+        }
+        self.stack.push(Link {
+            path: path.to_path_buf(),
+            canonical: file.canonical,
+            json: file.json,
+            pending,
+            bases: None,
+        });
+        Ok(())
+    }
+
+    /// Returns the next specifier to resolve, with the path of the config that wrote it, merging
+    /// each config whose bases have all been loaded.
+    fn next_specifier(&mut self) -> Option<(PathBuf, String)> {
+        loop {
+            let link = self.stack.last_mut()?;
+            if let Some(specifier) = link.pending.pop_front() {
+                return Some((link.path.clone(), specifier));
+            }
+
+            let mut link = self.stack.pop()?;
+            if let Some(bases) = link.bases {
+                inherit(&mut link.json, bases);
+            }
+            if self.stack.len() == 1 {
+                self.bases.push(link.canonical);
+            }
+            let extending = match self.stack.last_mut() {
+                Some(extending) => extending,
+                None => {
+                    self.merged = Some(link.json);
+                    return None;
+                }
+            };
+            if self.rebase_paths {
+                let from_dir = extending.path.parent().unwrap_or_else(|| Path::new(""));
+                let to_dir = link.path.parent().unwrap_or_else(|| Path::new(""));
+                let has_base_url = !extending.json.value["compilerOptions"]["baseUrl"].is_null();
+                rebase_paths(
+                    &mut link.json.value,
+                    &relative_dir(from_dir, to_dir),
+                    has_base_url,
+                );
+            }
+            if let Some(earlier) = extending.bases.take() {
+                inherit(&mut link.json, earlier);
+            }
+            extending.bases = Some(link.json);
+        }
+    }
+
+    /// Adds the configs being loaded to an error from resolving the last one's `extends`.
+    fn unresolved(&mut self, mut err: ConfigError) -> ConfigError {
+        if let ConfigError::UnresolvedExtends { chain, .. } = &mut err {
+            *chain = self.canonical();
+        }
+        err
+    }
+
+    /// Returns the merged config, once [Chain::next_specifier] has returned `None`.
+    fn finish(self) -> Loaded {
+        Loaded {
+            json: self.merged.unwrap_or_default(),
+            files: self.files,
+            bases: self.bases,
+        }
+    }
+}
+
+/// Merges `base` into `config`, which extends it, keeping the keys of `config` in front.
+fn inherit(config: &mut Ordered, base: Ordered) {
+    merge_extended(&mut config.value, base.value);
+    config.order.inherit(&base.order);
+}
+
+/// Compiler options whose values are paths, or lists of paths, relative to the config they're set in.
+pub(crate) const PATH_OPTIONS: &[&str] = &[
+    "baseUrl",
+    "declarationDir",
+    "generateCpuProfile",
+    "out",
+    "outDir",
+    "outFile",
+    "rootDir",
+    "rootDirs",
+    "tsBuildInfoFile",
+    "typeRoots",
+];
+
+/// Prefixes the relative paths in an extended config with `prefix`, the directory of that config
+/// relative to the extending one.
+///
+/// Entries in `paths` are relative to `baseUrl` if there is one, so are only rebased when neither
+/// config sets it.
+fn rebase_paths(value: &mut Value, prefix: &Path, extending_has_base_url: bool) {
+    if prefix.as_os_str().is_empty() {
+        return;
+    }
+    for key in ["files", "include", "exclude"] {
+        if let Some(paths) = value.get_mut(key) {
+            rebase_value(paths, prefix);
+        }
+    }
+    if let Some(Value::Object(watch_options)) = value.get_mut("watchOptions") {
+        for (key, paths) in watch_options.iter_mut() {
+            if key == "excludeDirectories" || key == "excludeFiles" {
+                rebase_value(paths, prefix);
+            }
+        }
+    }
+    if let Some(Value::Object(options)) = value.get_mut("compilerOptions") {
+        let has_base_url = extending_has_base_url || options.contains_key("baseUrl");
+        for (key, option) in options.iter_mut() {
+            if PATH_OPTIONS.contains(&key.as_str()) {
+                rebase_value(option, prefix);
+            } else if key == "paths" && !has_base_url {
+                if let Value::Object(paths) = option {
+                    paths.values_mut().for_each(|p| rebase_value(p, prefix));
+                }
+            }
+        }
+    }
+}
+
+/// Makes the relative paths in `value`, the JSON of a config in the absolute directory `dir`,
+/// absolute, and replaces the `${configDir}` that starts any path with `dir`.
+pub(crate) fn absolutize_paths(value: &mut Value, dir: &Path) {
+    for key in [
+        "files",
+        "include",
+        "exclude",
+        "compilerOptions",
+        "watchOptions",
+    ] {
+        if let Some(field) = value.get_mut(key) {
+            substitute_config_dir(field, dir);
+        }
+    }
+    rebase_paths(value, dir, false);
+}
+
+/// Replaces `${configDir}` at the start of any string in `value` with `dir`.
+fn substitute_config_dir(value: &mut Value, dir: &Path) {
+    match value {
+        Value::String(path) if path.starts_with("${configDir}") => {
+            let rest = path["${configDir}".len()..].trim_start_matches(['/', '\\']);
+            *path = to_slashes(&normalize(&dir.join(rest)));
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| substitute_config_dir(value, dir)),
+        Value::Object(values) => values
+            .values_mut()
+            .for_each(|value| substitute_config_dir(value, dir)),
+        _ => {}
+    }
+}
+
+/// Rebases a path, or each path in an array; other values are left alone.
+fn rebase_value(value: &mut Value, prefix: &Path) {
+    match value {
+        Value::String(path)
+            if !path.starts_with("${configDir}") && !Path::new(path.as_str()).is_absolute() =>
+        {
+            *path = to_slashes(&normalize(&prefix.join(path.as_str())));
+        }
+        Value::Array(paths) => paths.iter_mut().for_each(|p| rebase_value(p, prefix)),
+        _ => {}
+    }
+}
+
+/// Returns the path of directory `to`, relative to directory `from`.
+pub(crate) fn relative_dir(from: &Path, to: &Path) -> PathBuf {
+    let absolute = |path: &Path| match std::env::current_dir() {
+        Ok(cwd) if path.is_relative() => normalize(&cwd.join(path)),
+        _ => normalize(path),
+    };
+    let (from, to) = (absolute(from), absolute(to));
+    if from.has_root() != to.has_root() {
+        return to;
+    }
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 && from.has_root() {
+        // Different drives on Windows.
+        return to;
+    }
+    let mut relative = PathBuf::new();
+    from.components()
+        .skip(common)
+        .for_each(|_| relative.push(".."));
+    to.components().skip(common).for_each(|c| relative.push(c));
+    relative
+}
+
+fn to_slashes(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if path.is_empty() {
+        ".".to_string()
+    } else {
+        path.replace('\\', "/")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn fixture(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("test")
+            .join(path)
+    }
+
+    #[test]
+    fn resolves_relative_specifiers() {
+        let from = fixture("tsconfig.noextension.json");
+        let resolved = NodeResolver::new()
+            .resolve(&from, "./b/tsconfig.base", &OsFileSystem)
+            .unwrap();
+        assert_eq!(resolved, fixture("b/tsconfig.base.json"));
+
+        let err = NodeResolver::new()
+            .resolve(&from, "./b/missing", &OsFileSystem)
+            .unwrap_err();
+        match err {
+            ConfigError::UnresolvedExtends { searched, .. } => assert_eq!(
+                searched,
+                vec![fixture("b/missing"), fixture("b/missing.json")]
+            ),
+            other => panic!("expected an unresolved extends, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn resolves_packages_from_ancestor_node_modules() {
+        let from = fixture("a/tsconfig.package.json");
+        let package = fixture("node_modules/@acme/tsconfig");
+
+        let resolved = NodeResolver::new()
+            .resolve(&from, "@acme/tsconfig", &OsFileSystem)
+            .unwrap();
+        assert_eq!(resolved, package.join("./strict.json"));
+
+        let resolved = NodeResolver::new()
+            .resolve(&from, "@acme/tsconfig/tsconfig", &OsFileSystem)
+            .unwrap();
+        assert_eq!(resolved, package.join("tsconfig.json"));
+
+        let err = NodeResolver::new()
+            .resolve(&from, "@acme/missing", &OsFileSystem)
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::UnresolvedExtends { .. }));
+    }
+
+    #[test]
+    fn loads_package_configs() {
+        let config = ConfigLoader::new()
+            .load(&fixture("a/tsconfig.package.json"))
+            .unwrap();
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.strict, Some(true));
+        assert_eq!(opts.no_emit, Some(false));
+
+        let config = ConfigLoader::new()
+            .load(&fixture("a/tsconfig.package_file.json"))
+            .unwrap();
+        assert_eq!(config.compiler_options.unwrap().declaration, Some(true));
+    }
+
+    #[test]
+    fn detects_extends_cycles() {
+        let err = ConfigLoader::new()
+            .load(&fixture("cycle/tsconfig.a.json"))
+            .unwrap_err();
+        match err {
+            ConfigError::ExtendsCycle { chain } => {
+                let names: Vec<_> = chain.iter().map(|p| p.file_name().unwrap()).collect();
+                assert_eq!(
+                    names,
+                    ["tsconfig.a.json", "tsconfig.b.json", "tsconfig.a.json"]
+                );
+            }
+            other => panic!("expected a cycle, got {:?}", other),
+        }
+
+        let err = ConfigLoader::new()
+            .load(&fixture("cycle/tsconfig.self.json"))
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::ExtendsCycle { chain } if chain.len() == 2));
+    }
+
+    #[test]
+    fn limits_extends_depth() {
+        let path = fixture("a/tsconfig.inherits_again.json");
+        let loader = ConfigLoader::new().with_max_depth(1);
+        match loader.load(&path).unwrap_err() {
+            ConfigError::ExtendsTooDeep { chain, max_depth } => {
+                assert_eq!(max_depth, 1);
+                assert_eq!(chain.len(), 3);
+            }
+            other => panic!("expected a too deep chain, got {:?}", other),
+        }
+
+        assert!(ConfigLoader::new().with_max_depth(2).load(&path).is_ok());
+    }
+
+    #[test]
+    fn reports_unresolved_extends() {
+        let mut fs = crate::MemoryFileSystem::new();
+        fs.insert(
+            "/repo/tsconfig.base.json",
+            r#"{"extends": "@acme/missing"}"#,
+        );
+        fs.insert(
+            "/repo/app/tsconfig.json",
+            r#"{"extends": "../tsconfig.base.json"}"#,
+        );
+
+        let err = ConfigLoader::new()
+            .with_file_system(fs)
+            .load(&"/repo/app/tsconfig.json")
+            .unwrap_err();
+        match &err {
+            ConfigError::UnresolvedExtends {
+                specifier,
+                searched,
+                chain,
+                ..
+            } => {
+                assert_eq!(specifier, "@acme/missing");
+                assert!(searched.contains(&PathBuf::from("/repo/node_modules/@acme/missing.json")));
+                assert!(
+                    searched.contains(&PathBuf::from("/node_modules/@acme/missing/tsconfig.json"))
+                );
+                assert_eq!(
+                    chain,
+                    &[
+                        PathBuf::from("/repo/app/tsconfig.json"),
+                        PathBuf::from("/repo/tsconfig.base.json")
+                    ]
+                );
+            }
+            other => panic!("expected an unresolved extends, got {:?}", other),
+        }
+        assert!(err.to_string().contains(
+            "extended by /repo/tsconfig.base.json; searched /repo/node_modules/@acme/missing, "
+        ));
+    }
+
+    #[test]
+    fn rebases_inherited_paths() {
+        let config = ConfigLoader::new()
+            .load(&fixture("a/tsconfig.rebase.json"))
+            .unwrap();
+        assert_eq!(config.include, Some(vec!["../b/src/**/*".to_string()]));
+        assert_eq!(config.exclude, Some(vec![]));
+
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.out_dir.as_deref(), Some("../b/dist"));
+        assert_eq!(
+            opts.root_dirs,
+            Some(vec!["../b/src".to_string(), "../b/generated".to_string()])
+        );
+        assert_eq!(
+            opts.type_roots,
+            Some(vec![
+                "/usr/lib/types".to_string(),
+                "${configDir}/types".to_string()
+            ])
+        );
+        assert_eq!(opts.paths.unwrap()["@/*"], vec!["../b/src/*".to_string()]);
+    }
+
+    #[test]
+    fn rebases_inherited_base_url() {
+        let mut fs = crate::MemoryFileSystem::new();
+        fs.insert(
+            "/repo/configs/base.json",
+            r#"{"compilerOptions": {"baseUrl": "../src", "paths": {"@/*": ["app/*"]}}}"#,
+        );
+        fs.insert(
+            "/repo/tsconfig.json",
+            r#"{"extends": "./configs/base.json"}"#,
+        );
+
+        let config = ConfigLoader::new()
+            .with_file_system(fs)
+            .load(&"/repo/tsconfig.json")
+            .unwrap();
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.base_url.as_deref(), Some("src"));
+        assert_eq!(opts.paths.unwrap()["@/*"], vec!["app/*".to_string()]);
+    }
+
+    #[test]
+    fn keeps_raw_paths_on_request() {
+        let config = ConfigLoader::new()
+            .with_raw_paths(true)
+            .load(&fixture("a/tsconfig.rebase.json"))
+            .unwrap();
+        assert_eq!(config.include, Some(vec!["src/**/*".to_string()]));
+        assert_eq!(
+            config.compiler_options.unwrap().out_dir.as_deref(),
+            Some("dist")
+        );
+    }
+
+    #[test]
+    fn computes_relative_dirs() {
+        let dir = |from: &str, to: &str| to_slashes(&relative_dir(Path::new(from), Path::new(to)));
+        assert_eq!(dir("/repo/app", "/repo/app"), ".");
+        assert_eq!(dir("/repo/app", "/repo/app/configs"), "configs");
+        assert_eq!(dir("/repo/app", "/repo/base"), "../base");
+        assert_eq!(dir("/repo/app", "/repo/app/../base/./x"), "../base/x");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolves_symlinked_packages() {
+        let root = std::env::temp_dir().join(format!("tsconfig-symlinks-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        // The temporary directory may itself be behind a symlink, as on macOS.
+        let root = root.canonicalize().unwrap();
+        let store = root.join("node_modules/.pnpm/base@1.0.0/node_modules/base");
+        std::fs::create_dir_all(&store).unwrap();
+        std::fs::write(
+            store.join("tsconfig.json"),
+            r#"{"compilerOptions": {"outDir": "dist"}}"#,
+        )
+        .unwrap();
+        std::os::unix::fs::symlink(&store, root.join("node_modules/base")).unwrap();
+        std::fs::write(root.join("tsconfig.json"), r#"{"extends": "base"}"#).unwrap();
+
+        let out_dir = |resolver: NodeResolver| {
+            let config = ConfigLoader::new()
+                .with_resolver(resolver)
+                .load(&root.join("tsconfig.json"))
+                .unwrap();
+            config.compiler_options.unwrap().out_dir.unwrap()
+        };
+        let real = out_dir(NodeResolver::new());
+        let preserved = out_dir(NodeResolver::new().with_preserve_symlinks(true));
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(real, "node_modules/.pnpm/base@1.0.0/node_modules/base/dist");
+        assert_eq!(preserved, "node_modules/base/dist");
+    }
+
+    #[test]
+    fn does_not_inherit_references() {
+        let config = ConfigLoader::new()
+            .load(&fixture("a/tsconfig.references.json"))
+            .unwrap();
+        assert!(config.references.is_none());
+        assert_eq!(config.compiler_options.unwrap().composite, Some(true));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn loads_asynchronously() {
+        let loader = ConfigLoader::new();
+        let path = fixture("a/tsconfig.rebase.json");
+        let config = loader.load_async(&path).await.unwrap();
+        assert_eq!(config.raw(), loader.load(&path).unwrap().raw());
+
+        let err = loader
+            .load_async(&fixture("cycle/tsconfig.a.json"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::ExtendsCycle { .. }));
+    }
+
+    #[test]
+    fn loads_from_memory() {
+        let mut fs = crate::MemoryFileSystem::new();
+        fs.insert(
+            "/repo/node_modules/@acme/tsconfig/package.json",
+            r#"{"tsconfig": "base.json"}"#,
+        );
+        fs.insert(
+            "/repo/node_modules/@acme/tsconfig/base.json",
+            r#"{"include": ["src"], "compilerOptions": {"strict": true}}"#,
+        );
+        fs.insert(
+            "/repo/app/tsconfig.json",
+            r#"{"extends": "@acme/tsconfig"}"#,
+        );
+
+        let config = ConfigLoader::new()
+            .with_file_system(fs)
+            .load(&"/repo/app/tsconfig.json")
+            .unwrap();
+        assert_eq!(
+            config.include,
+            Some(vec!["../node_modules/@acme/tsconfig/src".to_string()])
+        );
+        assert_eq!(config.compiler_options.unwrap().strict, Some(true));
+    }
+
+    #[test]
+    fn records_where_extends_resolved() {
+        let mut fs = crate::MemoryFileSystem::new();
+        fs.insert("/repo/a.json", r#"{"compilerOptions": {"strict": true}}"#);
+        fs.insert("/repo/b.json", r#"{"compilerOptions": {"strict": false}}"#);
+        fs.insert("/repo/single.json", r#"{"extends": ["./a.json"]}"#);
+        fs.insert(
+            "/repo/multiple.json",
+            r#"{"extends": ["./a.json", "./b.json"]}"#,
+        );
+        fs.insert("/repo/none.json", r#"{}"#);
+        let loader = ConfigLoader::new().with_file_system(fs);
+
+        let config = loader.load(&"/repo/single.json").unwrap();
+        assert_eq!(
+            config.extends,
+            Some(crate::Extends::Multiple(vec!["./a.json".into()]))
+        );
+        assert_eq!(
+            config.extends_paths().unwrap(),
+            [PathBuf::from("/repo/a.json")]
+        );
+        assert_eq!(config.compiler_options.unwrap().strict, Some(true));
+
+        let config = loader.load(&"/repo/multiple.json").unwrap();
+        assert_eq!(
+            config.extends_paths().unwrap(),
+            [PathBuf::from("/repo/a.json"), PathBuf::from("/repo/b.json")]
+        );
+        assert_eq!(config.compiler_options.unwrap().strict, Some(false));
+
+        let mut config = loader.load(&"/repo/none.json").unwrap();
+        assert_eq!(config.extends_paths(), Some(&[][..]));
+        config.set("extends", "./a.json").unwrap();
+        assert!(!config.is_extends_resolved());
+    }
+
+    #[test]
+    fn merges_multiple_extends_in_order() {
+        let mut fs = crate::MemoryFileSystem::new();
+        fs.insert(
+            "/repo/configs/common.json",
+            r#"{"compilerOptions": {"strict": true, "outDir": "dist"}}"#,
+        );
+        fs.insert(
+            "/repo/configs/node.json",
+            r#"{"extends": "./common.json", "compilerOptions": {"module": "nodenext", "strict": false}}"#,
+        );
+        fs.insert(
+            "/repo/configs/strict.json",
+            r#"{"extends": "./common.json", "compilerOptions": {"noImplicitAny": true}, "include": ["src"]}"#,
+        );
+        fs.insert(
+            "/repo/app/tsconfig.json",
+            r#"{"extends": ["../configs/node.json", "../configs/strict.json"], "compilerOptions": {"target": "es2022"}}"#,
+        );
+        let loader = ConfigLoader::new().with_file_system(fs);
+
+        let (value, files) = loader
+            .load_value_and_files(&"/repo/app/tsconfig.json")
+            .unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "extends": ["../configs/node.json", "../configs/strict.json"],
+                "compilerOptions": {
+                    "target": "es2022",
+                    "strict": true,
+                    "outDir": "../configs/dist",
+                    "noImplicitAny": true,
+                    "module": "nodenext",
+                },
+                "include": ["../configs/src"],
+            })
+        );
+        assert_eq!(
+            files,
+            [
+                "/repo/app/tsconfig.json",
+                "/repo/configs/node.json",
+                "/repo/configs/common.json",
+                "/repo/configs/strict.json",
+            ]
+            .map(PathBuf::from)
+        );
+    }
+
+    #[cfg(feature = "bundled-presets")]
+    #[test]
+    fn falls_back_to_bundled_presets() {
+        let mut fs = crate::MemoryFileSystem::new();
+        fs.insert(
+            "/repo/tsconfig.json",
+            r#"{"extends": "@tsconfig/strictest/tsconfig.json", "compilerOptions": {"checkJs": false}}"#,
+        );
+        let config = ConfigLoader::new()
+            .with_file_system(fs.clone())
+            .load(&"/repo/tsconfig.json")
+            .unwrap();
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.no_unused_locals, Some(true));
+        assert_eq!(opts.check_js, Some(false));
+
+        // An installed copy takes precedence.
+        fs.insert(
+            "/repo/node_modules/@tsconfig/strictest/tsconfig.json",
+            r#"{"compilerOptions": {"noUnusedLocals": false}}"#,
+        );
+        let config = ConfigLoader::new()
+            .with_file_system(fs)
+            .load(&"/repo/tsconfig.json")
+            .unwrap();
+        assert_eq!(
+            config.compiler_options.unwrap().no_unused_locals,
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn uses_custom_resolvers() {
+        let loader = ConfigLoader::new().with_resolver(|_: &Path, _: &str, _: &dyn FileSystem| {
+            Ok(fixture("b/tsconfig.base.json"))
+        });
+        let config = loader.load(&fixture("a/tsconfig.package.json")).unwrap();
+        assert_eq!(
+            config.compiler_options.unwrap().jsx,
+            Some(crate::Jsx::Preserve)
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! The macros that define the enums for options that take one of a fixed set of strings, and the
+//! accessor methods for options.
+
+/// Defines an enum for an option whose value is one of a fixed set of strings, from a table of its
+/// variants and the strings that name them.
+///
+/// Each variant lists its canonical name, in the casing tsc documents, followed by any aliases.
+/// Names are matched case-insensitively, as tsc does, and aliases parse to the same variant as the
+/// canonical name. `as_str`, `Display` and `Serialize` give the canonical name. Ending the table
+/// with `..Other` adds an `Other(String)` variant that keeps unknown values as written; without
+/// it, unknown values fail to parse with a [ParseOptionError](crate::ParseOptionError). The enum is
+/// `#[non_exhaustive]`, so that values TypeScript adds can become variants.
+///
+/// ```text
+/// option_enum! {
+///     #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+///     pub enum Target {
+///         Es2015 = "ES2015" | "ES6",
+///         EsNext = "ESNext",
+///         ..Other
+///     }
+/// }
+/// ```
+macro_rules! option_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $canonical:literal $(| $alias:literal)*,
+            )*
+            ..$other:ident
+        }
+    ) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value that this crate doesn't know, as it was written.
+            $other(String),
+        }
+
+        option_enum!(@common $name { $($variant = $canonical $(| $alias)*,)* });
+
+        impl $name {
+            /// A string, which may be one of the known values or any other.
+            #[cfg(feature = "schema")]
+            fn schema() -> schemars::Schema {
+                schemars::json_schema!({
+                    "type": "string",
+                    "anyOf": [{ "enum": $name::NAMES }, {}],
+                })
+            }
+
+            /// Returns the canonical name of the value, in the casing tsc documents, or the value
+            /// as it was written if this crate doesn't know it.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $canonical,)*
+                    $name::$other(value) => value,
+                }
+            }
+
+            /// Returns the value if this crate doesn't know it.
+            fn unknown(&self) -> Option<&str> {
+                match self {
+                    $name::$other(value) => Some(value),
+                    _ => None,
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($name::from_name(s).unwrap_or_else(|| $name::$other(s.to_string())))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $canonical:literal $(| $alias:literal)*,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        option_enum!(@common $name { $($variant = $canonical $(| $alias)*,)* });
+
+        impl $name {
+            /// One of the known values.
+            #[cfg(feature = "schema")]
+            fn schema() -> schemars::Schema {
+                schemars::json_schema!({
+                    "type": "string",
+                    "enum": $name::NAMES,
+                })
+            }
+
+            /// Returns the canonical name of the value, in the casing tsc documents.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $canonical,)*
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseOptionError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::from_name(s).ok_or_else(|| ParseOptionError::new(s, $name::NAMES))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+    (@common $name:ident { $($variant:ident = $canonical:literal $(| $alias:literal)*,)* }) => {
+        impl $name {
+            /// The values tsc accepts, in the casing it documents.
+            pub const NAMES: &'static [&'static str] = &[$($canonical),*];
+
+            fn from_name(s: &str) -> Option<$name> {
+                $(
+                    if [$canonical $(, $alias)*]
+                        .iter()
+                        .any(|name| name.eq_ignore_ascii_case(s))
+                    {
+                        return Some($name::$variant);
+                    }
+                )*
+                None
+            }
+
+            /// Each variant with its canonical name followed by its aliases, for tests.
+            #[cfg(test)]
+            fn table() -> Vec<($name, &'static [&'static str])> {
+                vec![$(($name::$variant, &[$canonical $(, $alias)*])),*]
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+
+        #[cfg(feature = "schema")]
+        impl schemars::JsonSchema for $name {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                stringify!($name).into()
+            }
+
+            fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                $name::schema()
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+    };
+}
+
+/// Defines a getter and a setter for each of a struct's optional fields, from a table of the fields
+/// and their types.
+///
+/// `copy` fields are returned by value, `deref` fields as the type they dereference to, after
+/// `=>`, and `ref` fields by reference. Setters take the value or `None`, which unsets the field.
+/// Naming a method after `then` calls it after every setter.
+///
+/// ```text
+/// accessors! {
+///     impl TsConfig then sync_raw {
+///         copy compile_on_save, set_compile_on_save: bool;
+///         deref files, set_files: Vec<String> => [String];
+///         ref references, set_references: References;
+///     }
+/// }
+/// ```
+macro_rules! accessors {
+    (
+        impl $name:ident $(then $sync:ident)? {
+            $(
+                $(#[$meta:meta])*
+                $kind:ident $field:ident, $setter:ident: $ty:ty $(=> $target:ty)?;
+            )*
+        }
+    ) => {
+        accessors! {
+            @impl $name ($($sync)?) {
+                $($(#[$meta])* $kind $field, $setter: $ty $(=> $target)?;)*
+            }
+        }
+    };
+    (
+        @impl $name:ident $sync:tt {
+            $(
+                $(#[$meta:meta])*
+                $kind:ident $field:ident, $setter:ident: $ty:ty $(=> $target:ty)?;
+            )*
+        }
+    ) => {
+        impl $name {
+            $(
+                accessors!(@get $(#[$meta])* $kind $field: $ty $(=> $target)?);
+
+                #[doc = concat!("Sets the `", stringify!($field), "` field, or unsets it with `None`.")]
+                $(#[$meta])*
+                #[allow(deprecated)]
+                pub fn $setter(&mut self, value: impl Into<Option<$ty>>) {
+                    self.$field = value.into();
+                    accessors!(@sync self $sync);
+                }
+            )*
+        }
+    };
+    (@sync $self:ident ()) => {};
+    (@sync $self:ident ($sync:ident)) => {
+        $self.$sync();
+    };
+    (@get $(#[$meta:meta])* copy $field:ident: $ty:ty) => {
+        #[doc = concat!("Returns the `", stringify!($field), "` field.")]
+        $(#[$meta])*
+        #[allow(deprecated)]
+        pub fn $field(&self) -> Option<$ty> {
+            self.$field
+        }
+    };
+    (@get $(#[$meta:meta])* deref $field:ident: $ty:ty => $target:ty) => {
+        #[doc = concat!("Returns the `", stringify!($field), "` field.")]
+        $(#[$meta])*
+        #[allow(deprecated)]
+        pub fn $field(&self) -> Option<&$target> {
+            self.$field.as_deref()
+        }
+    };
+    (@get $(#[$meta:meta])* ref $field:ident: $ty:ty) => {
+        #[doc = concat!("Returns the `", stringify!($field), "` field.")]
+        $(#[$meta])*
+        #[allow(deprecated)]
+        pub fn $field(&self) -> Option<&$ty> {
+            self.$field.as_ref()
+        }
+    };
+}
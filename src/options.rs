@@ -0,0 +1,1092 @@
+//! Metadata describing the compiler options that tsc accepts.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::descriptions;
+use crate::{
+    ConfigError, FallbackPolling, ImportsNotUsedAsValues, Jsx, Lib, Module, ModuleDetectionMode,
+    ModuleResolutionMode, Result, Target, WatchDirectory, WatchFile,
+};
+
+/// A TypeScript release, such as 5.0, identified by its major and minor version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TsVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl TsVersion {
+    pub const fn new(major: u32, minor: u32) -> TsVersion {
+        TsVersion { major, minor }
+    }
+}
+
+impl FromStr for TsVersion {
+    type Err = ConfigError;
+
+    /// Parses a version such as `5.0`, `5.4.5` or `5.5-beta`, keeping the major and minor parts.
+    fn from_str(s: &str) -> Result<TsVersion> {
+        let mut parts = s.split(['.', '-']);
+        let major = parts.next().and_then(|part| part.parse().ok());
+        let minor = parts.next().and_then(|part| part.parse().ok());
+        match (major, minor) {
+            (Some(major), Some(minor)) => Ok(TsVersion::new(major, minor)),
+            _ => Err(ConfigError::InvalidVersion(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for TsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The kind of value a compiler option takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionKind {
+    Boolean,
+    Number,
+    String,
+    /// A path, which is resolved relative to the config that sets it.
+    Path,
+    /// One of a fixed set of strings, given in the casing tsc documents. Matching is case-insensitive.
+    Enum(&'static [&'static str]),
+    List(&'static OptionKind),
+    /// An object mapping strings to lists of paths, as `paths` does.
+    Map,
+    /// An object whose keys depend on the tool reading it, as each of the `plugins` does.
+    Object,
+}
+
+impl OptionKind {
+    /// Whether `value` has the JSON type that options of this kind take. Enum values are only
+    /// checked to be strings, since the option's own type knows the aliases tsc accepts.
+    pub(crate) fn accepts(&self, value: &Value) -> bool {
+        match (self, value) {
+            (OptionKind::Boolean, Value::Bool(_)) | (OptionKind::Number, Value::Number(_)) => true,
+            (OptionKind::String | OptionKind::Path | OptionKind::Enum(_), Value::String(_)) => true,
+            (OptionKind::List(kind), Value::Array(items)) => {
+                items.iter().all(|item| kind.accepts(item))
+            }
+            (OptionKind::Map | OptionKind::Object, Value::Object(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Describes the values that options of this kind take, such as `a list of strings`.
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            OptionKind::Boolean => "a boolean".to_string(),
+            OptionKind::Number => "a number".to_string(),
+            OptionKind::String | OptionKind::Path => "a string".to_string(),
+            OptionKind::Enum(names) => format!("one of {}", names.join(", ")),
+            OptionKind::List(kind) => format!("a list of {}", kind.describe()),
+            OptionKind::Map | OptionKind::Object => "an object".to_string(),
+        }
+    }
+}
+
+/// The section of the [TSConfig reference](https://www.typescriptlang.org/tsconfig) that documents an
+/// option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionCategory {
+    TypeChecking,
+    Modules,
+    Emit,
+    JavaScriptSupport,
+    EditorSupport,
+    InteropConstraints,
+    BackwardsCompatibility,
+    LanguageAndEnvironment,
+    CompilerDiagnostics,
+    Projects,
+    OutputFormatting,
+    Completeness,
+    CommandLine,
+    WatchOptions,
+}
+
+/// When an option was deprecated, and what replaced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deprecation {
+    /// The release that deprecated the option.
+    pub since: TsVersion,
+    /// The release that stopped accepting the option, if it has been removed.
+    pub removed: Option<TsVersion>,
+    /// The option to use instead, if there is one.
+    pub replacement: Option<&'static str>,
+}
+
+/// What tsc accepts for one compiler option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptionInfo {
+    /// The name of the option in a tsconfig, such as `noImplicitAny`.
+    pub name: &'static str,
+    pub kind: OptionKind,
+    pub category: OptionCategory,
+    /// The release that added the option.
+    pub since: TsVersion,
+    /// The value tsc uses when the option isn't set, as the TSConfig reference describes it, such as
+    /// `false` or `true if strict is set; false otherwise`.
+    pub default: Option<&'static str>,
+    pub deprecation: Option<Deprecation>,
+}
+
+impl OptionInfo {
+    const fn new(
+        name: &'static str,
+        kind: OptionKind,
+        category: OptionCategory,
+        since: TsVersion,
+    ) -> OptionInfo {
+        OptionInfo {
+            name,
+            kind,
+            category,
+            since,
+            default: None,
+            deprecation: None,
+        }
+    }
+
+    /// Returns what the option does, in the words `tsc --help` uses.
+    pub fn description(&self) -> &'static str {
+        descriptions::description(self.name).unwrap_or_default()
+    }
+
+    const fn with_default(self, default: &'static str) -> OptionInfo {
+        OptionInfo {
+            default: Some(default),
+            ..self
+        }
+    }
+
+    const fn with_deprecation(
+        self,
+        since: TsVersion,
+        removed: Option<TsVersion>,
+        replacement: Option<&'static str>,
+    ) -> OptionInfo {
+        OptionInfo {
+            deprecation: Some(Deprecation {
+                since,
+                removed,
+                replacement,
+            }),
+            ..self
+        }
+    }
+
+    /// Whether tsc still accepts the option in `version`: it had been added, and not yet removed.
+    pub fn is_available_in(&self, version: TsVersion) -> bool {
+        self.since <= version
+            && self
+                .deprecation
+                .and_then(|deprecation| deprecation.removed)
+                .is_none_or(|removed| version < removed)
+    }
+}
+
+/// Every compiler option this crate knows, sorted by name.
+const COMPILER_OPTIONS: &[OptionInfo] = &[
+    OptionInfo::new(
+        "allowArbitraryExtensions",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(5, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "allowImportingTsExtensions",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(5, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "allowJs",
+        OptionKind::Boolean,
+        OptionCategory::JavaScriptSupport,
+        TsVersion::new(1, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "allowSyntheticDefaultImports",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(1, 8),
+    )
+    .with_default("true if esModuleInterop is set; false otherwise"),
+    OptionInfo::new(
+        "allowUmdGlobalAccess",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(3, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "allowUnreachableCode",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(1, 8),
+    ),
+    OptionInfo::new(
+        "allowUnusedLabels",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(1, 8),
+    ),
+    OptionInfo::new(
+        "alwaysStrict",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 1),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "assumeChangesOnlyAffectDirectDependencies",
+        OptionKind::Boolean,
+        OptionCategory::WatchOptions,
+        TsVersion::new(3, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "baseUrl",
+        OptionKind::Path,
+        OptionCategory::Modules,
+        TsVersion::new(2, 0),
+    ),
+    OptionInfo::new(
+        "charset",
+        OptionKind::String,
+        OptionCategory::BackwardsCompatibility,
+        TsVersion::new(1, 0),
+    )
+    .with_default("utf8")
+    .with_deprecation(TsVersion::new(5, 0), Some(TsVersion::new(5, 5)), None),
+    OptionInfo::new(
+        "checkJs",
+        OptionKind::Boolean,
+        OptionCategory::JavaScriptSupport,
+        TsVersion::new(2, 3),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "composite",
+        OptionKind::Boolean,
+        OptionCategory::Projects,
+        TsVersion::new(3, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "customConditions",
+        OptionKind::List(&OptionKind::String),
+        OptionCategory::Modules,
+        TsVersion::new(5, 0),
+    ),
+    OptionInfo::new(
+        "declaration",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 0),
+    )
+    .with_default("true if composite is set; false otherwise"),
+    OptionInfo::new(
+        "declarationDir",
+        OptionKind::Path,
+        OptionCategory::Emit,
+        TsVersion::new(2, 0),
+    ),
+    OptionInfo::new(
+        "declarationMap",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(2, 9),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "diagnostics",
+        OptionKind::Boolean,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(1, 0),
+    )
+    .with_default("false")
+    .with_deprecation(TsVersion::new(2, 0), None, Some("extendedDiagnostics")),
+    OptionInfo::new(
+        "disableReferencedProjectLoad",
+        OptionKind::Boolean,
+        OptionCategory::Projects,
+        TsVersion::new(4, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "disableSizeLimit",
+        OptionKind::Boolean,
+        OptionCategory::EditorSupport,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "disableSolutionSearching",
+        OptionKind::Boolean,
+        OptionCategory::Projects,
+        TsVersion::new(3, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "disableSourceOfProjectReferenceRedirect",
+        OptionKind::Boolean,
+        OptionCategory::Projects,
+        TsVersion::new(3, 7),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "downlevelIteration",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(2, 3),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "emitBOM",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "emitDeclarationOnly",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(2, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "emitDecoratorMetadata",
+        OptionKind::Boolean,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "erasableSyntaxOnly",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(5, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "esModuleInterop",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(2, 7),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "exactOptionalPropertyTypes",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(4, 4),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "experimentalDecorators",
+        OptionKind::Boolean,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "explainFiles",
+        OptionKind::Boolean,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(4, 2),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "extendedDiagnostics",
+        OptionKind::Boolean,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "fallbackPolling",
+        OptionKind::Enum(FallbackPolling::NAMES),
+        OptionCategory::WatchOptions,
+        TsVersion::new(3, 8),
+    ),
+    OptionInfo::new(
+        "forceConsistentCasingInFileNames",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(1, 8),
+    )
+    .with_default("true"),
+    OptionInfo::new(
+        "generateCpuProfile",
+        OptionKind::Path,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(3, 7),
+    )
+    .with_default("profile.cpuprofile"),
+    OptionInfo::new(
+        "importHelpers",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(2, 1),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "importsNotUsedAsValues",
+        OptionKind::Enum(ImportsNotUsedAsValues::NAMES),
+        OptionCategory::Emit,
+        TsVersion::new(3, 8),
+    )
+    .with_default("remove")
+    .with_deprecation(
+        TsVersion::new(5, 0),
+        Some(TsVersion::new(5, 5)),
+        Some("verbatimModuleSyntax"),
+    ),
+    OptionInfo::new(
+        "incremental",
+        OptionKind::Boolean,
+        OptionCategory::Projects,
+        TsVersion::new(3, 4),
+    )
+    .with_default("true if composite is set; false otherwise"),
+    OptionInfo::new(
+        "inlineSourceMap",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "inlineSources",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "isolatedDeclarations",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(5, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "isolatedModules",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(1, 5),
+    )
+    .with_default("true if verbatimModuleSyntax is set; false otherwise"),
+    OptionInfo::new(
+        "jsx",
+        OptionKind::Enum(Jsx::NAMES),
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(1, 6),
+    ),
+    OptionInfo::new(
+        "jsxFactory",
+        OptionKind::String,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(2, 2),
+    )
+    .with_default("React.createElement"),
+    OptionInfo::new(
+        "jsxFragmentFactory",
+        OptionKind::String,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(4, 0),
+    )
+    .with_default("React.Fragment"),
+    OptionInfo::new(
+        "jsxImportSource",
+        OptionKind::String,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(4, 1),
+    )
+    .with_default("react"),
+    OptionInfo::new(
+        "keyofStringsOnly",
+        OptionKind::Boolean,
+        OptionCategory::BackwardsCompatibility,
+        TsVersion::new(2, 9),
+    )
+    .with_default("false")
+    .with_deprecation(TsVersion::new(5, 0), Some(TsVersion::new(5, 5)), None),
+    OptionInfo::new(
+        "lib",
+        OptionKind::List(&OptionKind::Enum(Lib::NAMES)),
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(2, 0),
+    )
+    .with_default("the default libs for target"),
+    OptionInfo::new(
+        "libReplacement",
+        OptionKind::Boolean,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(5, 8),
+    )
+    .with_default("true"),
+    OptionInfo::new(
+        "listEmittedFiles",
+        OptionKind::Boolean,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "listFiles",
+        OptionKind::Boolean,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "locale",
+        OptionKind::String,
+        OptionCategory::CommandLine,
+        TsVersion::new(2, 0),
+    )
+    .with_default("the locale of the system"),
+    OptionInfo::new(
+        "mapRoot",
+        OptionKind::Path,
+        OptionCategory::Emit,
+        TsVersion::new(1, 0),
+    ),
+    OptionInfo::new(
+        "maxNodeModuleJsDepth",
+        OptionKind::Number,
+        OptionCategory::JavaScriptSupport,
+        TsVersion::new(2, 0),
+    )
+    .with_default("0"),
+    OptionInfo::new(
+        "module",
+        OptionKind::Enum(Module::NAMES),
+        OptionCategory::Modules,
+        TsVersion::new(1, 0),
+    )
+    .with_default("CommonJS if target is ES5; ES6/ES2015 otherwise"),
+    OptionInfo::new(
+        "moduleDetection",
+        OptionKind::Enum(ModuleDetectionMode::NAMES),
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(4, 7),
+    )
+    .with_default("auto"),
+    OptionInfo::new(
+        "moduleResolution",
+        OptionKind::Enum(ModuleResolutionMode::NAMES),
+        OptionCategory::Modules,
+        TsVersion::new(1, 6),
+    )
+    .with_default("depends on module"),
+    OptionInfo::new(
+        "moduleSuffixes",
+        OptionKind::List(&OptionKind::String),
+        OptionCategory::Modules,
+        TsVersion::new(4, 7),
+    ),
+    OptionInfo::new(
+        "noCheck",
+        OptionKind::Boolean,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(5, 6),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noEmit",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noEmitHelpers",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noEmitOnError",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 4),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noErrorTruncation",
+        OptionKind::Boolean,
+        OptionCategory::OutputFormatting,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noFallthroughCasesInSwitch",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(1, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noImplicitAny",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(1, 0),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "noImplicitOverride",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(4, 3),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noImplicitReturns",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(1, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noImplicitThis",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 0),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "noImplicitUseStrict",
+        OptionKind::Boolean,
+        OptionCategory::BackwardsCompatibility,
+        TsVersion::new(1, 8),
+    )
+    .with_default("false")
+    .with_deprecation(TsVersion::new(5, 0), Some(TsVersion::new(5, 5)), None),
+    OptionInfo::new(
+        "noLib",
+        OptionKind::Boolean,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(1, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noPropertyAccessFromIndexSignature",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(4, 2),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noResolve",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(1, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noStrictGenericChecks",
+        OptionKind::Boolean,
+        OptionCategory::BackwardsCompatibility,
+        TsVersion::new(2, 4),
+    )
+    .with_default("false")
+    .with_deprecation(TsVersion::new(5, 0), Some(TsVersion::new(5, 5)), None),
+    OptionInfo::new(
+        "noUncheckedIndexedAccess",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(4, 1),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noUncheckedSideEffectImports",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(5, 6),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noUnusedLocals",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "noUnusedParameters",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "out",
+        OptionKind::Path,
+        OptionCategory::BackwardsCompatibility,
+        TsVersion::new(1, 0),
+    )
+    .with_deprecation(
+        TsVersion::new(1, 6),
+        Some(TsVersion::new(5, 5)),
+        Some("outFile"),
+    ),
+    OptionInfo::new(
+        "outDir",
+        OptionKind::Path,
+        OptionCategory::Emit,
+        TsVersion::new(1, 0),
+    ),
+    OptionInfo::new(
+        "outFile",
+        OptionKind::Path,
+        OptionCategory::Emit,
+        TsVersion::new(1, 6),
+    ),
+    OptionInfo::new(
+        "paths",
+        OptionKind::Map,
+        OptionCategory::Modules,
+        TsVersion::new(2, 0),
+    ),
+    OptionInfo::new(
+        "plugins",
+        OptionKind::List(&OptionKind::Object),
+        OptionCategory::EditorSupport,
+        TsVersion::new(2, 3),
+    ),
+    OptionInfo::new(
+        "preserveConstEnums",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 4),
+    )
+    .with_default("true if isolatedModules is set; false otherwise"),
+    OptionInfo::new(
+        "preserveSymlinks",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(2, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "preserveValueImports",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(4, 5),
+    )
+    .with_default("false")
+    .with_deprecation(
+        TsVersion::new(5, 0),
+        Some(TsVersion::new(5, 5)),
+        Some("verbatimModuleSyntax"),
+    ),
+    OptionInfo::new(
+        "preserveWatchOutput",
+        OptionKind::Boolean,
+        OptionCategory::OutputFormatting,
+        TsVersion::new(2, 8),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "pretty",
+        OptionKind::Boolean,
+        OptionCategory::OutputFormatting,
+        TsVersion::new(1, 8),
+    )
+    .with_default("true"),
+    OptionInfo::new(
+        "reactNamespace",
+        OptionKind::String,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(1, 8),
+    )
+    .with_default("React"),
+    OptionInfo::new(
+        "removeComments",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "resolveJsonModule",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(2, 9),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "resolvePackageJsonExports",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(5, 0),
+    )
+    .with_default("depends on moduleResolution"),
+    OptionInfo::new(
+        "resolvePackageJsonImports",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(5, 0),
+    )
+    .with_default("depends on moduleResolution"),
+    OptionInfo::new(
+        "rewriteRelativeImportExtensions",
+        OptionKind::Boolean,
+        OptionCategory::Modules,
+        TsVersion::new(5, 7),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "rootDir",
+        OptionKind::Path,
+        OptionCategory::Modules,
+        TsVersion::new(1, 5),
+    )
+    .with_default("the longest common path of the input files"),
+    OptionInfo::new(
+        "rootDirs",
+        OptionKind::List(&OptionKind::Path),
+        OptionCategory::Modules,
+        TsVersion::new(2, 0),
+    ),
+    OptionInfo::new(
+        "skipDefaultLibCheck",
+        OptionKind::Boolean,
+        OptionCategory::Completeness,
+        TsVersion::new(1, 6),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "skipLibCheck",
+        OptionKind::Boolean,
+        OptionCategory::Completeness,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "sourceMap",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "sourceRoot",
+        OptionKind::Path,
+        OptionCategory::Emit,
+        TsVersion::new(1, 0),
+    ),
+    OptionInfo::new(
+        "strict",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 3),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "strictBindCallApply",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(3, 2),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "strictBuiltinIteratorReturn",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(5, 6),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "strictFunctionTypes",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 6),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "strictNullChecks",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 0),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "strictPropertyInitialization",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(2, 7),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "stripInternal",
+        OptionKind::Boolean,
+        OptionCategory::Emit,
+        TsVersion::new(1, 5),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "suppressExcessPropertyErrors",
+        OptionKind::Boolean,
+        OptionCategory::BackwardsCompatibility,
+        TsVersion::new(1, 6),
+    )
+    .with_default("false")
+    .with_deprecation(TsVersion::new(5, 0), Some(TsVersion::new(5, 5)), None),
+    OptionInfo::new(
+        "suppressImplicitAnyIndexErrors",
+        OptionKind::Boolean,
+        OptionCategory::BackwardsCompatibility,
+        TsVersion::new(1, 4),
+    )
+    .with_default("false")
+    .with_deprecation(TsVersion::new(5, 0), Some(TsVersion::new(5, 5)), None),
+    OptionInfo::new(
+        "target",
+        OptionKind::Enum(Target::NAMES),
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(1, 0),
+    )
+    .with_default("ES5"),
+    OptionInfo::new(
+        "traceResolution",
+        OptionKind::Boolean,
+        OptionCategory::CompilerDiagnostics,
+        TsVersion::new(2, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "tsBuildInfoFile",
+        OptionKind::Path,
+        OptionCategory::Projects,
+        TsVersion::new(3, 4),
+    )
+    .with_default(".tsbuildinfo"),
+    OptionInfo::new(
+        "typeRoots",
+        OptionKind::List(&OptionKind::Path),
+        OptionCategory::Modules,
+        TsVersion::new(2, 0),
+    ),
+    OptionInfo::new(
+        "types",
+        OptionKind::List(&OptionKind::String),
+        OptionCategory::Modules,
+        TsVersion::new(2, 0),
+    ),
+    OptionInfo::new(
+        "useDefineForClassFields",
+        OptionKind::Boolean,
+        OptionCategory::LanguageAndEnvironment,
+        TsVersion::new(3, 7),
+    )
+    .with_default("true if target is ES2022 or higher; false otherwise"),
+    OptionInfo::new(
+        "useUnknownInCatchVariables",
+        OptionKind::Boolean,
+        OptionCategory::TypeChecking,
+        TsVersion::new(4, 4),
+    )
+    .with_default("true if strict is set; false otherwise"),
+    OptionInfo::new(
+        "verbatimModuleSyntax",
+        OptionKind::Boolean,
+        OptionCategory::InteropConstraints,
+        TsVersion::new(5, 0),
+    )
+    .with_default("false"),
+    OptionInfo::new(
+        "watchDirectory",
+        OptionKind::Enum(WatchDirectory::NAMES),
+        OptionCategory::WatchOptions,
+        TsVersion::new(3, 8),
+    )
+    .with_default("useFsEvents"),
+    OptionInfo::new(
+        "watchFile",
+        OptionKind::Enum(WatchFile::NAMES),
+        OptionCategory::WatchOptions,
+        TsVersion::new(3, 8),
+    )
+    .with_default("useFsEvents"),
+];
+
+/// Returns every compiler option this crate knows, sorted by name.
+///
+/// ## Example
+/// ```
+/// use tsconfig::{compiler_options, OptionCategory};
+///
+/// let strict_family = compiler_options()
+///     .iter()
+///     .filter(|option| option.default == Some("true if strict is set; false otherwise"))
+///     .count();
+/// assert!(strict_family > 5);
+/// assert!(compiler_options().iter().any(|o| o.category == OptionCategory::Modules));
+/// ```
+pub fn compiler_options() -> &'static [OptionInfo] {
+    COMPILER_OPTIONS
+}
+
+/// Returns what tsc accepts for the compiler option called `name`, such as `verbatimModuleSyntax`.
+///
+/// ## Example
+/// ```
+/// use tsconfig::{compiler_option, OptionKind, TsVersion};
+///
+/// let option = compiler_option("importsNotUsedAsValues").unwrap();
+/// assert_eq!(option.kind, OptionKind::Enum(&["remove", "preserve", "error"]));
+/// assert_eq!(option.since, TsVersion::new(3, 8));
+/// assert_eq!(option.deprecation.unwrap().replacement, Some("verbatimModuleSyntax"));
+/// assert!(compiler_option("noSuchOption").is_none());
+/// ```
+pub fn compiler_option(name: &str) -> Option<&'static OptionInfo> {
+    COMPILER_OPTIONS
+        .binary_search_by(|option| option.name.cmp(name))
+        .ok()
+        .map(|index| &COMPILER_OPTIONS[index])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TsConfig;
+    use serde_json::{json, Map, Value};
+
+    #[test]
+    fn describes_every_modelled_option() {
+        assert!(COMPILER_OPTIONS.windows(2).all(|w| w[0].name < w[1].name));
+
+        // Every option in the registry should be a field of CompilerOptions, so none are unknown.
+        let options: Map<String, Value> = COMPILER_OPTIONS
+            .iter()
+            .map(|option| (option.name.to_string(), Value::Null))
+            .collect();
+        let config = TsConfig::from_value(json!({ "compilerOptions": options })).unwrap();
+        let unknown = config.compiler_options.unwrap().unknown_fields().clone();
+        assert!(unknown.is_empty(), "not fields: {:?}", unknown.keys());
+    }
+
+    #[test]
+    fn tracks_availability_by_version() {
+        let option = |name| compiler_option(name).unwrap();
+        assert!(!option("verbatimModuleSyntax").is_available_in(TsVersion::new(4, 9)));
+        assert!(option("verbatimModuleSyntax").is_available_in(TsVersion::new(5, 0)));
+        assert!(option("charset").is_available_in(TsVersion::new(5, 4)));
+        assert!(!option("charset").is_available_in(TsVersion::new(5, 5)));
+    }
+}
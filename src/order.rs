@@ -0,0 +1,335 @@
+//! The order that the keys of a config were written in.
+//!
+//! serde_json's maps keep their keys sorted, unless its `preserve_order` feature is enabled, which
+//! Cargo would then enable for every crate in a build. Instead, a config keeps the order of the
+//! keys in its JSON alongside it, and writes them out in that order.
+
+use std::fmt;
+use std::iter::FromIterator;
+
+use indexmap::IndexMap;
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Number, Value};
+
+/// The order of the keys of every object in a JSON value.
+///
+/// An order is only a hint. Keys that it doesn't list, such as those added since it was recorded,
+/// come after those it does, in the order their map iterates them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Order {
+    /// The keys of an object, each with the order of its value.
+    keys: IndexMap<String, Order>,
+    /// The order of each item of an array.
+    items: Vec<Order>,
+}
+
+impl Order {
+    /// Returns the order of `value` as its maps iterate it.
+    pub(crate) fn of(value: &Value) -> Order {
+        let mut order = Order::default();
+        order.update(value);
+        order
+    }
+
+    /// Returns the order of the value at `key`, if this is the order of an object.
+    pub(crate) fn key(&self, key: &str) -> Option<&Order> {
+        self.keys.get(key)
+    }
+
+    /// Returns the order of the item at `index`, if this is the order of an array.
+    pub(crate) fn item(&self, index: usize) -> Option<&Order> {
+        self.items.get(index)
+    }
+
+    /// Sets the order of the value at `key`, which keeps its place if it's already listed.
+    pub(crate) fn insert(&mut self, key: String, order: Order) {
+        self.keys.insert(key, order);
+    }
+
+    /// Makes this the order of `value`, dropping the keys that it no longer has and appending
+    /// those it has gained.
+    pub(crate) fn update(&mut self, value: &Value) {
+        match value {
+            Value::Object(object) => {
+                self.keys.retain(|key, _| object.contains_key(key));
+                for (key, value) in object {
+                    match self.keys.get_mut(key) {
+                        Some(order) => order.update(value),
+                        None => {
+                            self.keys.insert(key.clone(), Order::of(value));
+                        }
+                    }
+                }
+                self.items.clear();
+            }
+            Value::Array(items) => {
+                self.keys.clear();
+                self.items.resize_with(items.len(), Order::default);
+                for (order, item) in self.items.iter_mut().zip(items) {
+                    order.update(item);
+                }
+            }
+            _ => *self = Order::default(),
+        }
+    }
+
+    /// Appends the keys of `base` that this doesn't have, at every level, as a config inherits
+    /// options from the one it extends.
+    pub(crate) fn inherit(&mut self, base: &Order) {
+        for (key, order) in &base.keys {
+            match self.keys.get_mut(key) {
+                Some(existing) => existing.inherit(order),
+                None => {
+                    self.keys.insert(key.clone(), order.clone());
+                }
+            }
+        }
+    }
+
+    /// Renames the key at the dotted path `from` to `to`, in the same object, keeping its place.
+    /// If `to` is already there, `from` is dropped instead.
+    pub(crate) fn rename(&mut self, from: &str, to: &str) {
+        let (parent, from) = from.rsplit_once('.').unwrap_or(("", from));
+        let to = to.rsplit('.').next().unwrap_or(to);
+        let object = match self.at_mut(parent) {
+            Some(object) => object,
+            None => return,
+        };
+        if object.keys.contains_key(to) {
+            object.keys.shift_remove(from);
+        } else {
+            object.keys = std::mem::take(&mut object.keys)
+                .into_iter()
+                .map(|(key, order)| match key == from {
+                    true => (to.to_string(), order),
+                    false => (key, order),
+                })
+                .collect();
+        }
+    }
+
+    /// Returns the order of the value at a dotted path, where numeric segments index arrays.
+    fn at_mut(&mut self, path: &str) -> Option<&mut Order> {
+        let mut order = self;
+        for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+            order = match segment.parse::<usize>() {
+                Ok(index) if !order.items.is_empty() => order.items.get_mut(index)?,
+                _ => order.keys.get_mut(segment)?,
+            };
+        }
+        Some(order)
+    }
+}
+
+/// Lists keys in the order given, each with the order of its value.
+impl FromIterator<(String, Order)> for Order {
+    fn from_iter<I: IntoIterator<Item = (String, Order)>>(keys: I) -> Order {
+        Order {
+            keys: keys.into_iter().collect(),
+            items: Vec::new(),
+        }
+    }
+}
+
+/// Returns the members of `object` in `order`, followed by any it doesn't list.
+pub(crate) fn members<'a>(
+    order: Option<&Order>,
+    object: &'a Map<String, Value>,
+) -> Vec<(&'a String, &'a Value)> {
+    let order = match order {
+        Some(order) => order,
+        None => return object.iter().collect(),
+    };
+    let listed = order
+        .keys
+        .keys()
+        .filter_map(|key| object.get_key_value(key));
+    let unlisted = object
+        .iter()
+        .filter(|(key, _)| !order.keys.contains_key(*key));
+    listed.chain(unlisted).collect()
+}
+
+/// JSON read from text, along with the order its keys were written in.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Ordered {
+    pub(crate) value: Value,
+    pub(crate) order: Order,
+}
+
+impl Ordered {
+    /// JSON that wasn't read from text, whose keys are in the order its maps iterate them.
+    pub(crate) fn new(value: Value) -> Ordered {
+        Ordered {
+            value,
+            order: Order::default(),
+        }
+    }
+
+    /// Returns an object with `members`, in the order given. A key given twice keeps its first
+    /// place and its last value, as when serde_json reads it.
+    pub(crate) fn object(members: Vec<(String, Ordered)>) -> Ordered {
+        let mut object = Map::new();
+        let mut keys = IndexMap::new();
+        for (key, Ordered { value, order }) in members {
+            object.insert(key.clone(), value);
+            keys.insert(key, order);
+        }
+        Ordered {
+            value: Value::Object(object),
+            order: Order {
+                keys,
+                items: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns an array of `items`.
+    pub(crate) fn array(items: Vec<Ordered>) -> Ordered {
+        let (values, items) = items
+            .into_iter()
+            .map(|item| (item.value, item.order))
+            .unzip();
+        Ordered {
+            value: Value::Array(values),
+            order: Order {
+                keys: IndexMap::new(),
+                items,
+            },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Ordered {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(OrderedVisitor)
+    }
+}
+
+/// Reads a value the way serde_json reads a [Value], recording the order of its keys as well.
+struct OrderedVisitor;
+
+impl<'de> Visitor<'de> for OrderedVisitor {
+    type Value = Ordered;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any valid JSON value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Ordered, E> {
+        Ok(Ordered::new(Value::Bool(value)))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Ordered, E> {
+        Ok(Ordered::new(Value::from(value)))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Ordered, E> {
+        Ok(Ordered::new(Value::from(value)))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Ordered, E> {
+        Ok(Ordered::new(
+            Number::from_f64(value).map_or(Value::Null, Value::Number),
+        ))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Ordered, E> {
+        Ok(Ordered::new(Value::String(value.to_string())))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Ordered, E> {
+        Ok(Ordered::new(Value::String(value)))
+    }
+
+    fn visit_none<E>(self) -> Result<Ordered, E> {
+        Ok(Ordered::default())
+    }
+
+    fn visit_unit<E>(self) -> Result<Ordered, E> {
+        Ok(Ordered::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Ordered, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ordered::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Ordered, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Ordered::array(items))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Ordered, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut members = Vec::new();
+        while let Some(key) = map.next_key()? {
+            members.push((key, map.next_value()?));
+        }
+        Ok(Ordered::object(members))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn keys(order: Option<&Order>, value: &Value) -> Vec<String> {
+        members(order, value.as_object().unwrap())
+            .into_iter()
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    #[test]
+    fn records_the_order_keys_were_written_in() {
+        let text = r#"{"b": 1, "a": {"z": [{"y": 1, "x": 2}], "c": null}}"#;
+        let Ordered { value, mut order } = serde_json::from_str(text).unwrap();
+        assert_eq!(value, serde_json::from_str::<Value>(text).unwrap());
+        assert_eq!(keys(Some(&order), &value), ["b", "a"]);
+        assert_eq!(keys(order.key("a"), &value["a"]), ["z", "c"]);
+        let item = order
+            .key("a")
+            .and_then(|a| a.key("z"))
+            .and_then(|z| z.item(0));
+        assert_eq!(keys(item, &value["a"]["z"][0]), ["y", "x"]);
+
+        let value = json!({"b": 1, "d": 2, "a": {"c": null}, "c": 3});
+        assert_eq!(keys(Some(&order), &value), ["b", "a", "c", "d"]);
+        order.update(&value);
+        assert_eq!(keys(Some(&order), &value), ["b", "a", "c", "d"]);
+        assert_eq!(keys(order.key("a"), &value["a"]), ["c"]);
+
+        order.rename("b", "e");
+        order.rename("a.c", "a.f");
+        let value = json!({"e": 1, "d": 2, "a": {"f": null}, "c": 3});
+        assert_eq!(keys(Some(&order), &value), ["e", "a", "c", "d"]);
+        assert_eq!(keys(None, &value), ["a", "c", "d", "e"]);
+    }
+
+    #[test]
+    fn inherits_the_keys_of_a_base() {
+        let config: Ordered = serde_json::from_str(r#"{"b": {"y": 1}, "a": 1}"#).unwrap();
+        let base: Ordered = serde_json::from_str(r#"{"c": 1, "b": {"z": 1, "x": 1}}"#).unwrap();
+        let mut order = config.order;
+        order.inherit(&base.order);
+
+        let value = json!({"a": 1, "b": {"x": 1, "y": 1, "z": 1}, "c": 1});
+        assert_eq!(keys(Some(&order), &value), ["b", "a", "c"]);
+        assert_eq!(keys(order.key("b"), &value["b"]), ["y", "z", "x"]);
+    }
+}
@@ -0,0 +1,294 @@
+//! The `paths` compiler option, which maps module specifiers to the files to look for instead.
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Index;
+
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Diagnostic;
+
+/// The `paths` compiler option: patterns for module specifiers, each with the paths to try in its
+/// place.
+///
+/// A pattern, and each of its substitutions, may contain one `*`, which matches any text, and is
+/// replaced by the text it matched. Patterns are kept in the order they were written in, which tsc
+/// uses to choose between patterns that match equally well.
+///
+/// ```json
+/// {
+///   "compilerOptions": {
+///     "paths": {
+///       "@app/*": ["src/app/*", "generated/app/*"],
+///       "jquery": ["vendor/jquery/dist/jquery"]
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Paths(Vec<(String, Vec<String>)>);
+
+impl Paths {
+    /// Creates an empty set of patterns.
+    pub fn new() -> Paths {
+        Paths::default()
+    }
+
+    /// Sets the substitutions for `pattern`, replacing any it had, and keeping its place if it's
+    /// already there.
+    pub fn insert(&mut self, pattern: impl Into<String>, substitutions: Vec<String>) {
+        let pattern = pattern.into();
+        match self.0.iter_mut().find(|(key, _)| *key == pattern) {
+            Some((_, existing)) => *existing = substitutions,
+            None => self.0.push((pattern, substitutions)),
+        }
+    }
+
+    /// Returns the substitutions for `pattern`, if it's one of the patterns.
+    pub fn get(&self, pattern: &str) -> Option<&Vec<String>> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == pattern)
+            .map(|(_, substitutions)| substitutions)
+    }
+
+    /// Iterates over the patterns and their substitutions, in the order they were written in.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Vec<String>)> {
+        self.0
+            .iter()
+            .map(|(pattern, substitutions)| (pattern, substitutions))
+    }
+
+    /// Iterates over the patterns, in the order they were written in.
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.0.iter().map(|(pattern, _)| pattern)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a diagnostic for each pattern or substitution that tsc rejects: those with more than
+    /// one `*`, and patterns with no substitutions.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let json = r#"{"compilerOptions": {"paths": {"@app/*/*": ["src/*"], "@lib/*": []}}}"#;
+    /// let paths = TsConfig::parse_str(json).unwrap().compiler_options.unwrap().paths.unwrap();
+    ///
+    /// let diagnostics = paths.validate();
+    /// assert_eq!(diagnostics[0].field, "compilerOptions.paths.@app/*/*");
+    /// assert_eq!(diagnostics[0].message, "Pattern '@app/*/*' can have at most one '*' character.");
+    /// assert_eq!(diagnostics[1].message, "Substitutions for pattern '@lib/*' shouldn't be an empty array.");
+    /// ```
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for (pattern, substitutions) in &self.0 {
+            let field = format!("compilerOptions.paths.{}", pattern);
+            if pattern.matches('*').count() > 1 {
+                let message = format!("Pattern '{}' can have at most one '*' character.", pattern);
+                diagnostics.push(Diagnostic::new(&field, message));
+            }
+            if substitutions.is_empty() {
+                let message = format!(
+                    "Substitutions for pattern '{}' shouldn't be an empty array.",
+                    pattern
+                );
+                diagnostics.push(Diagnostic::new(&field, message));
+            }
+            for substitution in substitutions {
+                if substitution.matches('*').count() > 1 {
+                    let message = format!(
+                        "Substitution '{}' in pattern '{}' can have at most one '*' character.",
+                        substitution, pattern
+                    );
+                    diagnostics.push(Diagnostic::new(&field, message));
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// Returns the paths that tsc tries, in order, for the module `specifier`, or nothing if no
+    /// pattern matches it.
+    ///
+    /// A pattern without a `*` that equals the specifier is used first. Otherwise, of the patterns
+    /// whose text before and after the `*` the specifier starts and ends with, the one with the
+    /// longest text before the `*` is used, and the first written if several tie. Only that
+    /// pattern's substitutions are tried, with the text that the `*` matched put in place of
+    /// theirs. The paths are as written, not yet resolved against `baseUrl` or the config's
+    /// directory.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let json = r#"{"compilerOptions": {"paths": {
+    ///     "*": ["types/*"],
+    ///     "@app/*": ["src/app/*", "generated/app/*"],
+    ///     "@app/config": ["config/index"]
+    /// }}}"#;
+    /// let paths = TsConfig::parse_str(json).unwrap().compiler_options.unwrap().paths.unwrap();
+    ///
+    /// assert_eq!(paths.match_specifier("@app/foo"), ["src/app/foo", "generated/app/foo"]);
+    /// assert_eq!(paths.match_specifier("@app/config"), ["config/index"]);
+    /// assert_eq!(paths.match_specifier("react"), ["types/react"]);
+    /// ```
+    pub fn match_specifier(&self, specifier: &str) -> Vec<String> {
+        let exact = self
+            .0
+            .iter()
+            .find(|(pattern, _)| !pattern.contains('*') && pattern == specifier);
+        if let Some((_, substitutions)) = exact {
+            return substitutions.clone();
+        }
+
+        let mut best: Option<(&str, &str, &Vec<String>)> = None;
+        for (pattern, substitutions) in &self.0 {
+            let (prefix, suffix) = match pattern.split_once('*') {
+                Some(parts) => parts,
+                None => continue,
+            };
+            let matches = specifier.len() >= prefix.len() + suffix.len()
+                && specifier.starts_with(prefix)
+                && specifier.ends_with(suffix);
+            if matches && best.is_none_or(|(best, _, _)| prefix.len() > best.len()) {
+                best = Some((prefix, suffix, substitutions));
+            }
+        }
+        match best {
+            Some((prefix, suffix, substitutions)) => {
+                let star = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                substitutions
+                    .iter()
+                    .map(|substitution| substitution.replacen('*', star, 1))
+                    .collect()
+            }
+            None => Vec::new(),
+        }
+    }
+}
+
+impl Index<&str> for Paths {
+    type Output = Vec<String>;
+
+    fn index(&self, pattern: &str) -> &Vec<String> {
+        self.get(pattern)
+            .unwrap_or_else(|| panic!("no pattern `{}` in paths", pattern))
+    }
+}
+
+impl FromIterator<(String, Vec<String>)> for Paths {
+    fn from_iter<I: IntoIterator<Item = (String, Vec<String>)>>(iter: I) -> Paths {
+        let mut paths = Paths::new();
+        for (pattern, substitutions) in iter {
+            paths.insert(pattern, substitutions);
+        }
+        paths
+    }
+}
+
+impl<'de> Deserialize<'de> for Paths {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PathsVisitor;
+
+        impl<'de> Visitor<'de> for PathsVisitor {
+            type Value = Paths;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an object mapping patterns to lists of paths")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Paths, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paths = Paths::new();
+                while let Some((pattern, substitutions)) =
+                    map.next_entry::<String, Vec<String>>()?
+                {
+                    paths.insert(pattern, substitutions);
+                }
+                Ok(paths)
+            }
+        }
+
+        deserializer.deserialize_map(PathsVisitor)
+    }
+}
+
+impl Serialize for Paths {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (pattern, substitutions) in &self.0 {
+            map.serialize_entry(pattern, substitutions)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Paths {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Paths".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <std::collections::HashMap<String, Vec<String>>>::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn paths(json: &str) -> Paths {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn matches_the_longest_prefix_first_written() {
+        let paths = paths(
+            r#"{
+                "@app/*.css": ["styles/*.css"],
+                "@app/*": ["src/*", "lib/*"],
+                "@app/*.js": ["unused/*"],
+                "@app/ui/*": ["ui/*/index"]
+            }"#,
+        );
+        assert_eq!(paths.keys().next().unwrap(), "@app/*.css");
+        assert_eq!(paths.match_specifier("@app/ui/button"), ["ui/button/index"]);
+        assert_eq!(
+            paths.match_specifier("@app/theme.css"),
+            ["styles/theme.css"]
+        );
+        assert_eq!(paths.match_specifier("@app/main"), ["src/main", "lib/main"]);
+        assert!(paths.match_specifier("@other/main").is_empty());
+        assert_eq!(paths.match_specifier("@app/"), ["src/", "lib/"]);
+        assert!(paths.match_specifier("@ap").is_empty());
+    }
+
+    #[test]
+    fn reports_substitutions_with_several_stars() {
+        let paths = paths(r#"{"@app/*": ["src/*/*", "lib/*"]}"#);
+        let messages: Vec<_> = paths.validate().into_iter().map(|d| d.message).collect();
+        assert_eq!(
+            messages,
+            ["Substitution 'src/*/*' in pattern '@app/*' can have at most one '*' character."]
+        );
+    }
+}
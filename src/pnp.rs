@@ -0,0 +1,221 @@
+//! Resolving `extends` in Yarn Plug'n'Play installs, which have no node_modules directory.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use pnp::fs::{LruZipCache, VPath, VPathInfo, ZipCache};
+use pnp::{Manifest, Resolution};
+
+use crate::fs::{normalize, FileSystem};
+use crate::loader::{is_relative_specifier, Search};
+use crate::{ConfigError, ExtendsResolver, NodeResolver, Result};
+
+/// Resolves `extends` through a Yarn Plug'n'Play manifest.
+///
+/// Package specifiers are looked up in the manifest, from the package that owns the extending
+/// config, rather than in `node_modules`. Relative specifiers are resolved as [NodeResolver] does.
+/// Yarn usually leaves packages in zip archives, so this is best combined with [PnpFileSystem].
+/// It is only available with the `pnp` feature.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::{ConfigLoader, PnpFileSystem, PnpResolver};
+///
+/// let root = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test/pnp");
+/// let loader = ConfigLoader::new()
+///     .with_resolver(PnpResolver::find(&root).unwrap().unwrap())
+///     .with_file_system(PnpFileSystem::new());
+///
+/// let config = loader.load(&root.join("tsconfig.json")).unwrap();
+/// assert_eq!(config.compiler_options.unwrap().strict, Some(true));
+/// ```
+#[derive(Debug)]
+pub struct PnpResolver {
+    manifest: Manifest,
+}
+
+impl PnpResolver {
+    /// Loads the manifest at `path`, which is either a `.pnp.cjs` file or a `.pnp.data.json` file.
+    pub fn from_manifest(path: &Path) -> Result<PnpResolver> {
+        let path = absolute(path)?;
+        if path.extension() != Some("json".as_ref()) {
+            return Ok(PnpResolver {
+                manifest: pnp::load_pnp_manifest(&path)?,
+            });
+        }
+        let text = std::fs::read_to_string(&path)
+            .map_err(|err| ConfigError::CouldNotReadFile(path.clone(), err))?;
+        let mut manifest: Manifest = serde_json::from_str(&text)?;
+        pnp::init_pnp_manifest(&mut manifest, &path);
+        Ok(PnpResolver { manifest })
+    }
+
+    /// Finds and loads the nearest manifest in `dir` or one of its ancestors, if there is one.
+    pub fn find(dir: &Path) -> Result<Option<PnpResolver>> {
+        for dir in absolute(dir)?.ancestors() {
+            for name in &[".pnp.cjs", ".pnp.data.json"] {
+                let path = dir.join(name);
+                if path.is_file() {
+                    return PnpResolver::from_manifest(&path).map(Some);
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl ExtendsResolver for PnpResolver {
+    fn resolve(&self, from: &Path, specifier: &str, fs: &dyn FileSystem) -> Result<PathBuf> {
+        if is_relative_specifier(specifier) {
+            return NodeResolver::new().resolve(from, specifier, fs);
+        }
+        let mut search = Search::new(fs);
+        match pnp::resolve_to_unqualified_via_manifest(&self.manifest, specifier, &absolute(from)?)?
+        {
+            Resolution::Resolved(package, subpath) => {
+                let candidate = match subpath {
+                    Some(subpath) => package.join(subpath),
+                    None => package,
+                };
+                search
+                    .package(candidate)
+                    .ok_or_else(|| search.unresolved(from, specifier))
+            }
+            // `from` isn't part of any package in the manifest.
+            Resolution::Skipped => Err(search.unresolved(from, specifier)),
+        }
+    }
+}
+
+fn absolute(path: &Path) -> Result<PathBuf> {
+    if path.is_absolute() {
+        return Ok(normalize(path));
+    }
+    std::env::current_dir()
+        .map(|cwd| normalize(&cwd.join(path)))
+        .map_err(|err| ConfigError::CouldNotReadFile(path.into(), err))
+}
+
+/// The real filesystem, extended to read files inside the zip archives that Yarn Plug'n'Play
+/// installs packages into, and to follow its `__virtual__` paths.
+///
+/// It is only available with the `pnp` feature.
+#[derive(Debug)]
+pub struct PnpFileSystem {
+    zips: LruZipCache<Vec<u8>>,
+}
+
+impl PnpFileSystem {
+    /// Creates a filesystem that keeps up to 50 zip archives open.
+    pub fn new() -> PnpFileSystem {
+        PnpFileSystem {
+            zips: LruZipCache::new(50, pnp::fs::open_zip_via_read_p),
+        }
+    }
+}
+
+impl Default for PnpFileSystem {
+    fn default() -> Self {
+        PnpFileSystem::new()
+    }
+}
+
+impl FileSystem for PnpFileSystem {
+    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
+        match VPath::from(path)? {
+            VPath::Zip(info) => self.zips.read(info.physical_base_path(), &info.zip_path),
+            VPath::Virtual(info) => std::fs::read(info.physical_base_path()),
+            VPath::Native(path) => std::fs::read(path),
+        }
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        match VPath::from(path) {
+            Ok(VPath::Zip(info)) => matches!(
+                self.zips
+                    .file_type(info.physical_base_path(), &info.zip_path),
+                Ok(pnp::fs::FileType::File)
+            ),
+            Ok(VPath::Virtual(info)) => info.physical_base_path().is_file(),
+            Ok(VPath::Native(path)) => path.is_file(),
+            Err(_) => false,
+        }
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        match VPath::from(path)? {
+            // Files inside an archive can't be symlinks.
+            VPath::Zip(_) if self.exists(path) => Ok(normalize(path)),
+            VPath::Zip(_) => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} does not exist", path.display()),
+            )),
+            VPath::Virtual(info) => info.physical_base_path().canonicalize(),
+            VPath::Native(path) => path.canonicalize(),
+        }
+    }
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        // Files inside an archive change when the archive does.
+        let physical = match VPath::from(path)? {
+            VPath::Zip(info) => info.physical_base_path(),
+            VPath::Virtual(info) => info.physical_base_path(),
+            VPath::Native(path) => path,
+        };
+        std::fs::metadata(physical)?.modified()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ConfigLoader;
+
+    fn fixture(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("test/pnp")
+            .join(path)
+    }
+
+    #[test]
+    fn resolves_packages_in_zip_archives() {
+        for manifest in &[".pnp.cjs", ".pnp.data.json"] {
+            let resolver = PnpResolver::from_manifest(&fixture(manifest)).unwrap();
+            let resolved = resolver
+                .resolve(
+                    &fixture("tsconfig.json"),
+                    "@acme/tsconfig",
+                    &PnpFileSystem::new(),
+                )
+                .unwrap();
+            assert!(resolved.ends_with("node_modules/@acme/tsconfig/base.json"));
+            assert!(resolved.to_str().unwrap().contains(".zip/"));
+        }
+    }
+
+    #[test]
+    fn loads_configs_from_zip_archives() {
+        let config = ConfigLoader::new()
+            .with_resolver(PnpResolver::find(&fixture("")).unwrap().unwrap())
+            .with_file_system(PnpFileSystem::new())
+            .load(&fixture("tsconfig.json"))
+            .unwrap();
+        let out_dir = config.compiler_options.unwrap().out_dir.unwrap();
+        assert!(out_dir.ends_with(".zip/node_modules/@acme/tsconfig/dist"));
+    }
+
+    #[test]
+    fn rejects_undeclared_dependencies() {
+        let resolver = PnpResolver::from_manifest(&fixture(".pnp.cjs")).unwrap();
+        let err = resolver
+            .resolve(
+                &fixture("tsconfig.json"),
+                "@acme/other",
+                &PnpFileSystem::new(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::PnpError(_)));
+    }
+}
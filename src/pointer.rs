@@ -0,0 +1,149 @@
+//! Setting values by JSON Pointer, as RFC 6901 defines them.
+
+use serde_json::{Map, Value};
+
+use crate::{ConfigError, Result};
+
+const NOT_A_CONTAINER: &str = "passes through a value that isn't an object or array";
+const NO_SUCH_ITEM: &str = "refers to an array item that doesn't exist";
+
+/// Sets the value at `pointer` in `root`, creating any missing objects on the way to it.
+///
+/// The last token may be `-`, which appends to an array, as in RFC 6902.
+pub(crate) fn set(root: &mut Value, pointer: &str, value: Value) -> Result<()> {
+    let invalid = |reason: &str| ConfigError::InvalidEdit(format!("`{}` {}", pointer, reason));
+    if pointer.is_empty() {
+        *root = value;
+        return Ok(());
+    }
+    let tokens = pointer
+        .strip_prefix('/')
+        .ok_or_else(|| invalid("is not a JSON pointer, which must start with `/`"))?
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>();
+
+    let (last, parents) = tokens.split_last().expect("split always gives a token");
+    let mut target = root;
+    for token in parents {
+        target = match target {
+            Value::Object(object) => object
+                .entry(token.as_str())
+                .or_insert_with(|| Value::Object(Map::new())),
+            Value::Array(array) => {
+                let i = index(array, token).ok_or_else(|| invalid(NO_SUCH_ITEM))?;
+                &mut array[i]
+            }
+            _ => return Err(invalid(NOT_A_CONTAINER)),
+        };
+        if target.is_null() {
+            *target = Value::Object(Map::new());
+        }
+    }
+    match target {
+        Value::Object(object) => {
+            object.insert(last.clone(), value);
+        }
+        Value::Array(array) if last == "-" => array.push(value),
+        Value::Array(array) => {
+            let i = index(array, last).ok_or_else(|| invalid(NO_SUCH_ITEM))?;
+            array[i] = value;
+        }
+        _ => return Err(invalid(NOT_A_CONTAINER)),
+    }
+    Ok(())
+}
+
+/// Removes the value at `pointer` in `root`, returning it, or `None` if there is nothing there.
+/// Object members keep their order.
+pub(crate) fn remove(root: &mut Value, pointer: &str) -> Option<Value> {
+    let (parent, last) = pointer.rsplit_once('/')?;
+    let last = last.replace("~1", "/").replace("~0", "~");
+    match root.pointer_mut(parent)? {
+        Value::Object(object) => {
+            let value = object.get(&last).cloned()?;
+            object.retain(|key, _| *key != last);
+            Some(value)
+        }
+        Value::Array(array) => {
+            let i = index(array, &last)?;
+            Some(array.remove(i))
+        }
+        _ => None,
+    }
+}
+
+/// Converts a dotted path, such as `compilerOptions.paths`, into a JSON pointer.
+pub(crate) fn from_path(path: &str) -> String {
+    path.split('.')
+        .map(|key| format!("/{}", key.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Parses an array index, which RFC 6901 writes without leading zeros.
+fn index(array: &[Value], token: &str) -> Option<usize> {
+    if token.len() > 1 && token.starts_with('0') {
+        return None;
+    }
+    token.parse().ok().filter(|i| *i < array.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sets_values_by_pointer() {
+        let mut value = json!({"compilerOptions": {"paths": {}}, "include": ["src"]});
+        set(
+            &mut value,
+            "/compilerOptions/paths/@app~1*",
+            json!(["app/*"]),
+        )
+        .unwrap();
+        set(&mut value, "/include/-", json!("test")).unwrap();
+        set(&mut value, "/include/0", json!("lib")).unwrap();
+        set(&mut value, "/watchOptions/watchFile", json!("useFsEvents")).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "compilerOptions": {"paths": {"@app/*": ["app/*"]}},
+                "include": ["lib", "test"],
+                "watchOptions": {"watchFile": "useFsEvents"},
+            })
+        );
+
+        for pointer in &[
+            "compilerOptions",
+            "/include/2",
+            "/include/00",
+            "/include/0/x",
+        ] {
+            let err = set(&mut value, pointer, json!(true)).unwrap_err();
+            assert!(matches!(err, ConfigError::InvalidEdit(_)), "{}", pointer);
+        }
+    }
+
+    #[test]
+    fn removes_values_by_dotted_path() {
+        let mut value = json!({"compilerOptions": {"paths": {"@app/*": []}, "strict": true}, "include": ["a", "b"]});
+        assert_eq!(
+            from_path("compilerOptions.paths.@app/*"),
+            "/compilerOptions/paths/@app~1*"
+        );
+
+        let removed = remove(&mut value, &from_path("compilerOptions.paths"));
+        assert_eq!(removed, Some(json!({"@app/*": []})));
+        assert_eq!(
+            remove(&mut value, &from_path("include.0")),
+            Some(json!("a"))
+        );
+        assert_eq!(remove(&mut value, &from_path("include.1")), None);
+        assert_eq!(remove(&mut value, &from_path("files")), None);
+        assert_eq!(
+            value,
+            json!({"compilerOptions": {"strict": true}, "include": ["b"]})
+        );
+    }
+}
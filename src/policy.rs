@@ -0,0 +1,227 @@
+//! Checking that the packages in a monorepo stay consistent with a shared base config.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::diff::diff;
+use crate::fingerprint::normalize;
+use crate::{ConfigLoader, Result};
+
+/// Top-level fields that describe a package's own files, rather than options shared with the base.
+const PACKAGE_FIELDS: &[&str] = &["extends", "files", "include", "exclude", "references"];
+
+/// Rules that every package config in a repo should follow: extend a shared base config, leave
+/// the options it sets alone, and set certain options to required values.
+///
+/// ## Example
+/// ```
+/// use serde_json::json;
+/// use tsconfig::{ConfigLoader, ConfigPolicy, MemoryFileSystem, PolicyViolation};
+///
+/// let mut fs = MemoryFileSystem::new();
+/// fs.insert("/repo/tsconfig.base.json", r#"{"compilerOptions": {"strict": true}}"#);
+/// fs.insert("/repo/app/tsconfig.json", r#"{"extends": "../tsconfig.base.json", "compilerOptions": {"strict": false}}"#);
+/// fs.insert("/repo/lib/tsconfig.json", r#"{"compilerOptions": {"strict": true}}"#);
+///
+/// let policy = ConfigPolicy::new("/repo/tsconfig.base.json")
+///     .with_loader(ConfigLoader::new().with_file_system(fs))
+///     .with_required("compilerOptions.strict", json!(true));
+/// let violations = policy
+///     .check(&["/repo/app/tsconfig.json", "/repo/lib/tsconfig.json"])
+///     .unwrap();
+///
+/// assert_eq!(violations.len(), 3);
+/// assert!(matches!(violations[0], PolicyViolation::OverridesBase { .. }));
+/// assert!(matches!(violations[1], PolicyViolation::NotRequiredValue { .. }));
+/// assert!(matches!(violations[2], PolicyViolation::DoesNotExtendBase { .. }));
+/// ```
+#[derive(Clone)]
+pub struct ConfigPolicy {
+    base: PathBuf,
+    required: Vec<(String, Value)>,
+    loader: ConfigLoader,
+}
+
+/// A way in which a package config breaks a [ConfigPolicy].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyViolation {
+    /// The package doesn't extend the base config, directly or indirectly.
+    DoesNotExtendBase { path: PathBuf },
+    /// The package sets an option to something other than the value the base config gives it.
+    OverridesBase {
+        path: PathBuf,
+        /// Dotted path of the option, such as `compilerOptions.strict`.
+        field: String,
+        base: Value,
+        value: Value,
+    },
+    /// The package doesn't set a required option to the required value.
+    NotRequiredValue {
+        path: PathBuf,
+        /// Dotted path of the option, such as `compilerOptions.strict`.
+        field: String,
+        required: Value,
+        /// The value the package has, if it has one.
+        value: Option<Value>,
+    },
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyViolation::DoesNotExtendBase { path } => {
+                write!(f, "{}: does not extend the base config", path.display())
+            }
+            PolicyViolation::OverridesBase {
+                path,
+                field,
+                base,
+                value,
+            } => write!(
+                f,
+                "{}: {} is {}, overriding {} from the base config",
+                path.display(),
+                field,
+                value,
+                base
+            ),
+            PolicyViolation::NotRequiredValue {
+                path,
+                field,
+                required,
+                value: Some(value),
+            } => write!(
+                f,
+                "{}: {} is {}, but must be {}",
+                path.display(),
+                field,
+                value,
+                required
+            ),
+            PolicyViolation::NotRequiredValue {
+                path,
+                field,
+                required,
+                value: None,
+            } => write!(f, "{}: {} must be {}", path.display(), field, required),
+        }
+    }
+}
+
+impl ConfigPolicy {
+    /// Creates a policy that packages must extend the config at `base`.
+    pub fn new(base: impl AsRef<Path>) -> ConfigPolicy {
+        ConfigPolicy {
+            base: base.as_ref().to_path_buf(),
+            required: Vec::new(),
+            loader: ConfigLoader::new(),
+        }
+    }
+
+    /// Requires packages to set the option at the dotted path `field`, such as
+    /// `compilerOptions.strict`, to `value`, whether they inherit it or set it themselves.
+    pub fn with_required(mut self, field: &str, value: Value) -> ConfigPolicy {
+        self.required.push((field.to_string(), value));
+        self
+    }
+
+    /// Sets the loader used to read the base and package configs.
+    ///
+    /// Paths are compared as they are written, so the loader's
+    /// [ConfigLoader::with_raw_paths] setting is ignored.
+    pub fn with_loader(self, loader: ConfigLoader) -> ConfigPolicy {
+        ConfigPolicy { loader, ..self }
+    }
+
+    /// Checks each package config against the policy, returning every violation found, in the
+    /// order the packages were given.
+    pub fn check<P: AsRef<Path>>(&self, packages: &[P]) -> Result<Vec<PolicyViolation>> {
+        let loader = self.loader.clone().with_raw_paths(true);
+        let (base, base_files) = loader.load_value_and_files(&self.base)?;
+
+        let mut violations = Vec::new();
+        for package in packages {
+            let path = package.as_ref().to_path_buf();
+            let (value, files) = loader.load_value_and_files(&path)?;
+
+            if !files.iter().skip(1).any(|file| file == &base_files[0]) {
+                violations.push(PolicyViolation::DoesNotExtendBase { path: path.clone() });
+            } else {
+                for (field, base, value) in diff(&base, &value).changed {
+                    let top_level = field.split('.').next().unwrap_or("");
+                    if !PACKAGE_FIELDS.contains(&top_level) {
+                        violations.push(PolicyViolation::OverridesBase {
+                            path: path.clone(),
+                            field,
+                            base,
+                            value,
+                        });
+                    }
+                }
+            }
+
+            for (field, required) in &self.required {
+                let actual = field
+                    .split('.')
+                    .try_fold(&value, |value, key| value.get(key));
+                let matches = actual
+                    .is_some_and(|actual| normalize(actual, field) == normalize(required, field));
+                if !matches {
+                    violations.push(PolicyViolation::NotRequiredValue {
+                        path: path.clone(),
+                        field: field.clone(),
+                        required: required.clone(),
+                        value: actual.cloned(),
+                    });
+                }
+            }
+        }
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryFileSystem;
+    use serde_json::json;
+
+    #[test]
+    fn allows_package_fields_and_inherited_paths() {
+        let mut fs = MemoryFileSystem::new();
+        fs.insert(
+            "/repo/tsconfig.base.json",
+            r#"{"compilerOptions": {"strict": true, "target": "ES2022", "outDir": "dist"}, "include": ["src"]}"#,
+        );
+        fs.insert(
+            "/repo/tsconfig.node.json",
+            r#"{"extends": "./tsconfig.base.json", "compilerOptions": {"types": ["node"]}}"#,
+        );
+        fs.insert(
+            "/repo/packages/app/tsconfig.json",
+            r#"{"extends": "../../tsconfig.node.json", "include": ["lib"], "compilerOptions": {"target": "es2022"}}"#,
+        );
+
+        let policy = ConfigPolicy::new("/repo/tsconfig.base.json")
+            .with_loader(ConfigLoader::new().with_file_system(fs))
+            .with_required("compilerOptions.target", json!("es2022"))
+            .with_required("compilerOptions.noEmit", json!(true));
+        let violations = policy.check(&["/repo/packages/app/tsconfig.json"]).unwrap();
+
+        assert_eq!(
+            violations,
+            [PolicyViolation::NotRequiredValue {
+                path: "/repo/packages/app/tsconfig.json".into(),
+                field: "compilerOptions.noEmit".to_string(),
+                required: json!(true),
+                value: None,
+            }]
+        );
+        assert_eq!(
+            violations[0].to_string(),
+            "/repo/packages/app/tsconfig.json: compilerOptions.noEmit must be true"
+        );
+    }
+}
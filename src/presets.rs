@@ -0,0 +1,200 @@
+//! Vendored copies of popular `@tsconfig/*` base configs, used when they aren't installed, and
+//! the presets built from them.
+
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::from_jsonc;
+use crate::order::Ordered;
+
+/// The bundled packages, and the contents of their tsconfig.json.
+const PRESETS: &[(&str, &str)] = &[
+    ("@tsconfig/node16", include_str!("presets/node16.json")),
+    ("@tsconfig/node18", include_str!("presets/node18.json")),
+    ("@tsconfig/node20", include_str!("presets/node20.json")),
+    ("@tsconfig/node22", include_str!("presets/node22.json")),
+    (
+        "@tsconfig/recommended",
+        include_str!("presets/recommended.json"),
+    ),
+    (
+        "@tsconfig/strictest",
+        include_str!("presets/strictest.json"),
+    ),
+    (
+        "@tsconfig/vite-react",
+        include_str!("presets/vite-react.json"),
+    ),
+];
+
+/// Fields of the `@tsconfig/*` packages that describe the package, or its schema, rather than the
+/// config.
+const PACKAGE_FIELDS: &[&str] = &["$schema", "display", "_version"];
+
+/// A starting point for a new project's config, for use with [TsConfig::preset](crate::TsConfig::preset).
+///
+/// Most presets are the config of an `@tsconfig/*` base, which is named in each variant's docs.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// `@tsconfig/node16`.
+    Node16,
+    /// `@tsconfig/node18`.
+    Node18,
+    /// `@tsconfig/node20`.
+    Node20,
+    /// `@tsconfig/node22`.
+    Node22,
+    /// `@tsconfig/vite-react`, for a React app bundled by Vite.
+    React,
+    /// `@tsconfig/recommended`.
+    Recommended,
+    /// `@tsconfig/strictest`.
+    Strictest,
+    /// `@tsconfig/recommended`, with `declaration`, `declarationMap` and `sourceMap` turned on, for
+    /// a package that publishes its types.
+    Library,
+}
+
+impl Preset {
+    /// Returns the preset's config as JSON, without the fields that describe its package.
+    pub(crate) fn to_json(self) -> Ordered {
+        let package = match self {
+            Preset::Node16 => "@tsconfig/node16",
+            Preset::Node18 => "@tsconfig/node18",
+            Preset::Node20 => "@tsconfig/node20",
+            Preset::Node22 => "@tsconfig/node22",
+            Preset::React => "@tsconfig/vite-react",
+            Preset::Recommended | Preset::Library => "@tsconfig/recommended",
+            Preset::Strictest => "@tsconfig/strictest",
+        };
+        let (_, text) = find(package).expect("every preset is bundled");
+        let mut json: Ordered = from_jsonc(text).expect("bundled presets are valid JSON");
+        if let Value::Object(fields) = &mut json.value {
+            fields.retain(|key, _| !PACKAGE_FIELDS.contains(&key.as_str()));
+        }
+        if self == Preset::Library {
+            crate::merge(
+                &mut json.value,
+                serde_json::json!({
+                    "compilerOptions": {
+                        "declaration": true,
+                        "declarationMap": true,
+                        "sourceMap": true,
+                    },
+                }),
+            );
+        }
+        json
+    }
+}
+
+/// Looks up a bundled preset by the specifier used to extend it, such as `@tsconfig/node20`,
+/// `@tsconfig/node20/tsconfig` or `@tsconfig/node20/tsconfig.json`.
+///
+/// Returns the path the preset would have in `node_modules`, and its contents.
+pub(crate) fn find(specifier: &str) -> Option<(PathBuf, &'static str)> {
+    let package = specifier
+        .strip_suffix("/tsconfig.json")
+        .or_else(|| specifier.strip_suffix("/tsconfig"))
+        .unwrap_or(specifier);
+    PRESETS
+        .iter()
+        .find(|(name, _)| *name == package)
+        .map(|(name, text)| {
+            let path = PathBuf::from("node_modules")
+                .join(name)
+                .join("tsconfig.json");
+            (path, *text)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{KeyOrder, Target, TsConfig, WriteOptions};
+
+    #[test]
+    fn bundled_presets_parse() {
+        for (name, text) in PRESETS {
+            let config = TsConfig::parse_str(text).unwrap();
+            let strict = config.compiler_options.unwrap().strict;
+            assert_eq!(strict, Some(true), "{}", name);
+        }
+    }
+
+    #[test]
+    fn finds_presets_by_specifier() {
+        for specifier in &[
+            "@tsconfig/node20",
+            "@tsconfig/node20/tsconfig",
+            "@tsconfig/node20/tsconfig.json",
+        ] {
+            let (path, text) = find(specifier).unwrap();
+            assert!(path.ends_with("node_modules/@tsconfig/node20/tsconfig.json"));
+            assert!(text.contains("Node 20"));
+        }
+        assert!(find("@tsconfig/node10").is_none());
+        assert!(find("@tsconfig/node20/other.json").is_none());
+    }
+
+    #[test]
+    fn presets_drop_package_fields() {
+        let config = TsConfig::preset(Preset::Library);
+        let raw = config.raw();
+        assert_eq!(
+            raw.as_object().unwrap().keys().collect::<Vec<_>>(),
+            ["compilerOptions"]
+        );
+        let opts = config.compiler_options.unwrap();
+        assert_eq!(opts.declaration_map, Some(true));
+        assert_eq!(opts.force_consistent_casing_in_file_names, Some(true));
+
+        for preset in [
+            Preset::Node16,
+            Preset::Node18,
+            Preset::Node20,
+            Preset::Node22,
+            Preset::React,
+            Preset::Recommended,
+            Preset::Strictest,
+            Preset::Library,
+        ] {
+            let json = TsConfig::preset(preset).to_string_pretty().unwrap();
+            TsConfig::parse_str_strict(&json).unwrap();
+        }
+    }
+
+    #[test]
+    fn presets_serialize_tweaks() {
+        let mut config = TsConfig::preset(Preset::Node20);
+        config.include = Some(vec!["src".to_string()]);
+        let options = config.compiler_options.as_mut().unwrap();
+        options.target = Some(Target::Es2024);
+        options.module_resolution = None;
+        options.out_dir = Some("dist".to_string());
+
+        let options = WriteOptions::new().with_key_order(KeyOrder::Documented);
+        assert_eq!(
+            config.to_string_with(&options).unwrap(),
+            r#"{
+  "include": [
+    "src"
+  ],
+  "compilerOptions": {
+    "strict": true,
+    "module": "nodenext",
+    "outDir": "dist",
+    "esModuleInterop": true,
+    "lib": [
+      "es2023"
+    ],
+    "target": "ES2024",
+    "skipLibCheck": true
+  }
+}
+"#
+        );
+    }
+}
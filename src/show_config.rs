@@ -0,0 +1,144 @@
+//! Printing a config the way `tsc --showConfig` does.
+
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::fingerprint::normalize as normalize_case;
+use crate::fs::normalize;
+use crate::loader::PATH_OPTIONS;
+
+/// The top-level fields that `tsc --showConfig` prints, in the order it prints them.
+const SHOWN_FIELDS: &[&str] = &[
+    "compilerOptions",
+    "watchOptions",
+    "references",
+    "files",
+    "include",
+    "exclude",
+    "compileOnSave",
+];
+
+/// Option values that tsc prints under another name, because it prints the first name it knows
+/// for a value.
+const ALIASES: &[(&str, &str, &str)] = &[
+    ("lib", "es2015", "es6"),
+    ("lib", "es2016", "es7"),
+    ("module", "es2015", "es6"),
+    ("moduleResolution", "node", "node10"),
+    ("target", "es2015", "es6"),
+];
+
+/// Converts `raw`, the JSON of a config with everything it extends merged in, into the JSON that
+/// `tsc --showConfig` prints for it.
+pub(crate) fn show_config(raw: &Value) -> Value {
+    let mut shown = Map::new();
+    for &field in SHOWN_FIELDS {
+        let value = match raw.get(field) {
+            Some(value) => value,
+            None => continue,
+        };
+        let value = match (field, value) {
+            ("compilerOptions", Value::Object(options)) => show_compiler_options(options),
+            ("files", Value::Array(files)) if files.is_empty() => continue,
+            ("include", Value::Array(include)) if include == &[Value::from("**/*")] => continue,
+            ("compileOnSave", Value::Bool(false)) => continue,
+            _ => value.clone(),
+        };
+        shown.insert(field.to_string(), value);
+    }
+    Value::Object(shown)
+}
+
+fn show_compiler_options(options: &Map<String, Value>) -> Value {
+    let mut shown = Map::new();
+    for (key, value) in options {
+        let mut value = normalize_case(value, &format!("compilerOptions.{}", key));
+        if PATH_OPTIONS.contains(&key.as_str()) {
+            show_path(&mut value);
+        }
+        rename_alias(key, &mut value);
+        shown.insert(key.clone(), value);
+    }
+    Value::Object(shown)
+}
+
+/// Writes a relative path, or each path in an array, as tsc does: normalized, with forward slashes,
+/// and starting with `./` or `../`.
+fn show_path(value: &mut Value) {
+    match value {
+        Value::Array(paths) => paths.iter_mut().for_each(show_path),
+        Value::String(path)
+            if !path.starts_with("${configDir}") && !Path::new(path).is_absolute() =>
+        {
+            let normalized = normalize(Path::new(path));
+            let normalized = normalized.to_string_lossy().replace('\\', "/");
+            *path = if normalized == ".." || normalized.starts_with("../") {
+                normalized
+            } else {
+                format!("./{}", normalized)
+            };
+        }
+        _ => {}
+    }
+}
+
+fn rename_alias(option: &str, value: &mut Value) {
+    match value {
+        Value::Array(values) => values.iter_mut().for_each(|v| rename_alias(option, v)),
+        Value::String(name) => {
+            let alias = ALIASES
+                .iter()
+                .find(|(o, from, _)| *o == option && from == name);
+            if let Some((_, _, to)) = alias {
+                *name = to.to_string();
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn shows_config_like_tsc() {
+        let raw = json!({
+            "extends": "./tsconfig.base.json",
+            "compilerOptions": {
+                "target": "ES2015",
+                "lib": ["ES2016", "DOM"],
+                "moduleResolution": "Node",
+                "outDir": "dist/",
+                "rootDir": ".",
+                "typeRoots": ["../types", "./node_modules/@types"],
+                "declarationDir": "${configDir}/types",
+                "strict": true,
+            },
+            "include": ["**/*"],
+            "exclude": ["dist"],
+            "files": [],
+            "compileOnSave": false,
+            "ts-node": {"transpileOnly": true},
+        });
+
+        assert_eq!(
+            show_config(&raw),
+            json!({
+                "compilerOptions": {
+                    "target": "es6",
+                    "lib": ["es7", "dom"],
+                    "moduleResolution": "node10",
+                    "outDir": "./dist",
+                    "rootDir": "./",
+                    "typeRoots": ["../types", "./node_modules/@types"],
+                    "declarationDir": "${configDir}/types",
+                    "strict": true,
+                },
+                "exclude": ["dist"],
+            })
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Solution-style configs, which exist only to reference the projects that `tsc -b` builds.
+
+use std::path::{Path, PathBuf};
+
+use crate::{ConfigLoader, Result, TsConfig};
+
+/// A root config and the projects it references, as `tsc -b` sees them.
+///
+/// Usually the root is a solution-style config, which sets `"files": []` so that it contains no
+/// files of its own, and only lists `references`. See [TsConfig::is_solution].
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::Solution;
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/solution/tsconfig.json");
+/// let solution = Solution::load(&path).unwrap();
+///
+/// assert!(solution.config.is_solution());
+/// assert_eq!(solution.projects.len(), 2);
+/// assert!(solution.projects[0].path.ends_with("app/tsconfig.json"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Solution {
+    /// The path of the root config.
+    pub path: PathBuf,
+    /// The root config.
+    pub config: TsConfig,
+    /// The projects that the root config references, in the order they are listed.
+    pub projects: Vec<Project>,
+}
+
+/// A config loaded by following a project reference.
+#[derive(Debug, Clone)]
+pub struct Project {
+    /// The path of the config file.
+    pub path: PathBuf,
+    /// The config, with everything it extends merged in.
+    pub config: TsConfig,
+}
+
+impl Solution {
+    /// Loads the config at `path`, and every project that it references.
+    pub fn load<P: AsRef<Path>>(path: &P) -> Result<Solution> {
+        Solution::load_with(&ConfigLoader::new(), path)
+    }
+
+    /// Loads the config at `path`, and every project that it references, with `loader`.
+    pub fn load_with<P: AsRef<Path>>(loader: &ConfigLoader, path: &P) -> Result<Solution> {
+        let path = path.as_ref().to_path_buf();
+        let config = loader.load(&path)?;
+        let projects = reference_paths(&path, &config)
+            .into_iter()
+            .map(|path| {
+                let config = loader.load(&path)?;
+                Ok(Project { path, config })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Solution {
+            path,
+            config,
+            projects,
+        })
+    }
+}
+
+/// Returns the paths of the configs referenced by `config`, which was loaded from `path`.
+pub(crate) fn reference_paths(path: &Path, config: &TsConfig) -> Vec<PathBuf> {
+    config.resolve_references(path.parent().unwrap_or_else(|| Path::new("")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryFileSystem;
+
+    #[test]
+    fn detects_solution_configs() {
+        let solution = |json| TsConfig::parse_str(json).unwrap().is_solution();
+        assert!(solution(
+            r#"{"files": [], "references": [{"path": "./app"}]}"#
+        ));
+        assert!(!solution(r#"{"references": [{"path": "./app"}]}"#));
+        assert!(!solution(r#"{"files": [], "references": []}"#));
+        assert!(!solution(
+            r#"{"files": [], "include": ["src"], "references": [{"path": "./app"}]}"#
+        ));
+    }
+
+    #[test]
+    fn loads_referenced_projects() {
+        let mut fs = MemoryFileSystem::new();
+        fs.insert(
+            "/repo/tsconfig.json",
+            r#"{"files": [], "references": [{"path": "./app"}, {"path": "lib/tsconfig.build.json"}]}"#,
+        );
+        fs.insert(
+            "/repo/tsconfig.base.json",
+            r#"{"compilerOptions": {"composite": true}}"#,
+        );
+        fs.insert(
+            "/repo/app/tsconfig.json",
+            r#"{"extends": "../tsconfig.base.json"}"#,
+        );
+        fs.insert("/repo/lib/tsconfig.build.json", "{}");
+
+        let loader = ConfigLoader::new().with_file_system(fs);
+        let solution = Solution::load_with(&loader, &"/repo/tsconfig.json").unwrap();
+        let paths: Vec<_> = solution.projects.iter().map(|p| p.path.as_path()).collect();
+        assert_eq!(
+            paths,
+            [
+                Path::new("/repo/app/tsconfig.json"),
+                Path::new("/repo/lib/tsconfig.build.json")
+            ]
+        );
+        let app = solution.projects[0].config.compiler_options.as_ref();
+        assert_eq!(app.unwrap().composite, Some(true));
+    }
+}
@@ -0,0 +1,159 @@
+//! Source locations for fields in a parsed TSConfig.
+
+use std::collections::HashMap;
+
+use crate::jsonc::{self, Token, TokenKind};
+
+/// The location of a field in the source text of a TSConfig file.
+///
+/// A span covers a whole object member, from the opening quote of its key to the end of its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Byte offset of the start of the member.
+    pub start: usize,
+    /// Byte offset just past the end of the member.
+    pub end: usize,
+    /// 1-based line on which the member starts.
+    pub line: usize,
+    /// 1-based column, counted in characters, at which the member starts.
+    pub column: usize,
+}
+
+impl Span {
+    pub(crate) fn new(src: &str, start: usize, end: usize) -> Span {
+        let (line, column) = line_column(src, start);
+        Span {
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+}
+
+/// Converts a byte offset into a 1-based line and column.
+pub(crate) fn line_column(src: &str, offset: usize) -> (usize, usize) {
+    let before = &src[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// Records the span of every object member in a JSONC document, keyed by its dotted path
+/// (for example `compilerOptions.strict`).
+///
+/// Collection stops silently at the first syntax error, or at values nested more deeply than the
+/// recovering parser reads; the spans found up to that point are kept.
+pub(crate) fn collect(src: &str) -> HashMap<String, Span> {
+    let tokens: Vec<Token> = jsonc::tokenize(src)
+        .into_iter()
+        .filter(|t| !t.kind.is_trivia())
+        .collect();
+    let mut collector = Collector {
+        src,
+        tokens,
+        pos: 0,
+        spans: HashMap::new(),
+    };
+    collector.value("", 0);
+    collector.spans
+}
+
+struct Collector<'a> {
+    src: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    spans: HashMap<String, Span>,
+}
+
+impl<'a> Collector<'a> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<Token> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    /// Parses a value, returning the byte offset at which it ends.
+    fn value(&mut self, path: &str, depth: usize) -> Option<usize> {
+        let token = self.bump()?;
+        match token.kind {
+            TokenKind::OpenBrace | TokenKind::OpenBracket if depth >= jsonc::MAX_DEPTH => None,
+            TokenKind::OpenBrace => self.object(path, depth + 1),
+            TokenKind::OpenBracket => self.array(path, depth + 1),
+            TokenKind::String | TokenKind::Number | TokenKind::Word => Some(token.end),
+            _ => None,
+        }
+    }
+
+    fn object(&mut self, path: &str, depth: usize) -> Option<usize> {
+        loop {
+            let key = self.bump()?;
+            match key.kind {
+                TokenKind::CloseBrace => return Some(key.end),
+                TokenKind::Comma => continue,
+                TokenKind::String => {}
+                _ => return None,
+            }
+            let name: String = serde_json::from_str(&self.src[key.start..key.end]).ok()?;
+            let member_path = if path.is_empty() {
+                name
+            } else {
+                format!("{}.{}", path, name)
+            };
+
+            if self.bump()?.kind != TokenKind::Colon {
+                return None;
+            }
+            let end = self.value(&member_path, depth)?;
+            self.spans
+                .insert(member_path, Span::new(self.src, key.start, end));
+        }
+    }
+
+    fn array(&mut self, path: &str, depth: usize) -> Option<usize> {
+        let mut index = 0;
+        loop {
+            match self.peek()?.kind {
+                TokenKind::CloseBracket => return Some(self.bump()?.end),
+                TokenKind::Comma => {
+                    self.bump();
+                }
+                _ => {
+                    self.value(&format!("{}.{}", path, index), depth)?;
+                    index += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn collects_nested_spans() {
+        let src = "{\n  // comment\n  \"compilerOptions\": {\n    \"strict\": true,\n  },\n  \"include\": [\"src\"]\n}";
+        let spans = collect(src);
+
+        let strict = spans["compilerOptions.strict"];
+        assert_eq!(&src[strict.start..strict.end], "\"strict\": true");
+        assert_eq!((strict.line, strict.column), (4, 5));
+
+        let include = spans["include"];
+        assert_eq!(&src[include.start..include.end], "\"include\": [\"src\"]");
+        assert_eq!((include.line, include.column), (6, 3));
+    }
+
+    #[test]
+    fn keeps_spans_before_syntax_error() {
+        let spans = collect(r#"{"a": 1, "b": }"#);
+        assert!(spans.contains_key("a"));
+        assert!(!spans.contains_key("b"));
+    }
+}
@@ -0,0 +1,46 @@
+//! Suggesting the name that a misspelt one was probably meant to be.
+
+/// Returns the name in `names` closest to `value`, ignoring case, if any is close enough to be a
+/// likely misspelling.
+pub(crate) fn closest<'a>(value: &str, names: &[&'a str]) -> Option<&'a str> {
+    let value = value.to_lowercase();
+    let max_distance = (value.chars().count() / 3).max(1);
+    names
+        .iter()
+        .map(|name| (distance(&value, &name.to_lowercase()), *name))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+/// The Levenshtein distance between `a` and `b`: how many characters must be inserted, removed or
+/// replaced to turn one into the other.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let replace = previous[j] + usize::from(a != *b);
+            current.push(replace.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn suggests_close_names() {
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(
+            closest("ES20017", &["ES2016", "ES2017", "ESNext"]),
+            Some("ES2017")
+        );
+        assert_eq!(closest("esnxt", &["ES2017", "ESNext"]), Some("ESNext"));
+        assert_eq!(closest("webpack", &["ES2017", "ESNext"]), None);
+    }
+}
@@ -0,0 +1,289 @@
+//! Rewriting deprecated settings to the ones that replace them.
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::deprecation::PREPEND;
+use crate::order;
+use crate::{compiler_option, Module, TsConfig, TsVersion};
+
+/// The release that renamed `moduleResolution: node` to `node10`, and added `bundler`.
+const NODE10: TsVersion = TsVersion::new(5, 0);
+
+/// The replacement for `importsNotUsedAsValues` and `preserveValueImports`.
+const VERBATIM_MODULE_SYNTAX: &str = "verbatimModuleSyntax";
+
+/// A setting that [TsConfig::upgrade](crate::TsConfig::upgrade) rewrote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    /// Dotted path of the setting, such as `compilerOptions.out`.
+    pub field: String,
+    /// The value it was set to.
+    pub from: Value,
+    /// Dotted path and value of the setting that replaces it, or `None` if it was removed without
+    /// a replacement.
+    pub to: Option<(String, Value)>,
+    /// The release that deprecated or removed the setting.
+    pub since: TsVersion,
+}
+
+impl Migration {
+    fn new(field: String, from: Value, to: Option<(String, Value)>, since: TsVersion) -> Migration {
+        Migration {
+            field,
+            from,
+            to,
+            since,
+        }
+    }
+}
+
+impl fmt::Display for Migration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.to {
+            Some((field, value)) if *field == self.field => write!(
+                f,
+                "Changed '{}' from {} to {}",
+                self.field, self.from, value
+            )?,
+            Some((field, value)) => write!(
+                f,
+                "Replaced '{}': {} with '{}': {}",
+                self.field, self.from, field, value
+            )?,
+            None => write!(f, "Removed '{}': {}", self.field, self.from)?,
+        }
+        write!(f, " (TypeScript {}).", self.since)
+    }
+}
+
+/// Returns the JSON of `config` with its deprecated settings rewritten for TypeScript `to`, and the
+/// changes that were made, in the order they were made.
+pub(crate) fn upgrade(config: &TsConfig, to: TsVersion) -> (Value, Vec<Migration>) {
+    let mut raw = config.raw().into_owned();
+    let mut migrations = Vec::new();
+
+    if let Some(Value::Object(options)) = raw.get_mut("compilerOptions") {
+        upgrade_imports(options, to, &mut migrations);
+        let written = options.clone();
+        let order = config.order().key("compilerOptions");
+        for (name, value) in order::members(order, &written) {
+            let (name, value) = (name.clone(), value.clone());
+            let deprecation = compiler_option(&name)
+                .and_then(|option| option.deprecation)
+                .filter(|_| !value.is_null());
+            let deprecation = match deprecation {
+                Some(deprecation) => deprecation,
+                None => continue,
+            };
+            let field = format!("compilerOptions.{}", name);
+            match deprecation.replacement {
+                Some(replacement) if to >= deprecation.since => {
+                    replace(options, &name, replacement, value.clone());
+                    let to = (
+                        format!("compilerOptions.{}", replacement),
+                        options[replacement].clone(),
+                    );
+                    let migration = Migration::new(field, value, Some(to), deprecation.since);
+                    migrations.push(migration);
+                }
+                None if deprecation.removed.is_some_and(|removed| to >= removed) => {
+                    options.retain(|key, _| *key != name);
+                    let since = deprecation.removed.unwrap_or(deprecation.since);
+                    migrations.push(Migration::new(field, value, None, since));
+                }
+                _ => {}
+            }
+        }
+        upgrade_module_resolution(config, options, to, &mut migrations);
+    }
+
+    let prepend_removed = PREPEND.removed.is_some_and(|removed| to >= removed);
+    if let Some(Value::Array(references)) = raw.get_mut("references") {
+        for (i, reference) in references.iter_mut().enumerate() {
+            let prepend = match reference {
+                Value::Object(reference) if prepend_removed => {
+                    let prepend = reference.get("prepend").cloned();
+                    reference.retain(|key, _| key != "prepend");
+                    prepend
+                }
+                _ => None,
+            };
+            if let Some(prepend) = prepend.filter(|value| !value.is_null()) {
+                let field = format!("references.{}.prepend", i);
+                let since = PREPEND.removed.unwrap_or(PREPEND.since);
+                migrations.push(Migration::new(field, prepend, None, since));
+            }
+        }
+    }
+    (raw, migrations)
+}
+
+/// Replaces `importsNotUsedAsValues` and `preserveValueImports` with `verbatimModuleSyntax`, which
+/// is only set if they kept imports that tsc would otherwise drop.
+fn upgrade_imports(
+    options: &mut Map<String, Value>,
+    to: TsVersion,
+    migrations: &mut Vec<Migration>,
+) {
+    let names = ["importsNotUsedAsValues", "preserveValueImports"];
+    let since = match compiler_option(names[0]).and_then(|option| option.deprecation) {
+        Some(deprecation) if to >= deprecation.since => deprecation.since,
+        _ => return,
+    };
+    let set: Vec<(&str, Value)> = names
+        .iter()
+        .filter_map(|name| Some((*name, options.get(*name)?.clone())))
+        .filter(|(_, value)| !value.is_null())
+        .collect();
+    let keeps_imports = set.iter().any(|(_, value)| match value {
+        Value::String(value) => {
+            value.eq_ignore_ascii_case("preserve") || value.eq_ignore_ascii_case("error")
+        }
+        value => *value == Value::Bool(true),
+    });
+
+    for (name, _) in &set {
+        if keeps_imports {
+            replace(options, name, VERBATIM_MODULE_SYNTAX, Value::Bool(true));
+        } else {
+            options.retain(|key, _| key != name);
+        }
+    }
+
+    let to = options
+        .get(VERBATIM_MODULE_SYNTAX)
+        .filter(|_| keeps_imports)
+        .map(|value| {
+            let field = format!("compilerOptions.{}", VERBATIM_MODULE_SYNTAX);
+            (field, value.clone())
+        });
+    for (name, value) in set {
+        let field = format!("compilerOptions.{}", name);
+        migrations.push(Migration::new(field, value, to.clone(), since));
+    }
+}
+
+/// Renames `moduleResolution: node` to `node10`, or to `bundler` when the config emits ES modules,
+/// which is the usual sign of a project built by a bundler.
+fn upgrade_module_resolution(
+    config: &TsConfig,
+    options: &mut Map<String, Value>,
+    to: TsVersion,
+    migrations: &mut Vec<Migration>,
+) {
+    let value = match options.get("moduleResolution") {
+        Some(Value::String(value)) if value.eq_ignore_ascii_case("node") => value.clone(),
+        _ => return,
+    };
+    if to < NODE10 {
+        return;
+    }
+    let module = config
+        .effective()
+        .compiler_options
+        .and_then(|options| options.module);
+    let resolution = match module {
+        Some(
+            Module::Es2015 | Module::Es2020 | Module::Es2022 | Module::EsNext | Module::Preserve,
+        ) => "bundler",
+        _ => "node10",
+    };
+    options.insert("moduleResolution".to_string(), resolution.into());
+    let field = "compilerOptions.moduleResolution".to_string();
+    let to = Some((field.clone(), resolution.into()));
+    migrations.push(Migration::new(field, value.into(), to, NODE10));
+}
+
+/// Replaces the option `from` with `to`, in the same place, or removes it if `to` is already set, in
+/// which case that value is kept.
+fn replace(options: &mut Map<String, Value>, from: &str, to: &str, value: Value) {
+    if options.contains_key(to) {
+        options.retain(|key, _| key != from);
+        return;
+    }
+    let mut value = Some(value);
+    *options = std::mem::take(options)
+        .into_iter()
+        .map(|(key, old)| {
+            if key == from {
+                (to.to_string(), value.take().unwrap_or(old))
+            } else {
+                (key, old)
+            }
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn upgrade(json: &str, to: TsVersion) -> (Value, Vec<String>) {
+        let mut config = TsConfig::parse_str(json).unwrap();
+        let migrations = config.upgrade(to);
+        let fields = migrations.into_iter().map(|m| m.field).collect();
+        (config.raw().into_owned(), fields)
+    }
+
+    #[test]
+    fn upgrades_deprecated_options() {
+        let json = r#"{
+            "compilerOptions": {
+                "out": "out.js",
+                "charset": "utf8",
+                "moduleResolution": "node",
+                "preserveValueImports": false,
+                "strict": true
+            },
+            "references": [{"path": "../a", "prepend": true, "circular": true}]
+        }"#;
+
+        let (raw, fields) = upgrade(json, TsVersion::new(4, 9));
+        assert_eq!(fields, ["compilerOptions.out"]);
+        assert_eq!(raw["compilerOptions"]["outFile"], "out.js");
+
+        let (raw, fields) = upgrade(json, TsVersion::new(5, 5));
+        assert_eq!(
+            raw,
+            json!({
+                "compilerOptions": {"outFile": "out.js", "moduleResolution": "node10", "strict": true},
+                "references": [{"path": "../a", "circular": true}]
+            })
+        );
+        assert_eq!(
+            fields,
+            [
+                "compilerOptions.preserveValueImports",
+                "compilerOptions.out",
+                "compilerOptions.charset",
+                "compilerOptions.moduleResolution",
+                "references.0.prepend"
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_the_replacement_that_is_already_set() {
+        let mut config = TsConfig::parse_str(
+            r#"{"compilerOptions": {"out": "a.js", "outFile": "b.js", "verbatimModuleSyntax": false, "preserveValueImports": true}}"#,
+        )
+        .unwrap();
+        let migrations = config.upgrade(TsVersion::new(5, 0));
+        assert_eq!(
+            *config.raw(),
+            json!({"compilerOptions": {"outFile": "b.js", "verbatimModuleSyntax": false}})
+        );
+        assert_eq!(
+            migrations[1].to,
+            Some(("compilerOptions.outFile".to_string(), json!("b.js")))
+        );
+        assert_eq!(
+            migrations[1].to_string(),
+            r#"Replaced 'compilerOptions.out': "a.js" with 'compilerOptions.outFile': "b.js" (TypeScript 1.6)."#
+        );
+        assert!(config.deprecations().is_empty());
+    }
+}
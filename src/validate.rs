@@ -0,0 +1,156 @@
+//! Checking a config against the options a particular TypeScript release accepts.
+
+use crate::order;
+use crate::{compiler_option, ConfigError, Diagnostic, Result, TsConfig, TsVersion};
+
+/// Checks that a config only uses compiler options that exist in a given TypeScript release.
+///
+/// Options that were added after the release, such as `verbatimModuleSyntax` on TypeScript 4.9, and
+/// options that the release no longer accepts, such as `charset` on TypeScript 5.5, are reported.
+/// Options this crate doesn't know are left alone.
+///
+/// ## Example
+/// ```
+/// use tsconfig::{TsConfig, Validator};
+///
+/// let json = r#"{"compilerOptions": {"verbatimModuleSyntax": true, "strict": true}}"#;
+/// let config = TsConfig::parse_str_with_spans(json).unwrap();
+///
+/// let diagnostics = Validator::for_version("4.9").unwrap().validate(&config);
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].field, "compilerOptions.verbatimModuleSyntax");
+/// assert!(diagnostics[0].span.is_some());
+///
+/// assert!(Validator::for_version("5.0").unwrap().validate(&config).is_empty());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Validator {
+    version: TsVersion,
+}
+
+impl Validator {
+    /// Creates a validator for `version`.
+    pub fn new(version: TsVersion) -> Validator {
+        Validator { version }
+    }
+
+    /// Creates a validator for the release given as a string, such as `4.9` or `5.4.5`.
+    ///
+    /// Returns [ConfigError::InvalidVersion] if `version` doesn't start with a major and minor
+    /// version.
+    pub fn for_version(version: &str) -> Result<Validator> {
+        Ok(Validator::new(version.parse()?))
+    }
+
+    /// Returns [ConfigError::Validation], with the diagnostics from [Validator::validate], if the
+    /// release doesn't accept every compiler option in `config`.
+    pub fn check(&self, config: &TsConfig) -> Result<()> {
+        match self.validate(config) {
+            diagnostics if diagnostics.is_empty() => Ok(()),
+            diagnostics => Err(ConfigError::Validation(diagnostics)),
+        }
+    }
+
+    /// Returns a diagnostic for each compiler option in `config` that the release doesn't accept.
+    pub fn validate(&self, config: &TsConfig) -> Vec<Diagnostic> {
+        let raw = config.raw();
+        let options = match raw.get("compilerOptions") {
+            Some(serde_json::Value::Object(options)) => options,
+            _ => return Vec::new(),
+        };
+        let order = config.order().key("compilerOptions");
+        let mut diagnostics = Vec::new();
+        for (name, _) in order::members(order, options) {
+            let option = match compiler_option(name) {
+                Some(option) => option,
+                None => continue,
+            };
+            let removal = option
+                .deprecation
+                .and_then(|deprecation| Some((deprecation.removed?, deprecation.replacement)));
+            let message = match removal {
+                _ if option.since > self.version => format!(
+                    "Option '{}' was added in TypeScript {}, after {}.",
+                    name, option.since, self.version
+                ),
+                Some((removed, replacement)) if removed <= self.version => match replacement {
+                    Some(replacement) => format!(
+                        "Option '{}' was removed in TypeScript {}. Use '{}' instead.",
+                        name, removed, replacement
+                    ),
+                    None => format!("Option '{}' was removed in TypeScript {}.", name, removed),
+                },
+                _ => continue,
+            };
+            let field = format!("compilerOptions.{}", name);
+            diagnostics.push(Diagnostic {
+                span: config.span_of(&field),
+                field,
+                message,
+            });
+        }
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_versions() {
+        assert_eq!("4.9".parse::<TsVersion>().unwrap(), TsVersion::new(4, 9));
+        assert_eq!("5.4.5".parse::<TsVersion>().unwrap(), TsVersion::new(5, 4));
+        assert_eq!(
+            "5.5-beta".parse::<TsVersion>().unwrap(),
+            TsVersion::new(5, 5)
+        );
+        assert!(matches!(
+            Validator::for_version("five"),
+            Err(ConfigError::InvalidVersion(version)) if version == "five"
+        ));
+    }
+
+    #[test]
+    fn reports_removed_options() {
+        let json = r#"{"compilerOptions": {"out": "bundle.js", "keyofStringsOnly": true, "futureOption": 1}}"#;
+        let config = TsConfig::parse_str(json).unwrap();
+
+        assert!(Validator::for_version("5.4")
+            .unwrap()
+            .validate(&config)
+            .is_empty());
+        let messages: Vec<_> = Validator::for_version("5.5")
+            .unwrap()
+            .validate(&config)
+            .into_iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "compilerOptions.out: Option 'out' was removed in TypeScript 5.5. Use 'outFile' instead.",
+                "compilerOptions.keyofStringsOnly: Option 'keyofStringsOnly' was removed in TypeScript 5.5.",
+            ]
+        );
+    }
+
+    #[test]
+    fn check_fails_with_the_diagnostics() {
+        let config = TsConfig::parse_str(r#"{"compilerOptions": {"charset": "utf8"}}"#).unwrap();
+        assert!(Validator::for_version("5.0")
+            .unwrap()
+            .check(&config)
+            .is_ok());
+
+        let err = Validator::for_version("5.5")
+            .unwrap()
+            .check(&config)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration: compilerOptions.charset: Option 'charset' was removed in TypeScript 5.5."
+        );
+        assert!(matches!(err, ConfigError::Validation(diagnostics) if diagnostics.len() == 1));
+    }
+}
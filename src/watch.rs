@@ -0,0 +1,212 @@
+//! Reloading a config whenever it, or a file it depends on, changes.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::solution::reference_paths;
+use crate::{ConfigLoader, Result, TsConfig};
+
+/// How long to wait for more changes after one is seen, so that the burst of events from saving a
+/// file only causes one reload.
+const SETTLE_TIME: Duration = Duration::from_millis(50);
+
+/// Watches a config file, everything it extends, and the projects it references, and reloads the
+/// config whenever any of them changes.
+///
+/// The files are watched on a background thread, which calls the callback with the reloaded config,
+/// or with the error from loading it. Since `extends` and `references` may change too, the set of
+/// watched files is worked out again on every reload. The callback is only called for changes, not
+/// for the initial config. Watching stops when the `ConfigWatcher` is dropped.
+///
+/// It is only available with the `watch` feature.
+///
+/// ## Example
+/// ```no_run
+/// use tsconfig::ConfigWatcher;
+///
+/// let watcher = ConfigWatcher::new(&"tsconfig.json", |config| match config {
+///     Ok(config) => println!("target is now {:?}", config.compiler_options.unwrap().target),
+///     Err(err) => eprintln!("{}", err),
+/// })
+/// .unwrap();
+/// ```
+pub struct ConfigWatcher {
+    messages: Sender<Message>,
+}
+
+enum Message {
+    Changed(notify::Result<Event>),
+    Stop,
+}
+
+impl ConfigWatcher {
+    /// Starts watching the config at `path`, loading it with a default [ConfigLoader].
+    pub fn new<P, F>(path: &P, on_change: F) -> Result<ConfigWatcher>
+    where
+        P: AsRef<Path>,
+        F: FnMut(Result<TsConfig>) + Send + 'static,
+    {
+        ConfigWatcher::with_loader(ConfigLoader::new(), path, on_change)
+    }
+
+    /// Starts watching the config at `path`, loading it with `loader`, which should read from the
+    /// real filesystem.
+    pub fn with_loader<P, F>(loader: ConfigLoader, path: &P, on_change: F) -> Result<ConfigWatcher>
+    where
+        P: AsRef<Path>,
+        F: FnMut(Result<TsConfig>) + Send + 'static,
+    {
+        let path = path.as_ref().to_path_buf();
+        let (messages, receiver) = mpsc::channel();
+        let events = messages.clone();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = events.send(Message::Changed(event));
+        })?;
+        let mut watching = Watching::default();
+        let (_, files) = load(&loader, &path);
+        watching.update(&mut watcher, files)?;
+
+        thread::spawn(move || {
+            run(watcher, watching, receiver, &loader, &path, on_change);
+        });
+        Ok(ConfigWatcher { messages })
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        let _ = self.messages.send(Message::Stop);
+    }
+}
+
+fn run<F: FnMut(Result<TsConfig>)>(
+    mut watcher: RecommendedWatcher,
+    mut watching: Watching,
+    receiver: Receiver<Message>,
+    loader: &ConfigLoader,
+    path: &Path,
+    mut on_change: F,
+) {
+    while let Ok(Message::Changed(event)) = receiver.recv() {
+        let mut relevant = watching.is_relevant(&event);
+        loop {
+            match receiver.recv_timeout(SETTLE_TIME) {
+                Ok(Message::Changed(event)) => relevant |= watching.is_relevant(&event),
+                Ok(Message::Stop) | Err(RecvTimeoutError::Disconnected) => return,
+                Err(RecvTimeoutError::Timeout) => break,
+            }
+        }
+        if !relevant {
+            continue;
+        }
+
+        let (config, files) = load(loader, path);
+        if let Err(err) = watching.update(&mut watcher, files) {
+            on_change(Err(err.into()));
+        }
+        on_change(config);
+    }
+}
+
+/// Loads the config at `path`, returning it along with the files it depends on: its own file, the
+/// files in its `extends` chain, and those of the projects it references.
+fn load(loader: &ConfigLoader, path: &Path) -> (Result<TsConfig>, BTreeSet<PathBuf>) {
+    let mut files = BTreeSet::new();
+    files.insert(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+    let config = loader
+        .load_value_and_files(&path)
+        .and_then(|(value, chain)| {
+            files.extend(chain);
+            TsConfig::from_value(value)
+        });
+    if let Ok(config) = &config {
+        for reference in reference_paths(path, config) {
+            if let Ok((_, chain)) = loader.load_value_and_files(&reference) {
+                files.extend(chain);
+            }
+            files.insert(reference.canonicalize().unwrap_or(reference));
+        }
+    }
+    // Bundled presets have no directory to watch.
+    files.retain(|file| file.parent().is_some_and(Path::is_dir));
+    (config, files)
+}
+
+/// The files being watched, and the directories watched to see them.
+///
+/// Directories are watched rather than files, since editors often save a file by replacing it,
+/// which would end a watch on the file itself.
+#[derive(Default)]
+struct Watching {
+    files: BTreeSet<PathBuf>,
+    dirs: BTreeSet<PathBuf>,
+}
+
+impl Watching {
+    fn update(
+        &mut self,
+        watcher: &mut impl Watcher,
+        files: BTreeSet<PathBuf>,
+    ) -> notify::Result<()> {
+        let dirs: BTreeSet<_> = files
+            .iter()
+            .filter_map(|file| file.parent().map(Path::to_path_buf))
+            .collect();
+        for dir in self.dirs.difference(&dirs) {
+            let _ = watcher.unwatch(dir);
+        }
+        for dir in dirs.difference(&self.dirs) {
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        }
+        self.files = files;
+        self.dirs = dirs;
+        Ok(())
+    }
+
+    fn is_relevant(&self, event: &notify::Result<Event>) -> bool {
+        match event {
+            Ok(event) => {
+                !matches!(event.kind, EventKind::Access(_))
+                    && event.paths.iter().any(|path| self.files.contains(path))
+            }
+            // Reload, in case the error hid a change.
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reloads_when_a_base_changes() {
+        let root = std::env::temp_dir().join(format!("tsconfig-watch-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("base")).unwrap();
+        let root = root.canonicalize().unwrap();
+        let base = root.join("base/tsconfig.json");
+        std::fs::write(&base, r#"{"compilerOptions": {"strict": false}}"#).unwrap();
+        std::fs::write(
+            root.join("tsconfig.json"),
+            r#"{"extends": "./base/tsconfig.json"}"#,
+        )
+        .unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        let watcher = ConfigWatcher::new(&root.join("tsconfig.json"), move |config| {
+            let _ = sender.send(config.map(|c| c.compiler_options.unwrap().strict));
+        })
+        .unwrap();
+        std::fs::write(&base, r#"{"compilerOptions": {"strict": true}}"#).unwrap();
+
+        let strict = receiver.recv_timeout(Duration::from_secs(10));
+        drop(watcher);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(strict.unwrap().unwrap(), Some(true));
+    }
+}
@@ -0,0 +1,123 @@
+//! Finding every config file in a workspace.
+
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+
+use crate::discover::load_config;
+use crate::{ConfigCache, ConfigLoader, Result, TsConfig};
+
+/// A config file found by [discover_workspace].
+#[derive(Debug)]
+pub struct WorkspaceConfig {
+    /// The path of the file, under the root that was scanned.
+    pub path: PathBuf,
+    /// The config, with everything it extends merged in, or the error from loading it.
+    pub config: Result<TsConfig>,
+}
+
+/// Finds every tsconfig-like file under `root`, and loads each one.
+///
+/// Any file named `tsconfig*.json` or `jsconfig*.json` is included, such as tsconfig.build.json.
+/// Files ignored by a .gitignore are skipped, as are `node_modules` directories and hidden
+/// directories. A config that fails to load doesn't stop the scan: its error is kept in
+/// [WorkspaceConfig::config]. The configs are sorted by path, and bases shared between them are only
+/// read once.
+///
+/// It is only available with the `workspace` feature.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::discover_workspace;
+///
+/// let root = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test/a");
+/// let configs = discover_workspace(&root).unwrap();
+/// assert!(configs
+///     .iter()
+///     .any(|found| found.path.ends_with("tsconfig.inherits_again.json")));
+/// ```
+pub fn discover_workspace<P: AsRef<Path>>(root: &P) -> Result<Vec<WorkspaceConfig>> {
+    let walk = WalkBuilder::new(root)
+        .require_git(false)
+        .filter_entry(|entry| entry.file_name() != "node_modules")
+        .build();
+    let mut paths = Vec::new();
+    for entry in walk {
+        let entry = entry?;
+        let is_file = entry.file_type().is_some_and(|kind| kind.is_file());
+        if is_file && is_config_name(entry.file_name().to_str().unwrap_or("")) {
+            paths.push(entry.into_path());
+        }
+    }
+    paths.sort();
+
+    let loader = ConfigLoader::new().with_cache(ConfigCache::new());
+    Ok(paths
+        .into_iter()
+        .map(|path| {
+            let config = load_config(&loader, &path);
+            WorkspaceConfig { path, config }
+        })
+        .collect())
+}
+
+fn is_config_name(name: &str) -> bool {
+    (name.starts_with("tsconfig") || name.starts_with("jsconfig")) && name.ends_with(".json")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn skips_ignored_files() {
+        let root = std::env::temp_dir().join(format!("tsconfig-workspace-{}", std::process::id()));
+        let files = [
+            (".gitignore", "dist\n"),
+            (
+                "tsconfig.base.json",
+                r#"{"compilerOptions": {"strict": true}}"#,
+            ),
+            (
+                "packages/app/tsconfig.json",
+                r#"{"extends": "../../tsconfig.base.json"}"#,
+            ),
+            ("packages/app/dist/tsconfig.json", "{}"),
+            ("packages/app/package.json", "{}"),
+            ("node_modules/base/tsconfig.json", "{}"),
+            ("scripts/jsconfig.json", "{}"),
+            ("broken/tsconfig.json", "{"),
+        ];
+        for (path, contents) in &files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+
+        let configs = discover_workspace(&root).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let paths: Vec<_> = configs
+            .iter()
+            .map(|found| found.path.strip_prefix(&root).unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                Path::new("broken/tsconfig.json"),
+                Path::new("packages/app/tsconfig.json"),
+                Path::new("scripts/jsconfig.json"),
+                Path::new("tsconfig.base.json"),
+            ]
+        );
+        assert!(configs[0].config.is_err());
+        let app = configs[1].config.as_ref().unwrap();
+        assert_eq!(app.compiler_options.as_ref().unwrap().strict, Some(true));
+        let scripts = configs[2].config.as_ref().unwrap();
+        assert_eq!(
+            scripts.compiler_options.as_ref().unwrap().allow_js,
+            Some(true)
+        );
+    }
+}
@@ -0,0 +1,452 @@
+//! Writing configs out as JSON, formatted the way a project prefers.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::order::{self, Order};
+use crate::{compiler_option, Result};
+
+/// The top-level fields in the order the [TSConfig reference](https://www.typescriptlang.org/tsconfig)
+/// documents them.
+const DOCUMENTED_FIELDS: &[&str] = &[
+    "files",
+    "extends",
+    "include",
+    "exclude",
+    "references",
+    "compilerOptions",
+    "watchOptions",
+    "typeAcquisition",
+];
+
+/// The watch options in the order the TSConfig reference documents them.
+const DOCUMENTED_WATCH_OPTIONS: &[&str] = &[
+    "watchFile",
+    "watchDirectory",
+    "fallbackPolling",
+    "synchronousWatchDirectory",
+    "excludeDirectories",
+    "excludeFiles",
+];
+
+/// The type acquisition options in the order the TSConfig reference documents them.
+const DOCUMENTED_TYPE_ACQUISITION: &[&str] = &[
+    "enable",
+    "include",
+    "exclude",
+    "disableFilenameBasedTypeAcquisition",
+];
+
+/// How each level of a written config is indented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// This many spaces per level.
+    Spaces(usize),
+    /// A tab per level.
+    Tab,
+}
+
+/// The order that [WriteOptions] puts the keys of a config in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOrder {
+    /// The order they were written in.
+    AsWritten,
+    /// The order the [TSConfig reference](https://www.typescriptlang.org/tsconfig) documents them
+    /// in, which groups compiler options by category. Keys the reference doesn't document follow
+    /// those it does, in the order they were written in.
+    Documented,
+    /// Alphabetical order.
+    Alphabetical,
+}
+
+/// How [TsConfig::to_string_with](crate::TsConfig::to_string_with) formats a config.
+///
+/// The default indents with two spaces, keeps keys in the order they were written in, and ends
+/// with a newline. Keys are only reordered at the top level and in `compilerOptions`,
+/// `watchOptions` and `typeAcquisition`, not inside values such as `paths`.
+///
+/// Comments can be attached to fields, and are written as `//` lines above them, which makes the
+/// output JSONC, as tsc and editors accept in tsconfig.json. [WriteOptions::with_descriptions]
+/// describes each compiler option the way `tsc --init` does.
+///
+/// ## Example
+/// ```
+/// use tsconfig::{Indent, KeyOrder, TsConfig, WriteOptions};
+///
+/// let config = TsConfig::parse_str(r#"{"include": ["src"], "compilerOptions": {"outDir": "dist", "strict": true}}"#).unwrap();
+/// let options = WriteOptions::new()
+///     .with_indent(Indent::Tab)
+///     .with_key_order(KeyOrder::Documented)
+///     .with_final_newline(false);
+///
+/// assert_eq!(
+///     config.to_string_with(&options).unwrap(),
+///     "{\n\t\"include\": [\n\t\t\"src\"\n\t],\n\t\"compilerOptions\": {\n\t\t\"strict\": true,\n\t\t\"outDir\": \"dist\"\n\t}\n}"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteOptions {
+    indent: Indent,
+    key_order: KeyOrder,
+    final_newline: bool,
+    comments: HashMap<String, String>,
+    descriptions: bool,
+}
+
+impl Default for WriteOptions {
+    fn default() -> WriteOptions {
+        WriteOptions {
+            indent: Indent::Spaces(2),
+            key_order: KeyOrder::AsWritten,
+            final_newline: true,
+            comments: HashMap::new(),
+            descriptions: false,
+        }
+    }
+}
+
+impl WriteOptions {
+    /// Creates the default options.
+    pub fn new() -> WriteOptions {
+        WriteOptions::default()
+    }
+
+    /// Sets how each level is indented.
+    pub fn with_indent(self, indent: Indent) -> WriteOptions {
+        WriteOptions { indent, ..self }
+    }
+
+    /// Sets the order that keys are written in.
+    pub fn with_key_order(self, key_order: KeyOrder) -> WriteOptions {
+        WriteOptions { key_order, ..self }
+    }
+
+    /// Whether to end the output with a newline.
+    pub fn with_final_newline(self, final_newline: bool) -> WriteOptions {
+        WriteOptions {
+            final_newline,
+            ..self
+        }
+    }
+
+    /// Attaches a comment to the field at a dotted path, such as `compilerOptions.strict`, which is
+    /// written above it. A comment of several lines is written as several `//` lines. Comments on
+    /// fields the config doesn't set aren't written.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{TsConfig, WriteOptions};
+    ///
+    /// let config = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true}}"#).unwrap();
+    /// let options = WriteOptions::new()
+    ///     .with_comment("compilerOptions.strict", "Required by our lint rules.");
+    ///
+    /// assert_eq!(
+    ///     config.to_string_with(&options).unwrap(),
+    ///     "{\n  \"compilerOptions\": {\n    // Required by our lint rules.\n    \"strict\": true\n  }\n}\n"
+    /// );
+    /// ```
+    pub fn with_comment(
+        mut self,
+        field: impl Into<String>,
+        comment: impl Into<String>,
+    ) -> WriteOptions {
+        self.comments.insert(field.into(), comment.into());
+        self
+    }
+
+    /// Whether to describe each compiler option above it, as `tsc --init` does, using
+    /// [OptionInfo::description](crate::OptionInfo::description). Comments attached with
+    /// [WriteOptions::with_comment] take the place of the description.
+    pub fn with_descriptions(self, descriptions: bool) -> WriteOptions {
+        WriteOptions {
+            descriptions,
+            ..self
+        }
+    }
+
+    /// The comment to write above the field at `field`, if any.
+    fn comment(&self, field: &str) -> Option<&str> {
+        if let Some(comment) = self.comments.get(field) {
+            return Some(comment);
+        }
+        let option = field
+            .strip_prefix("compilerOptions.")
+            .filter(|name| self.descriptions && !name.contains('.'))?;
+        compiler_option(option).map(|option| option.description())
+    }
+}
+
+/// Writes `raw`, whose keys were written in `order`, as JSON formatted according to `options`.
+pub(crate) fn write(raw: &Value, order: &Order, options: &WriteOptions) -> Result<String> {
+    let order = reorder(raw, order, options.key_order);
+    let indent = match options.indent {
+        Indent::Spaces(width) => " ".repeat(width),
+        Indent::Tab => "\t".to_string(),
+    };
+    let mut writer = Writer {
+        options,
+        indent,
+        out: String::new(),
+    };
+    writer.value(raw, Some(&order), "", 0)?;
+    if options.final_newline {
+        writer.out.push('\n');
+    }
+    Ok(writer.out)
+}
+
+/// Writes JSON the way `serde_json::to_string_pretty` does, with a configurable indent and with
+/// comments above fields.
+struct Writer<'a> {
+    options: &'a WriteOptions,
+    indent: String,
+    out: String,
+}
+
+impl Writer<'_> {
+    /// Writes `value`, with its keys in `order`, which is at `path` and nested `depth` levels deep.
+    fn value(
+        &mut self,
+        value: &Value,
+        order: Option<&Order>,
+        path: &str,
+        depth: usize,
+    ) -> Result<()> {
+        let field = |key: &str| match path {
+            "" => key.to_string(),
+            path => format!("{}.{}", path, key),
+        };
+        match value {
+            Value::Object(fields) if !fields.is_empty() => {
+                self.out.push('{');
+                for (i, (key, value)) in order::members(order, fields).into_iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                    }
+                    let field = field(key);
+                    self.newline(depth + 1);
+                    if let Some(comment) = self.options.comment(&field) {
+                        for line in comment.lines() {
+                            self.out.push_str(format!("// {}", line).trim_end());
+                            self.newline(depth + 1);
+                        }
+                    }
+                    self.out.push_str(&serde_json::to_string(key)?);
+                    self.out.push_str(": ");
+                    self.value(value, order.and_then(|o| o.key(key)), &field, depth + 1)?;
+                }
+                self.newline(depth);
+                self.out.push('}');
+            }
+            Value::Array(items) if !items.is_empty() => {
+                self.out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                    }
+                    self.newline(depth + 1);
+                    let order = order.and_then(|o| o.item(i));
+                    self.value(item, order, &field(&i.to_string()), depth + 1)?;
+                }
+                self.newline(depth);
+                self.out.push(']');
+            }
+            value => self.out.push_str(&serde_json::to_string(value)?),
+        }
+        Ok(())
+    }
+
+    fn newline(&mut self, depth: usize) {
+        self.out.push('\n');
+        for _ in 0..depth {
+            self.out.push_str(&self.indent);
+        }
+    }
+}
+
+/// Returns the order to write the keys of `raw` in, given the order they were written in: the
+/// keys of the config and its sections in `key_order`, and those of other values as written.
+fn reorder<'a>(raw: &Value, order: &'a Order, key_order: KeyOrder) -> Cow<'a, Order> {
+    let fields = match raw {
+        Value::Object(fields) if key_order != KeyOrder::AsWritten => fields,
+        _ => return Cow::Borrowed(order),
+    };
+    let mut sorted = sort(fields, Some(order), key_order, |key| {
+        position(DOCUMENTED_FIELDS, key)
+    });
+    for (section, documented) in [
+        ("watchOptions", DOCUMENTED_WATCH_OPTIONS),
+        ("typeAcquisition", DOCUMENTED_TYPE_ACQUISITION),
+    ] {
+        if let Some(Value::Object(options)) = fields.get(section) {
+            let options = sort(options, order.key(section), key_order, |key| {
+                position(documented, key)
+            });
+            sorted.insert(section.to_string(), options);
+        }
+    }
+    if let Some(Value::Object(options)) = fields.get("compilerOptions") {
+        let options = sort(options, order.key("compilerOptions"), key_order, |key| {
+            compiler_option(key).map(|option| (option.category as usize, option.name))
+        });
+        sorted.insert("compilerOptions".to_string(), options);
+    }
+    Cow::Owned(sorted)
+}
+
+/// Where `key` comes in `documented`, if it's there.
+fn position(documented: &[&str], key: &str) -> Option<usize> {
+    documented.iter().position(|name| *name == key)
+}
+
+/// Returns the order of the keys of `fields`, which were written in `order`, in `key_order`,
+/// where `rank` gives the place of each key the documentation lists.
+fn sort<K: Ord>(
+    fields: &Map<String, Value>,
+    order: Option<&Order>,
+    key_order: KeyOrder,
+    rank: impl Fn(&str) -> Option<K>,
+) -> Order {
+    let mut entries = order::members(order, fields);
+    match key_order {
+        KeyOrder::AsWritten => {}
+        // Undocumented keys rank as `None`, so put them last by sorting on `is_none` first.
+        KeyOrder::Documented => entries.sort_by_cached_key(|(key, _)| {
+            let rank = rank(key);
+            (rank.is_none(), rank)
+        }),
+        KeyOrder::Alphabetical => entries.sort_by_key(|(key, _)| *key),
+    }
+    entries
+        .into_iter()
+        .map(|(key, _)| {
+            let value = order.and_then(|o| o.key(key)).cloned().unwrap_or_default();
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::order::Ordered;
+
+    fn parse(json: &str) -> (Value, Order) {
+        let Ordered { value, order } = serde_json::from_str(json).unwrap();
+        (value, order)
+    }
+
+    fn keys(value: &Value, order: Option<&Order>) -> Vec<String> {
+        order::members(order, value.as_object().unwrap())
+            .into_iter()
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reorders_config_sections() {
+        let (raw, order) = parse(
+            r#"{
+                "ts-node": {"transpileOnly": true},
+                "compilerOptions": {"outDir": "dist", "somethingNew": 1, "strict": true, "paths": {"b": [], "a": []}},
+                "watchOptions": {"excludeFiles": [], "watchFile": "useFsEvents"},
+                "include": ["src"],
+                "extends": "./base.json"
+            }"#,
+        );
+
+        let documented = reorder(&raw, &order, KeyOrder::Documented);
+        let section = |name: &str| documented.key(name);
+        assert_eq!(
+            keys(&raw, Some(&documented)),
+            [
+                "extends",
+                "include",
+                "compilerOptions",
+                "watchOptions",
+                "ts-node"
+            ]
+        );
+        assert_eq!(
+            keys(&raw["compilerOptions"], section("compilerOptions")),
+            ["strict", "paths", "outDir", "somethingNew"]
+        );
+        assert_eq!(
+            keys(&raw["watchOptions"], section("watchOptions")),
+            ["watchFile", "excludeFiles"]
+        );
+        let paths = section("compilerOptions").and_then(|options| options.key("paths"));
+        assert_eq!(keys(&raw["compilerOptions"]["paths"], paths), ["b", "a"]);
+
+        let alphabetical = reorder(&raw, &order, KeyOrder::Alphabetical);
+        assert_eq!(
+            keys(&raw, Some(&alphabetical)),
+            [
+                "compilerOptions",
+                "extends",
+                "include",
+                "ts-node",
+                "watchOptions"
+            ]
+        );
+        assert_eq!(
+            keys(&raw["compilerOptions"], alphabetical.key("compilerOptions")),
+            ["outDir", "paths", "somethingNew", "strict"]
+        );
+    }
+
+    #[test]
+    fn writes_with_indentation_and_final_newline() {
+        let (raw, order) = parse(r#"{"files": ["a.ts"]}"#);
+        assert_eq!(
+            write(&raw, &order, &WriteOptions::new()).unwrap(),
+            "{\n  \"files\": [\n    \"a.ts\"\n  ]\n}\n"
+        );
+        let options = WriteOptions::new()
+            .with_indent(Indent::Spaces(4))
+            .with_final_newline(false);
+        assert_eq!(
+            write(&raw, &order, &options).unwrap(),
+            "{\n    \"files\": [\n        \"a.ts\"\n    ]\n}"
+        );
+    }
+
+    #[test]
+    fn writes_comments_above_fields() {
+        let (raw, order) = parse(
+            r#"{
+                "compilerOptions": {"target": "es2022", "strict": true, "somethingNew": 1},
+                "include": ["src"]
+            }"#,
+        );
+        let options = WriteOptions::new()
+            .with_descriptions(true)
+            .with_comment("compilerOptions.strict", "Required by our lint rules.")
+            .with_comment("include", "Sources only.\n\nTests have their own config.")
+            .with_comment("exclude", "Not written.");
+        let text = write(&raw, &order, &options).unwrap();
+        assert_eq!(
+            text,
+            r#"{
+  "compilerOptions": {
+    // Set the JavaScript language version for emitted JavaScript and include compatible library declarations.
+    "target": "es2022",
+    // Required by our lint rules.
+    "strict": true,
+    "somethingNew": 1
+  },
+  // Sources only.
+  //
+  // Tests have their own config.
+  "include": [
+    "src"
+  ]
+}
+"#
+        );
+        assert_eq!(*crate::TsConfig::parse_str(&text).unwrap().raw(), raw);
+    }
+}
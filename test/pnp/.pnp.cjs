@@ -0,0 +1,10 @@
+#!/usr/bin/env node
+/* eslint-disable */
+"use strict";
+
+const RAW_RUNTIME_STATE =
+'{"__info": ["This file is a minimal Plug\'n\'Play manifest used by the tsconfig crate\'s tests."], "dependencyTreeRoots": [{"name": "pnp-fixture", "reference": "workspace:."}], "enableTopLevelFallback": true, "ignorePatternData": null, "fallbackExclusionList": [], "fallbackPool": [], "packageRegistryData": [[null, [[null, {"packageLocation": "./", "packageDependencies": [["@acme/tsconfig", "npm:1.0.0"], ["pnp-fixture", "workspace:."]], "linkType": "SOFT"}]]], ["@acme/tsconfig", [["npm:1.0.0", {"packageLocation": "./.yarn/cache/@acme-tsconfig-npm-1.0.0-0123456789-abcdef.zip/node_modules/@acme/tsconfig/", "packageDependencies": [["@acme/tsconfig", "npm:1.0.0"]], "linkType": "HARD"}]]], ["pnp-fixture", [["workspace:.", {"packageLocation": "./", "packageDependencies": [["@acme/tsconfig", "npm:1.0.0"], ["pnp-fixture", "workspace:."]], "linkType": "SOFT"}]]]]}';
+
+function $$SETUP_STATE(hydrateRuntimeState, basePath) {
+  return hydrateRuntimeState(JSON.parse(RAW_RUNTIME_STATE), {basePath: basePath || __dirname});
+}